@@ -1,4 +1,5 @@
 use crate::errors::AccessControlError;
+use crate::grant::RoleGrantTrait;
 use crate::management::{MultipleAddressesManagementTrait, SingleAddressManagementTrait};
 use crate::role::Role;
 use soroban_sdk::{panic_with_error, Address, Env};
@@ -19,14 +20,15 @@ pub trait AccessControlTrait {
 
 impl AccessControlTrait for AccessControl {
     fn address_has_role(&self, address: &Address, role: &Role) -> bool {
-        if role.has_many_users() {
+        let has_role = if role.has_many_users() {
             self.get_role_addresses(role).contains(address)
         } else {
             match self.get_role_safe(role) {
                 Some(role_address) => address == &role_address,
                 None => false,
             }
-        }
+        };
+        has_role && !self.is_role_expired(role, address)
     }
 
     fn assert_address_has_role(&self, address: &Address, role: &Role) {
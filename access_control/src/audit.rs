@@ -0,0 +1,84 @@
+use crate::constants::AUDIT_LOG_SIZE;
+use crate::storage::DataKey;
+use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+use utils::bump::{bump_instance, bump_persistent};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum AuditAction {
+    SetRole,
+    CommitTransfer,
+    RevertTransfer,
+    RenounceRole,
+    SetRoleExpiry,
+    EnableEmergencyMode,
+    DisableEmergencyMode,
+    CommitUpgrade,
+    ApplyUpgrade,
+    RevertUpgrade,
+    SetDelay,
+}
+
+// role is empty for actions not related to any role, e.g. upgrades
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct AuditRecord {
+    pub action: AuditAction,
+    pub role: Option<Symbol>,
+    pub old_address: Option<Address>,
+    pub new_address: Option<Address>,
+    pub timestamp: u64,
+}
+
+// total number of records ever written. only last `AUDIT_LOG_SIZE` of them are kept
+pub fn get_audit_log_len(e: &Env) -> u32 {
+    bump_instance(e);
+    e.storage()
+        .instance()
+        .get(&DataKey::AuditLogLength)
+        .unwrap_or(0)
+}
+
+pub fn record_audit_action(
+    e: &Env,
+    action: AuditAction,
+    role: Option<Symbol>,
+    old_address: Option<Address>,
+    new_address: Option<Address>,
+) {
+    let length = get_audit_log_len(e);
+    let key = DataKey::AuditLogRecord(length % AUDIT_LOG_SIZE);
+    e.storage().persistent().set(
+        &key,
+        &AuditRecord {
+            action,
+            role,
+            old_address,
+            new_address,
+            timestamp: e.ledger().timestamp(),
+        },
+    );
+    bump_persistent(e, &key);
+    e.storage()
+        .instance()
+        .set(&DataKey::AuditLogLength, &(length + 1));
+}
+
+// records with sequence numbers starting from `start`, oldest first.
+// records overwritten by the ring buffer are skipped
+pub fn get_audit_log(e: &Env, start: u32, limit: u32) -> Vec<AuditRecord> {
+    let length = get_audit_log_len(e);
+    let first_kept = length.saturating_sub(AUDIT_LOG_SIZE);
+    let start = start.max(first_kept);
+    let end = start.saturating_add(limit).min(length);
+
+    let mut result = Vec::new(e);
+    for idx in start..end {
+        let key = DataKey::AuditLogRecord(idx % AUDIT_LOG_SIZE);
+        if let Some(record) = e.storage().persistent().get(&key) {
+            bump_persistent(e, &key);
+            result.push_back(record);
+        }
+    }
+    result
+}
@@ -1 +1,8 @@
 pub const ADMIN_ACTIONS_DELAY: u64 = 3 * 86400; // allow changes once per 3 days
+pub const MIN_ADMIN_ACTIONS_DELAY: u64 = 60; // configured delays bounds
+pub const MAX_ADMIN_ACTIONS_DELAY: u64 = 30 * 86400;
+pub const EMERGENCY_MODE_MAX_DURATION: u64 = 7 * 86400; // emergency mode expires automatically
+pub const EMERGENCY_MODE_VOTE_LIFETIME: u64 = 86400; // votes for emergency mode are valid for 1 day
+pub const EMERGENCY_VOTER_ROLE: &str = "EmergencyVoter"; // registry role of the emergency mode voters
+pub const COUNCIL_PROPOSAL_LIFETIME: u64 = 7 * 86400; // proposals not executed within 7 days expire
+pub const AUDIT_LOG_SIZE: u32 = 256; // number of privileged actions kept in the log
@@ -0,0 +1,225 @@
+use crate::access::AccessControl;
+use crate::constants::COUNCIL_PROPOSAL_LIFETIME;
+use crate::errors::AccessControlError;
+use crate::storage::DataKey;
+use soroban_sdk::{contracttype, panic_with_error, Address, Symbol, Val, Vec};
+use utils::bump::{bump_instance, bump_persistent};
+
+#[derive(Clone)]
+#[contracttype]
+pub enum CouncilAction {
+    // call `function` of the target contract with `args`.
+    // council address is expected to be passed as admin within arguments
+    Invoke(Address, Symbol, Vec<Val>),
+    // replace council signers and threshold
+    SetSigners(Vec<Address>, u32),
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct CouncilProposal {
+    pub action: CouncilAction,
+    // signer who created the proposal. only the proposer is able to cancel it
+    pub proposer: Address,
+    pub approvals: Vec<Address>,
+    pub executed: bool,
+    pub cancelled: bool,
+    // proposal can't be approved or executed after this timestamp
+    pub expires_at: u64,
+    // council nonce at the moment of creation. changing signers invalidates pending proposals
+    pub nonce: u32,
+}
+
+pub trait CouncilTrait {
+    fn get_council_signers(&self) -> Vec<Address>;
+    fn get_council_threshold(&self) -> u32;
+    fn set_council(&self, signers: &Vec<Address>, threshold: u32);
+    fn get_council_nonce(&self) -> u32;
+    fn assert_council_signer(&self, address: &Address);
+    fn get_proposals_count(&self) -> u32;
+    fn get_proposal(&self, proposal_id: u32) -> CouncilProposal;
+    fn create_proposal(&self, signer: &Address, action: &CouncilAction) -> u32;
+    fn approve_proposal(&self, signer: &Address, proposal_id: u32);
+    fn cancel_proposal(&self, signer: &Address, proposal_id: u32);
+    fn count_approvals(&self, proposal: &CouncilProposal) -> u32;
+    fn execute_proposal(&self, proposal_id: u32) -> CouncilAction;
+}
+
+impl AccessControl {
+    // proposal may be approved or executed only if it's still pending and belongs to the current signers set
+    fn assert_proposal_active(&self, proposal: &CouncilProposal) {
+        if proposal.executed {
+            panic_with_error!(&self.0, AccessControlError::ProposalAlreadyExecuted);
+        }
+        if proposal.cancelled {
+            panic_with_error!(&self.0, AccessControlError::ProposalCancelled);
+        }
+        if self.0.ledger().timestamp() > proposal.expires_at
+            || proposal.nonce != self.get_council_nonce()
+        {
+            panic_with_error!(&self.0, AccessControlError::ProposalExpired);
+        }
+    }
+
+    fn put_proposal(&self, proposal_id: u32, proposal: &CouncilProposal) {
+        let key = DataKey::CouncilProposal(proposal_id);
+        self.0.storage().persistent().set(&key, proposal);
+        bump_persistent(&self.0, &key);
+    }
+}
+
+impl CouncilTrait for AccessControl {
+    fn get_council_signers(&self) -> Vec<Address> {
+        bump_instance(&self.0);
+        self.0
+            .storage()
+            .instance()
+            .get(&DataKey::CouncilSigners)
+            .unwrap_or(Vec::new(&self.0))
+    }
+
+    fn get_council_threshold(&self) -> u32 {
+        bump_instance(&self.0);
+        self.0
+            .storage()
+            .instance()
+            .get(&DataKey::CouncilThreshold)
+            .unwrap_or(0)
+    }
+
+    fn set_council(&self, signers: &Vec<Address>, threshold: u32) {
+        if threshold == 0 || threshold > signers.len() {
+            panic_with_error!(&self.0, AccessControlError::BadCouncilConfig);
+        }
+        for i in 0..signers.len() {
+            if signers.first_index_of(signers.get(i).unwrap()) != Some(i) {
+                panic_with_error!(&self.0, AccessControlError::BadCouncilConfig);
+            }
+        }
+
+        bump_instance(&self.0);
+        let storage = self.0.storage().instance();
+        storage.set(&DataKey::CouncilSigners, signers);
+        storage.set(&DataKey::CouncilThreshold, &threshold);
+        storage.set(&DataKey::CouncilNonce, &(self.get_council_nonce() + 1));
+    }
+
+    fn get_council_nonce(&self) -> u32 {
+        bump_instance(&self.0);
+        self.0
+            .storage()
+            .instance()
+            .get(&DataKey::CouncilNonce)
+            .unwrap_or(0)
+    }
+
+    fn assert_council_signer(&self, address: &Address) {
+        if !self.get_council_signers().contains(address) {
+            panic_with_error!(&self.0, AccessControlError::Unauthorized);
+        }
+    }
+
+    fn get_proposals_count(&self) -> u32 {
+        bump_instance(&self.0);
+        self.0
+            .storage()
+            .instance()
+            .get(&DataKey::CouncilProposalsCount)
+            .unwrap_or(0)
+    }
+
+    fn get_proposal(&self, proposal_id: u32) -> CouncilProposal {
+        let key = DataKey::CouncilProposal(proposal_id);
+        match self.0.storage().persistent().get(&key) {
+            Some(v) => {
+                bump_persistent(&self.0, &key);
+                v
+            }
+            None => panic_with_error!(&self.0, AccessControlError::ProposalNotFound),
+        }
+    }
+
+    // proposer approves the proposal implicitly
+    fn create_proposal(&self, signer: &Address, action: &CouncilAction) -> u32 {
+        self.assert_council_signer(signer);
+
+        let proposal_id = self.get_proposals_count();
+        self.put_proposal(
+            proposal_id,
+            &CouncilProposal {
+                action: action.clone(),
+                proposer: signer.clone(),
+                approvals: Vec::from_array(&self.0, [signer.clone()]),
+                executed: false,
+                cancelled: false,
+                expires_at: self.0.ledger().timestamp() + COUNCIL_PROPOSAL_LIFETIME,
+                nonce: self.get_council_nonce(),
+            },
+        );
+
+        bump_instance(&self.0);
+        self.0
+            .storage()
+            .instance()
+            .set(&DataKey::CouncilProposalsCount, &(proposal_id + 1));
+        proposal_id
+    }
+
+    fn approve_proposal(&self, signer: &Address, proposal_id: u32) {
+        self.assert_council_signer(signer);
+
+        let mut proposal = self.get_proposal(proposal_id);
+        self.assert_proposal_active(&proposal);
+        if proposal.approvals.contains(signer) {
+            panic_with_error!(&self.0, AccessControlError::ProposalAlreadyApproved);
+        }
+
+        proposal.approvals.push_back(signer.clone());
+        self.put_proposal(proposal_id, &proposal);
+    }
+
+    // pending proposal may be cancelled only by its proposer while being a current signer,
+    // so a single signer isn't able to block actions proposed by others
+    fn cancel_proposal(&self, signer: &Address, proposal_id: u32) {
+        self.assert_council_signer(signer);
+
+        let mut proposal = self.get_proposal(proposal_id);
+        if proposal.proposer != *signer {
+            panic_with_error!(&self.0, AccessControlError::Unauthorized);
+        }
+        if proposal.executed {
+            panic_with_error!(&self.0, AccessControlError::ProposalAlreadyExecuted);
+        }
+        if proposal.cancelled {
+            panic_with_error!(&self.0, AccessControlError::ProposalCancelled);
+        }
+
+        proposal.cancelled = true;
+        self.put_proposal(proposal_id, &proposal);
+    }
+
+    // only approvals of current signers are counted as signers set may change after approval
+    fn count_approvals(&self, proposal: &CouncilProposal) -> u32 {
+        let signers = self.get_council_signers();
+        let mut result = 0;
+        for approval in proposal.approvals.iter() {
+            if signers.contains(&approval) {
+                result += 1;
+            }
+        }
+        result
+    }
+
+    // proposal is marked as executed before the action is performed by caller
+    fn execute_proposal(&self, proposal_id: u32) -> CouncilAction {
+        let mut proposal = self.get_proposal(proposal_id);
+        self.assert_proposal_active(&proposal);
+        if self.count_approvals(&proposal) < self.get_council_threshold() {
+            panic_with_error!(&self.0, AccessControlError::ThresholdNotReached);
+        }
+
+        proposal.executed = true;
+        self.put_proposal(proposal_id, &proposal);
+        proposal.action
+    }
+}
@@ -0,0 +1,95 @@
+use crate::audit::{record_audit_action, AuditAction};
+use crate::constants::{ADMIN_ACTIONS_DELAY, MAX_ADMIN_ACTIONS_DELAY, MIN_ADMIN_ACTIONS_DELAY};
+use crate::errors::AccessControlError;
+use crate::role::{Role, SymbolRepresentation};
+use crate::storage::DataKey;
+use soroban_sdk::{contracttype, panic_with_error, Env, Symbol};
+use utils::bump::bump_instance;
+
+// contract-wide delay is used for admin actions, upgrades and role transfers
+// unless more specific delay is configured
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DelayTarget {
+    AdminActions,
+    Upgrade,
+    RoleTransfer(Symbol),
+}
+
+pub fn get_delay(e: &Env, target: &DelayTarget) -> u64 {
+    bump_instance(e);
+    let value: Option<u64> = e.storage().instance().get(&DataKey::Delay(target.clone()));
+    match (value, target) {
+        (Some(v), _) => v,
+        (None, DelayTarget::AdminActions) => ADMIN_ACTIONS_DELAY,
+        (None, _) => get_delay(e, &DelayTarget::AdminActions),
+    }
+}
+
+pub fn get_delay_change_deadline(e: &Env, target: &DelayTarget) -> u64 {
+    bump_instance(e);
+    e.storage()
+        .instance()
+        .get(&DataKey::DelayChangeDeadline(target.clone()))
+        .unwrap_or(0)
+}
+
+// delay change is a delayed action itself: it's applied once the current delay passes
+pub fn commit_delay(e: &Env, target: &DelayTarget, value: u64) {
+    if value < MIN_ADMIN_ACTIONS_DELAY || value > MAX_ADMIN_ACTIONS_DELAY {
+        panic_with_error!(e, AccessControlError::BadDelay);
+    }
+    if let DelayTarget::RoleTransfer(role_name) = target {
+        // validate role exists
+        Role::from_symbol(e, role_name.clone());
+    }
+    if get_delay_change_deadline(e, target) != 0 {
+        panic_with_error!(e, AccessControlError::AnotherActionActive);
+    }
+
+    let deadline = e.ledger().timestamp() + get_delay(e, target);
+    let storage = e.storage().instance();
+    storage.set(&DataKey::DelayChangeDeadline(target.clone()), &deadline);
+    storage.set(&DataKey::FutureDelay(target.clone()), &value);
+}
+
+pub fn apply_delay(e: &Env, target: &DelayTarget) -> u64 {
+    let deadline = get_delay_change_deadline(e, target);
+    if deadline == 0 {
+        panic_with_error!(e, AccessControlError::NoActionActive);
+    }
+    if e.ledger().timestamp() < deadline {
+        panic_with_error!(e, AccessControlError::ActionNotReadyYet);
+    }
+
+    let storage = e.storage().instance();
+    let value: u64 = match storage.get(&DataKey::FutureDelay(target.clone())) {
+        Some(v) => v,
+        None => panic_with_error!(e, AccessControlError::NoActionActive),
+    };
+    storage.remove(&DataKey::DelayChangeDeadline(target.clone()));
+    storage.remove(&DataKey::FutureDelay(target.clone()));
+    storage.set(&DataKey::Delay(target.clone()), &value);
+
+    record_audit_action(e, AuditAction::SetDelay, target_role(target), None, None);
+    value
+}
+
+pub fn revert_delay(e: &Env, target: &DelayTarget) {
+    bump_instance(e);
+    let storage = e.storage().instance();
+    storage.remove(&DataKey::DelayChangeDeadline(target.clone()));
+    storage.remove(&DataKey::FutureDelay(target.clone()));
+}
+
+fn target_role(target: &DelayTarget) -> Option<Symbol> {
+    match target {
+        DelayTarget::RoleTransfer(role_name) => Some(role_name.clone()),
+        _ => None,
+    }
+}
+
+// shortcut for the role transfer delay
+pub(crate) fn get_role_transfer_delay(e: &Env, role: &Role) -> u64 {
+    get_delay(e, &DelayTarget::RoleTransfer(role.as_symbol(e)))
+}
@@ -1,16 +1,182 @@
+use crate::access::{AccessControl, AccessControlTrait};
+use crate::audit::{record_audit_action, AuditAction};
+use crate::constants::{
+    EMERGENCY_MODE_MAX_DURATION, EMERGENCY_MODE_VOTE_LIFETIME, EMERGENCY_VOTER_ROLE,
+};
+use crate::errors::AccessControlError;
+use crate::events::Events;
+use crate::management::SingleAddressManagementTrait;
+use crate::registry::RoleRegistryTrait;
+use crate::role::Role;
 use crate::storage::DataKey;
-use soroban_sdk::Env;
+use soroban_sdk::{panic_with_error, Address, Env, Symbol, Vec};
 use utils::bump::bump_instance;
 
+// emergency mode is considered disabled once max duration passed since activation.
+// legacy flag set without activation timestamp is considered expired,
+// otherwise it would allow instant upgrades forever
 pub fn get_emergency_mode(e: &Env) -> bool {
     bump_instance(e);
-    e.storage()
+    let value = e
+        .storage()
         .instance()
         .get(&DataKey::EmergencyMode)
-        .unwrap_or(false)
+        .unwrap_or(false);
+    if !value {
+        return false;
+    }
+
+    match get_emergency_mode_expires_at(e) {
+        0 => false,
+        expires_at => e.ledger().timestamp() < expires_at,
+    }
+}
+
+fn get_emergency_mode_expires_at(e: &Env) -> u64 {
+    bump_instance(e);
+    let activated_at: Option<u64> = e
+        .storage()
+        .instance()
+        .get(&DataKey::EmergencyModeActivatedAt);
+    match activated_at {
+        Some(v) => v + EMERGENCY_MODE_MAX_DURATION,
+        None => 0,
+    }
+}
+
+// zero if emergency mode is not active
+pub fn get_emergency_mode_expiration(e: &Env) -> u64 {
+    if !get_emergency_mode(e) {
+        return 0;
+    }
+    get_emergency_mode_expires_at(e)
 }
 
+// enabling emergency mode directly is not allowed once quorum is configured, use `vote_emergency_mode`
 pub fn set_emergency_mode(e: &Env, value: &bool) {
+    if *value && get_emergency_quorum_threshold(e) > 0 {
+        panic_with_error!(e, AccessControlError::EmergencyQuorumRequired);
+    }
+    write_emergency_mode(e, value);
+}
+
+fn write_emergency_mode(e: &Env, value: &bool) {
     bump_instance(e);
-    e.storage().instance().set(&DataKey::EmergencyMode, value);
+    let storage = e.storage().instance();
+    storage.set(&DataKey::EmergencyMode, value);
+    if *value {
+        storage.set(&DataKey::EmergencyModeActivatedAt, &e.ledger().timestamp());
+    } else {
+        storage.remove(&DataKey::EmergencyModeActivatedAt);
+    }
+    storage.remove(&DataKey::EmergencyModeVotes);
+
+    let action = match value {
+        true => AuditAction::EnableEmergencyMode,
+        false => AuditAction::DisableEmergencyMode,
+    };
+    record_audit_action(e, action, None, None, None);
+}
+
+// emergency admin and holders of the declared `EmergencyVoter` role are the voters.
+// emergency pause admins are not voters: emergency mode allows instant upgrades, not just pausing.
+// expired grants are not counted
+pub fn get_emergency_quorum_voters(e: &Env) -> Vec<Address> {
+    let access_control = AccessControl::new(e);
+    let mut voters = Vec::new(e);
+    if let Some(address) = access_control.get_role_safe(&Role::EmergencyAdmin) {
+        if access_control.address_has_role(&address, &Role::EmergencyAdmin) {
+            voters.push_back(address);
+        }
+    }
+    if let Some(role) = access_control.get_declared_role(&Symbol::new(e, EMERGENCY_VOTER_ROLE)) {
+        for address in access_control.get_role_addrs(&role).iter() {
+            if !voters.contains(&address) && access_control.address_has_role(&address, &role) {
+                voters.push_back(address);
+            }
+        }
+    }
+    voters
+}
+
+pub fn get_emergency_quorum_threshold(e: &Env) -> u32 {
+    bump_instance(e);
+    e.storage()
+        .instance()
+        .get(&DataKey::EmergencyQuorumThreshold)
+        .unwrap_or(0)
+}
+
+pub fn get_emergency_quorum(e: &Env) -> (Vec<Address>, u32) {
+    (
+        get_emergency_quorum_voters(e),
+        get_emergency_quorum_threshold(e),
+    )
+}
+
+// zero threshold disables quorum, so emergency admin is able to enable emergency mode alone.
+// caller is expected to check the admin authorization
+pub fn set_emergency_quorum(e: &Env, threshold: u32) {
+    if threshold > get_emergency_quorum_voters(e).len() {
+        panic_with_error!(e, AccessControlError::BadEmergencyQuorum);
+    }
+
+    bump_instance(e);
+    let storage = e.storage().instance();
+    storage.set(&DataKey::EmergencyQuorumThreshold, &threshold);
+    storage.remove(&DataKey::EmergencyModeVotes);
+    Events::new(e).set_emergency_quorum(threshold);
+}
+
+// records the vote and enables emergency mode once enough fresh votes collected.
+// caller is expected to check the voter authorization
+pub fn vote_emergency_mode(e: &Env, voter: &Address) {
+    let voters = get_emergency_quorum_voters(e);
+    let threshold = get_emergency_quorum_threshold(e);
+    if threshold == 0 {
+        panic_with_error!(e, AccessControlError::BadEmergencyQuorum);
+    }
+    if !voters.contains(voter) {
+        panic_with_error!(e, AccessControlError::Unauthorized);
+    }
+    if get_emergency_mode(e) {
+        panic_with_error!(e, AccessControlError::AnotherActionActive);
+    }
+
+    // drop outdated votes and votes of removed voters, re-voting refreshes the vote
+    let now = e.ledger().timestamp();
+    let votes: Vec<(Address, u64)> = e
+        .storage()
+        .instance()
+        .get(&DataKey::EmergencyModeVotes)
+        .unwrap_or(Vec::new(e));
+    let mut fresh_votes = Vec::new(e);
+    for (address, voted_at) in votes.iter() {
+        if &address != voter
+            && voters.contains(&address)
+            && voted_at + EMERGENCY_MODE_VOTE_LIFETIME > now
+        {
+            fresh_votes.push_back((address, voted_at));
+        }
+    }
+    fresh_votes.push_back((voter.clone(), now));
+
+    let votes_count = fresh_votes.len();
+    let events = Events::new(e);
+    if votes_count >= threshold {
+        write_emergency_mode(e, &true);
+        events.vote_emergency_mode(
+            voter.clone(),
+            votes_count,
+            threshold,
+            get_emergency_mode_expires_at(e),
+        );
+        events.set_emergency_mode(true);
+    } else {
+        bump_instance(e);
+        e.storage()
+            .instance()
+            .set(&DataKey::EmergencyModeVotes, &fresh_votes);
+        events.vote_emergency_mode(voter.clone(), votes_count, threshold, 0);
+    }
 }
@@ -9,8 +9,31 @@ pub enum AccessControlError {
     AdminAlreadySet = 103,
     BadRoleUsage = 104,
 
+    // admin council errors
+    BadCouncilConfig = 105,
+    ProposalNotFound = 106,
+    ProposalAlreadyApproved = 107,
+    ProposalAlreadyExecuted = 108,
+    ThresholdNotReached = 109,
+    ProposalExpired = 114,
+    ProposalCancelled = 115,
+
+    // roles registry errors
+    RoleAlreadyDeclared = 110,
+
+    // emergency mode errors
+    BadEmergencyQuorum = 111,
+    EmergencyQuorumRequired = 112,
+
+    // configurable delays errors
+    BadDelay = 113,
+
+    // role grants errors
+    BadRoleExpiry = 116,
+
     // transfer ownership errors
     AnotherActionActive = 2906,
     NoActionActive = 2907,
     ActionNotReadyYet = 2908,
+    FutureAddressMismatch = 2909,
 }
@@ -1,3 +1,4 @@
+use crate::delays::DelayTarget;
 use crate::role::{Role, SymbolRepresentation};
 use soroban_sdk::{Address, Env, Symbol, Vec};
 
@@ -35,6 +36,16 @@ impl Events {
         )
     }
 
+    pub fn accept_transfer_ownership(&self, role: Role, new_owner: Address) {
+        self.env().events().publish(
+            (
+                Symbol::new(self.env(), "accept_transfer_ownership"),
+                role.as_symbol(self.env()),
+            ),
+            (new_owner,),
+        )
+    }
+
     pub fn revert_transfer_ownership(&self, role: Role) {
         self.env().events().publish(
             (
@@ -45,6 +56,76 @@ impl Events {
         )
     }
 
+    pub fn enable_transfer_delay(&self, role: Role) {
+        self.env().events().publish(
+            (
+                Symbol::new(self.env(), "enable_transfer_delay"),
+                role.as_symbol(self.env()),
+            ),
+            (),
+        )
+    }
+
+    pub fn commit_transfer_addresses(&self, role: Role, new_addresses: Vec<Address>) {
+        self.env().events().publish(
+            (
+                Symbol::new(self.env(), "commit_transfer_addresses"),
+                role.as_symbol(self.env()),
+            ),
+            (new_addresses,),
+        )
+    }
+
+    pub fn apply_transfer_addresses(&self, role: Role, new_addresses: Vec<Address>) {
+        self.env().events().publish(
+            (
+                Symbol::new(self.env(), "apply_transfer_addresses"),
+                role.as_symbol(self.env()),
+            ),
+            (new_addresses,),
+        )
+    }
+
+    pub fn declare_role(&self, role: Role, has_many_users: bool, is_transfer_delayed: bool) {
+        self.env().events().publish(
+            (
+                Symbol::new(self.env(), "declare_role"),
+                role.as_symbol(self.env()),
+            ),
+            (has_many_users, is_transfer_delayed),
+        )
+    }
+
+    pub fn set_role_addrs(&self, role: Role, addresses: Vec<Address>) {
+        self.env().events().publish(
+            (
+                Symbol::new(self.env(), "set_role_addrs"),
+                role.as_symbol(self.env()),
+            ),
+            (addresses,),
+        )
+    }
+
+    pub fn renounce_role(&self, role: Role, address: Address) {
+        self.env().events().publish(
+            (
+                Symbol::new(self.env(), "renounce_role"),
+                role.as_symbol(self.env()),
+            ),
+            (address,),
+        )
+    }
+
+    pub fn set_role_expiry(&self, role: Role, address: Address, expires_at: u64) {
+        self.env().events().publish(
+            (
+                Symbol::new(self.env(), "set_role_expiry"),
+                role.as_symbol(self.env()),
+            ),
+            (address, expires_at),
+        )
+    }
+
     pub fn set_privileged_addrs(
         &self,
         rewards_admin: Address,
@@ -63,6 +144,74 @@ impl Events {
         )
     }
 
+    pub fn set_council(&self, signers: Vec<Address>, threshold: u32) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "set_council"),),
+            (signers, threshold),
+        )
+    }
+
+    pub fn council_propose(&self, proposal_id: u32, signer: Address) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "council_propose"), proposal_id),
+            (signer,),
+        )
+    }
+
+    pub fn council_approve(&self, proposal_id: u32, signer: Address) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "council_approve"), proposal_id),
+            (signer,),
+        )
+    }
+
+    pub fn council_execute(&self, proposal_id: u32) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "council_execute"), proposal_id),
+            (),
+        )
+    }
+
+    pub fn council_cancel(&self, proposal_id: u32, signer: Address) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "council_cancel"), proposal_id),
+            (signer,),
+        )
+    }
+
+    pub fn commit_delay(&self, target: DelayTarget, value: u64) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "commit_delay"), target), (value,))
+    }
+
+    pub fn apply_delay(&self, target: DelayTarget, value: u64) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "apply_delay"), target), (value,))
+    }
+
+    pub fn revert_delay(&self, target: DelayTarget) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "revert_delay"), target), ())
+    }
+
+    pub fn set_emergency_quorum(&self, threshold: u32) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "set_emergency_quorum"),),
+            (threshold,),
+        )
+    }
+
+    // expires_at is zero until quorum is reached
+    pub fn vote_emergency_mode(&self, voter: Address, votes: u32, threshold: u32, expires_at: u64) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "vote_emergency_mode"), voter),
+            (votes, threshold, expires_at),
+        )
+    }
+
     pub fn set_emergency_mode(&self, emergency_mode: bool) {
         self.env().events().publish(
             match emergency_mode {
@@ -0,0 +1,128 @@
+use crate::access::AccessControl;
+use crate::audit::{record_audit_action, AuditAction};
+use crate::delays::get_role_transfer_delay;
+use crate::errors::AccessControlError;
+use crate::management::{MultipleAddressesManagementTrait, SingleAddressManagementTrait};
+use crate::role::{Role, SymbolRepresentation};
+use crate::storage::{DataKey, StorageTrait};
+use crate::transfer::TransferOwnershipTrait;
+use soroban_sdk::{panic_with_error, Address};
+use utils::bump::bump_instance;
+
+pub trait RoleGrantTrait {
+    fn get_role_expiry(&self, role: &Role, address: &Address) -> u64;
+    fn set_role_expiry(&self, role: &Role, address: &Address, expires_at: u64);
+    fn clear_role_expiry(&self, role: &Role, address: &Address);
+    fn is_role_expired(&self, role: &Role, address: &Address) -> bool;
+    fn renounce_role(&self, role: &Role, address: &Address);
+}
+
+impl RoleGrantTrait for AccessControl {
+    // zero means grant never expires
+    fn get_role_expiry(&self, role: &Role, address: &Address) -> u64 {
+        bump_instance(&self.0);
+        self.0
+            .storage()
+            .instance()
+            .get(&DataKey::RoleExpiry(
+                role.as_symbol(&self.0),
+                address.clone(),
+            ))
+            .unwrap_or(0)
+    }
+
+    fn set_role_expiry(&self, role: &Role, address: &Address, expires_at: u64) {
+        // expired owner would make contract unmanageable.
+        // delayed roles can't be changed instantly, expiry would be a way around the delay
+        match role {
+            Role::Admin | Role::EmergencyAdmin => {
+                panic_with_error!(&self.0, AccessControlError::BadRoleUsage)
+            }
+            _ => {
+                if self.is_transfer_delayed(role) {
+                    panic_with_error!(&self.0, AccessControlError::BadRoleUsage);
+                }
+            }
+        }
+        // grant can't be cut shorter than the role transfer takes
+        if expires_at != 0
+            && expires_at < self.0.ledger().timestamp() + get_role_transfer_delay(&self.0, role)
+        {
+            panic_with_error!(&self.0, AccessControlError::BadRoleExpiry);
+        }
+        // expired holder is still a holder, so its grant can be extended
+        let is_holder = if role.has_many_users() {
+            self.get_role_addresses(role).contains(address)
+        } else {
+            self.get_role_safe(role).as_ref() == Some(address)
+        };
+        if !is_holder {
+            panic_with_error!(&self.0, AccessControlError::Unauthorized);
+        }
+
+        if expires_at == 0 {
+            self.clear_role_expiry(role, address);
+        } else {
+            bump_instance(&self.0);
+            self.0.storage().instance().set(
+                &DataKey::RoleExpiry(role.as_symbol(&self.0), address.clone()),
+                &expires_at,
+            );
+        }
+
+        record_audit_action(
+            &self.0,
+            AuditAction::SetRoleExpiry,
+            Some(role.as_symbol(&self.0)),
+            None,
+            Some(address.clone()),
+        );
+    }
+
+    fn clear_role_expiry(&self, role: &Role, address: &Address) {
+        bump_instance(&self.0);
+        self.0.storage().instance().remove(&DataKey::RoleExpiry(
+            role.as_symbol(&self.0),
+            address.clone(),
+        ));
+    }
+
+    fn is_role_expired(&self, role: &Role, address: &Address) -> bool {
+        let expires_at = self.get_role_expiry(role, address);
+        expires_at != 0 && self.0.ledger().timestamp() >= expires_at
+    }
+
+    fn renounce_role(&self, role: &Role, address: &Address) {
+        if let Role::Admin = role {
+            panic_with_error!(&self.0, AccessControlError::BadRoleUsage);
+        }
+
+        let key = self.get_key(role);
+        if role.has_many_users() {
+            let mut addresses = self.get_role_addresses(role);
+            match addresses.first_index_of(address) {
+                Some(index) => addresses.remove(index),
+                None => panic_with_error!(&self.0, AccessControlError::Unauthorized),
+            };
+            bump_instance(&self.0);
+            self.0.storage().instance().set(&key, &addresses);
+        } else {
+            match self.get_role_safe(role) {
+                Some(v) if &v == address => {}
+                _ => panic_with_error!(&self.0, AccessControlError::Unauthorized),
+            }
+            bump_instance(&self.0);
+            self.0.storage().instance().remove(&key);
+        }
+
+        self.clear_role_expiry(role, address);
+
+        record_audit_action(
+            &self.0,
+            AuditAction::RenounceRole,
+            Some(role.as_symbol(&self.0)),
+            Some(address.clone()),
+            None,
+        );
+    }
+}
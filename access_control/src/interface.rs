@@ -1,4 +1,6 @@
-use soroban_sdk::{Address, Env, Symbol};
+use crate::audit::AuditRecord;
+use crate::delays::DelayTarget;
+use soroban_sdk::{Address, Env, Symbol, Vec};
 
 pub trait TransferableContract {
     // Commit ownership transfer
@@ -10,6 +12,101 @@ pub trait TransferableContract {
     // Revert committed ownership transfer
     fn revert_transfer_ownership(e: Env, admin: Address, role_name: Symbol);
 
+    // Accept committed ownership transfer by the future address
+    fn accept_ownership(e: Env, new_address: Address, role_name: Symbol);
+
     // Get future address for transfer ownership process
     fn get_future_address(e: Env, role_name: Symbol) -> Address;
 }
+
+pub trait DelayedRolesContract {
+    // Enable transfer delay for privileged role. Cannot be disabled afterwards
+    fn enable_transfer_delay(e: Env, admin: Address, role_name: Symbol);
+
+    // Check if role changes require commit/apply flow
+    fn get_transfer_delay_enabled(e: Env, role_name: Symbol) -> bool;
+
+    // Commit delayed change of multiple addresses role
+    fn commit_transfer_addresses(
+        e: Env,
+        admin: Address,
+        role_name: Symbol,
+        new_addresses: Vec<Address>,
+    );
+
+    // Apply committed change of multiple addresses role
+    fn apply_transfer_addresses(e: Env, admin: Address, role_name: Symbol);
+
+    // Get future addresses for multiple addresses role
+    fn get_future_addresses(e: Env, role_name: Symbol) -> Vec<Address>;
+}
+
+pub trait RolesRegistryContract {
+    // Declare new role at runtime
+    fn declare_role(
+        e: Env,
+        admin: Address,
+        role_name: Symbol,
+        has_many_users: bool,
+        is_transfer_delayed: bool,
+    );
+
+    // Get names of roles declared at runtime
+    fn get_declared_roles(e: Env) -> Vec<Symbol>;
+
+    // Set addresses of the role. Single address roles expect exactly one address
+    fn set_role_addrs(e: Env, admin: Address, role_name: Symbol, addresses: Vec<Address>);
+
+    // Get addresses of the role
+    fn get_role_addrs(e: Env, role_name: Symbol) -> Vec<Address>;
+}
+
+pub trait RoleGrantsContract {
+    // Give up the role. Must be signed by the role holder
+    fn renounce_role(e: Env, address: Address, role_name: Symbol);
+
+    // Set grant expiration timestamp, not earlier than the role transfer delay from now.
+    // Zero means grant never expires. Not available for Admin, EmergencyAdmin and delayed roles
+    fn set_role_expiry(
+        e: Env,
+        admin: Address,
+        role_name: Symbol,
+        address: Address,
+        expires_at: u64,
+    );
+
+    // Get grant expiration timestamp
+    fn get_role_expiry(e: Env, role_name: Symbol, address: Address) -> u64;
+}
+
+pub trait EmergencyQuorumContract {
+    // Configure number of emergency admins votes required to enable emergency mode. Zero threshold disables quorum
+    fn set_emergency_quorum(e: Env, admin: Address, threshold: u32);
+
+    // Get emergency mode voters: emergency admin and holders of the declared `EmergencyVoter` role
+    fn get_emergency_quorum(e: Env) -> (Vec<Address>, u32);
+
+    // Vote for emergency mode. Enabled once threshold is reached
+    fn vote_emergency_mode(e: Env, voter: Address);
+
+    // Get emergency mode expiration timestamp
+    fn get_emergency_mode_expiration(e: Env) -> u64;
+}
+
+pub trait AuditLogContract {
+    // Get total number of privileged actions recorded
+    fn get_audit_log_len(e: Env) -> u32;
+
+    // Get recorded privileged actions starting from `start`, oldest first
+    fn get_audit_log(e: Env, start: u32, limit: u32) -> Vec<AuditRecord>;
+}
+
+pub trait ConfigurableDelaysContract {
+    // Get current delay for the target
+    fn get_delay(e: Env, target: DelayTarget) -> u64;
+
+    // Change delay for the target. Change is delayed by the current delay
+    fn commit_delay(e: Env, admin: Address, target: DelayTarget, value: u64);
+    fn apply_delay(e: Env, admin: Address, target: DelayTarget);
+    fn revert_delay(e: Env, admin: Address, target: DelayTarget);
+}
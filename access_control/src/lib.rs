@@ -1,11 +1,16 @@
 #![no_std]
 pub mod access;
+pub mod audit;
 pub mod constants;
+pub mod council;
+pub mod delays;
 pub mod emergency;
 pub mod errors;
 pub mod events;
+pub mod grant;
 pub mod interface;
 pub mod management;
+pub mod registry;
 pub mod role;
 mod storage;
 pub mod transfer;
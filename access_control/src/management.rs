@@ -1,8 +1,11 @@
 use crate::access::AccessControl;
+use crate::audit::{record_audit_action, AuditAction};
 use crate::errors::AccessControlError;
-use crate::role::Role;
+use crate::grant::RoleGrantTrait;
+use crate::role::{Role, SymbolRepresentation};
 use crate::storage::StorageTrait;
-use soroban_sdk::{panic_with_error, Address, Vec};
+use crate::transfer::TransferOwnershipTrait;
+use soroban_sdk::{panic_with_error, Address, Env, Vec};
 use utils::bump::bump_instance;
 
 pub trait SingleAddressManagementTrait {
@@ -48,15 +51,35 @@ impl SingleAddressManagementTrait for AccessControl {
         }
 
         // require delay if address is being replaced.
-        // don't require delay if role is being set for the first time
-        let addr = self.get_role_safe(role);
-        if addr.is_some() && role.is_transfer_delayed() {
-            panic_with_error!(&self.0, AccessControlError::BadRoleUsage);
+        // don't require delay if role is being set for the first time.
+        // roles with opt-in delay may be re-set to the same value to keep bulk setters usable
+        let current_address = self.get_role_safe(role);
+        if let Some(current_address) = current_address.clone() {
+            if role.is_transfer_delayed()
+                || (self.is_transfer_delayed(role) && &current_address != address)
+            {
+                panic_with_error!(&self.0, AccessControlError::BadRoleUsage);
+            }
+        }
+
+        // fresh grant shouldn't inherit expiration of the previous one
+        if current_address.as_ref() != Some(address) {
+            self.clear_role_expiry(role, address);
         }
 
         let key = self.get_key(role);
         bump_instance(&self.0);
         self.0.storage().instance().set(&key, address);
+
+        if current_address.as_ref() != Some(address) {
+            record_audit_action(
+                &self.0,
+                AuditAction::SetRole,
+                Some(role.as_symbol(&self.0)),
+                current_address,
+                Some(address.clone()),
+            );
+        }
     }
 }
 
@@ -75,14 +98,64 @@ impl MultipleAddressesManagementTrait for AccessControl {
             .unwrap_or(Vec::new(&self.0))
     }
 
-    // no delay-related code as we require it only for single addresses roles
     fn set_role_addresses(&self, role: &Role, addresses: &Vec<Address>) {
         if !role.has_many_users() || role.is_transfer_delayed() {
             panic_with_error!(&self.0, AccessControlError::BadRoleUsage);
         }
 
+        // same as for single address roles: opt-in delay forbids instant replacement
         let key = self.get_key(role);
+        let current_addresses: Option<Vec<Address>> = self.0.storage().instance().get(&key);
+        if self.is_transfer_delayed(role) {
+            if let Some(current_addresses) = current_addresses.clone() {
+                if &current_addresses != addresses {
+                    panic_with_error!(&self.0, AccessControlError::BadRoleUsage);
+                }
+            }
+        }
+
+        // fresh grants shouldn't inherit expiration of the previous ones
+        let current_addresses = current_addresses.unwrap_or(Vec::new(&self.0));
+        for address in addresses.iter() {
+            if !current_addresses.contains(&address) {
+                self.clear_role_expiry(role, &address);
+            }
+        }
+
         bump_instance(&self.0);
         self.0.storage().instance().set(&key, addresses);
+
+        record_addresses_change(&self.0, role, &current_addresses, addresses);
+    }
+}
+
+// every removed and added address is logged as a separate record
+pub(crate) fn record_addresses_change(
+    e: &Env,
+    role: &Role,
+    old_addresses: &Vec<Address>,
+    new_addresses: &Vec<Address>,
+) {
+    for address in old_addresses.iter() {
+        if !new_addresses.contains(&address) {
+            record_audit_action(
+                e,
+                AuditAction::SetRole,
+                Some(role.as_symbol(e)),
+                Some(address),
+                None,
+            );
+        }
+    }
+    for address in new_addresses.iter() {
+        if !old_addresses.contains(&address) {
+            record_audit_action(
+                e,
+                AuditAction::SetRole,
+                Some(role.as_symbol(e)),
+                None,
+                Some(address),
+            );
+        }
     }
 }
@@ -0,0 +1,87 @@
+use crate::access::AccessControl;
+use crate::errors::AccessControlError;
+use crate::management::{MultipleAddressesManagementTrait, SingleAddressManagementTrait};
+use crate::role::{Role, RoleConfig};
+use crate::storage::DataKey;
+use soroban_sdk::{panic_with_error, Address, Symbol, Vec};
+use utils::bump::bump_instance;
+
+pub trait RoleRegistryTrait {
+    fn get_declared_roles(&self) -> Vec<Symbol>;
+    fn get_declared_role(&self, name: &Symbol) -> Option<Role>;
+    fn declare_role(&self, name: &Symbol, has_many_users: bool, is_transfer_delayed: bool) -> Role;
+    fn get_role_addrs(&self, role: &Role) -> Vec<Address>;
+    fn set_role_addrs(&self, role: &Role, addresses: &Vec<Address>);
+}
+
+impl RoleRegistryTrait for AccessControl {
+    fn get_declared_roles(&self) -> Vec<Symbol> {
+        bump_instance(&self.0);
+        self.0
+            .storage()
+            .instance()
+            .get(&DataKey::DeclaredRoles)
+            .unwrap_or(Vec::new(&self.0))
+    }
+
+    fn get_declared_role(&self, name: &Symbol) -> Option<Role> {
+        bump_instance(&self.0);
+        let config: Option<RoleConfig> = self
+            .0
+            .storage()
+            .instance()
+            .get(&DataKey::RoleConfig(name.clone()));
+        config.map(Role::Custom)
+    }
+
+    // flags cannot be changed once declared as they define how role addresses are stored
+    fn declare_role(&self, name: &Symbol, has_many_users: bool, is_transfer_delayed: bool) -> Role {
+        if Role::from_builtin_symbol(&self.0, name).is_some() {
+            panic_with_error!(&self.0, AccessControlError::BadRoleUsage);
+        }
+        // multiple addresses roles can be delayed only through opt-in transfer delay
+        if has_many_users && is_transfer_delayed {
+            panic_with_error!(&self.0, AccessControlError::BadRoleUsage);
+        }
+        if self.get_declared_role(name).is_some() {
+            panic_with_error!(&self.0, AccessControlError::RoleAlreadyDeclared);
+        }
+
+        let config = RoleConfig {
+            name: name.clone(),
+            has_many_users,
+            is_transfer_delayed,
+        };
+        let mut declared_roles = self.get_declared_roles();
+        declared_roles.push_back(name.clone());
+
+        bump_instance(&self.0);
+        let storage = self.0.storage().instance();
+        storage.set(&DataKey::RoleConfig(name.clone()), &config);
+        storage.set(&DataKey::DeclaredRoles, &declared_roles);
+        Role::Custom(config)
+    }
+
+    // uniform access for both single and multiple addresses roles
+    fn get_role_addrs(&self, role: &Role) -> Vec<Address> {
+        if role.has_many_users() {
+            self.get_role_addresses(role)
+        } else {
+            match self.get_role_safe(role) {
+                Some(v) => Vec::from_array(&self.0, [v]),
+                None => Vec::new(&self.0),
+            }
+        }
+    }
+
+    fn set_role_addrs(&self, role: &Role, addresses: &Vec<Address>) {
+        if role.has_many_users() {
+            self.set_role_addresses(role, addresses);
+        } else {
+            if addresses.len() != 1 {
+                panic_with_error!(&self.0, AccessControlError::BadRoleUsage);
+            }
+            self.set_role_address(role, &addresses.get(0).unwrap());
+        }
+    }
+}
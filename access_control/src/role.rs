@@ -1,5 +1,16 @@
+use crate::access::AccessControl;
 use crate::errors::AccessControlError;
-use soroban_sdk::{panic_with_error, Env, Symbol};
+use crate::registry::RoleRegistryTrait;
+use soroban_sdk::{contracttype, panic_with_error, Env, Symbol};
+
+// flags of the role declared at runtime
+#[derive(Clone)]
+#[contracttype]
+pub struct RoleConfig {
+    pub name: Symbol,
+    pub has_many_users: bool,
+    pub is_transfer_delayed: bool,
+}
 
 #[derive(Clone)]
 pub enum Role {
@@ -9,6 +20,7 @@ pub enum Role {
     OperationsAdmin,
     PauseAdmin,
     EmergencyPauseAdmin,
+    Custom(RoleConfig),
 }
 
 impl Role {
@@ -20,6 +32,7 @@ impl Role {
             Role::OperationsAdmin => false,
             Role::PauseAdmin => false,
             Role::EmergencyPauseAdmin => true,
+            Role::Custom(config) => config.has_many_users,
         }
     }
 
@@ -31,8 +44,27 @@ impl Role {
             Role::OperationsAdmin => false,
             Role::PauseAdmin => false,
             Role::EmergencyPauseAdmin => false,
+            Role::Custom(config) => config.is_transfer_delayed,
         }
     }
+
+    // built-in roles have fixed names and legacy storage keys
+    pub(crate) fn from_builtin_symbol(e: &Env, value: &Symbol) -> Option<Self> {
+        if value == &Symbol::new(e, "Admin") {
+            return Some(Role::Admin);
+        } else if value == &Symbol::new(e, "EmergencyAdmin") {
+            return Some(Role::EmergencyAdmin);
+        } else if value == &Symbol::new(e, "RewardsAdmin") {
+            return Some(Role::RewardsAdmin);
+        } else if value == &Symbol::new(e, "OperationsAdmin") {
+            return Some(Role::OperationsAdmin);
+        } else if value == &Symbol::new(e, "PauseAdmin") {
+            return Some(Role::PauseAdmin);
+        } else if value == &Symbol::new(e, "EmergencyPauseAdmin") {
+            return Some(Role::EmergencyPauseAdmin);
+        }
+        None
+    }
 }
 
 pub trait SymbolRepresentation {
@@ -49,23 +81,17 @@ impl SymbolRepresentation for Role {
             Role::OperationsAdmin => Symbol::new(&e, "OperationsAdmin"),
             Role::PauseAdmin => Symbol::new(&e, "PauseAdmin"),
             Role::EmergencyPauseAdmin => Symbol::new(&e, "EmergencyPauseAdmin"),
+            Role::Custom(config) => config.name.clone(),
         }
     }
 
     fn from_symbol(e: &Env, value: Symbol) -> Self {
-        if value == Symbol::new(e, "Admin") {
-            return Role::Admin;
-        } else if value == Symbol::new(e, "EmergencyAdmin") {
-            return Role::EmergencyAdmin;
-        } else if value == Symbol::new(e, "RewardsAdmin") {
-            return Role::RewardsAdmin;
-        } else if value == Symbol::new(e, "OperationsAdmin") {
-            return Role::OperationsAdmin;
-        } else if value == Symbol::new(e, "PauseAdmin") {
-            return Role::PauseAdmin;
-        } else if value == Symbol::new(e, "EmergencyPauseAdmin") {
-            return Role::EmergencyPauseAdmin;
+        if let Some(role) = Role::from_builtin_symbol(e, &value) {
+            return role;
+        }
+        match AccessControl::new(e).get_declared_role(&value) {
+            Some(role) => role,
+            None => panic_with_error!(e, AccessControlError::BadRoleUsage),
         }
-        panic_with_error!(e, AccessControlError::BadRoleUsage);
     }
 }
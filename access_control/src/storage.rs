@@ -1,7 +1,8 @@
 use crate::access::AccessControl;
+use crate::delays::DelayTarget;
 use crate::errors::AccessControlError;
 use crate::role::Role;
-use soroban_sdk::{contracttype, panic_with_error};
+use soroban_sdk::{contracttype, panic_with_error, Address, Symbol};
 
 #[derive(Clone)]
 #[contracttype]
@@ -16,19 +17,64 @@ pub(crate) enum DataKey {
     // transfer ownership - pending values
     FutureAdmin,
     FutureEmergencyAdmin,
+    FutureRewardsAdmin,
+    FutureOperationsAdmin,
+    FuturePauseAdmin,
+    FutureEmPauseAdmins,
 
     // transfer ownership - deadlines
     TransferOwnershipDeadline,
     EmAdminTransferOwnershipDeadline,
+    RewardsAdminTransferDeadline,
+    OperationsAdminTransferDeadline,
+    PauseAdminTransferDeadline,
+    EmPauseAdminsTransferDeadline,
+
+    // transfer ownership - opt-in delay flags for privileged roles
+    RewardsAdminTransferDelayed,
+    OperationsAdminTransferDelayed,
+    PauseAdminTransferDelayed,
+    EmPauseAdminsTransferDelayed,
 
     // emergency mode
     EmergencyMode,
+    EmergencyModeActivatedAt,
+    EmergencyQuorumThreshold,
+    EmergencyModeVotes,
+
+    // admin council
+    CouncilSigners,
+    CouncilThreshold,
+    CouncilProposalsCount,
+    CouncilProposal(u32),
+    CouncilNonce,
+
+    // roles declared at runtime. keys are derived from the role symbol
+    DeclaredRoles,
+    RoleConfig(Symbol),
+    RoleAddress(Symbol),
+    FutureRoleAddress(Symbol),
+    RoleTransferDeadline(Symbol),
+    RoleTransferDelayed(Symbol),
+
+    // optional grant expiration timestamp per role symbol and address
+    RoleExpiry(Symbol, Address),
+
+    // audit log ring buffer of privileged actions
+    AuditLogLength,
+    AuditLogRecord(u32),
+
+    // configured delays and their pending changes
+    Delay(DelayTarget),
+    FutureDelay(DelayTarget),
+    DelayChangeDeadline(DelayTarget),
 }
 
 pub(crate) trait StorageTrait {
     fn get_key(&self, role: &Role) -> DataKey;
     fn get_future_key(&self, role: &Role) -> DataKey;
     fn get_future_deadline_key(&self, role: &Role) -> DataKey;
+    fn get_transfer_delayed_key(&self, role: &Role) -> DataKey;
 }
 
 impl StorageTrait for AccessControl {
@@ -40,6 +86,7 @@ impl StorageTrait for AccessControl {
             Role::OperationsAdmin => DataKey::OperationsAdmin,
             Role::PauseAdmin => DataKey::PauseAdmin,
             Role::EmergencyPauseAdmin => DataKey::EmPauseAdmins,
+            Role::Custom(config) => DataKey::RoleAddress(config.name.clone()),
         }
     }
 
@@ -47,7 +94,11 @@ impl StorageTrait for AccessControl {
         match role {
             Role::Admin => DataKey::FutureAdmin,
             Role::EmergencyAdmin => DataKey::FutureEmergencyAdmin,
-            _ => panic_with_error!(&self.0, AccessControlError::BadRoleUsage),
+            Role::RewardsAdmin => DataKey::FutureRewardsAdmin,
+            Role::OperationsAdmin => DataKey::FutureOperationsAdmin,
+            Role::PauseAdmin => DataKey::FuturePauseAdmin,
+            Role::EmergencyPauseAdmin => DataKey::FutureEmPauseAdmins,
+            Role::Custom(config) => DataKey::FutureRoleAddress(config.name.clone()),
         }
     }
 
@@ -55,6 +106,22 @@ impl StorageTrait for AccessControl {
         match role {
             Role::Admin => DataKey::TransferOwnershipDeadline,
             Role::EmergencyAdmin => DataKey::EmAdminTransferOwnershipDeadline,
+            Role::RewardsAdmin => DataKey::RewardsAdminTransferDeadline,
+            Role::OperationsAdmin => DataKey::OperationsAdminTransferDeadline,
+            Role::PauseAdmin => DataKey::PauseAdminTransferDeadline,
+            Role::EmergencyPauseAdmin => DataKey::EmPauseAdminsTransferDeadline,
+            Role::Custom(config) => DataKey::RoleTransferDeadline(config.name.clone()),
+        }
+    }
+
+    fn get_transfer_delayed_key(&self, role: &Role) -> DataKey {
+        match role {
+            Role::RewardsAdmin => DataKey::RewardsAdminTransferDelayed,
+            Role::OperationsAdmin => DataKey::OperationsAdminTransferDelayed,
+            Role::PauseAdmin => DataKey::PauseAdminTransferDelayed,
+            Role::EmergencyPauseAdmin => DataKey::EmPauseAdminsTransferDelayed,
+            Role::Custom(config) => DataKey::RoleTransferDelayed(config.name.clone()),
+            // owner roles are always delayed
             _ => panic_with_error!(&self.0, AccessControlError::BadRoleUsage),
         }
     }
@@ -1,22 +1,54 @@
 use crate::access::AccessControl;
-use crate::constants::ADMIN_ACTIONS_DELAY;
+use crate::audit::{record_audit_action, AuditAction};
+use crate::delays::get_role_transfer_delay;
 use crate::errors::AccessControlError;
-use crate::role::Role;
+use crate::grant::RoleGrantTrait;
+use crate::management::record_addresses_change;
+use crate::role::{Role, SymbolRepresentation};
 use crate::storage::StorageTrait;
-use soroban_sdk::{panic_with_error, Address};
+use soroban_sdk::{panic_with_error, Address, Vec};
 use utils::bump::bump_instance;
 use utils::storage_errors::StorageError;
 
 pub trait TransferOwnershipTrait {
+    fn is_transfer_delayed(&self, role: &Role) -> bool;
+    fn enable_transfer_delay(&self, role: &Role);
     fn get_transfer_ownership_deadline(&self, role: &Role) -> u64;
     fn put_transfer_ownership_deadline(&self, role: &Role, value: u64);
     fn get_future_address(&self, role: &Role) -> Address;
     fn commit_transfer_ownership(&self, role: &Role, future_address: &Address);
     fn apply_transfer_ownership(&self, role: &Role) -> Address;
     fn revert_transfer_ownership(&self, role: &Role);
+    fn accept_transfer_ownership(&self, role: &Role, new_address: &Address);
+    fn get_future_addresses(&self, role: &Role) -> Vec<Address>;
+    fn commit_transfer_addresses(&self, role: &Role, future_addresses: &Vec<Address>);
+    fn apply_transfer_addresses(&self, role: &Role) -> Vec<Address>;
 }
 
 impl TransferOwnershipTrait for AccessControl {
+    // owner roles are always delayed, privileged roles are delayed only after opt-in
+    fn is_transfer_delayed(&self, role: &Role) -> bool {
+        if role.is_transfer_delayed() {
+            return true;
+        }
+
+        bump_instance(&self.0);
+        self.0
+            .storage()
+            .instance()
+            .get(&self.get_transfer_delayed_key(role))
+            .unwrap_or(false)
+    }
+
+    // one-way switch: disabling the delay instantly would defeat its purpose
+    fn enable_transfer_delay(&self, role: &Role) {
+        bump_instance(&self.0);
+        self.0
+            .storage()
+            .instance()
+            .set(&self.get_transfer_delayed_key(role), &true);
+    }
+
     fn get_transfer_ownership_deadline(&self, role: &Role) -> u64 {
         let key = self.get_future_deadline_key(role);
         bump_instance(&self.0);
@@ -30,7 +62,7 @@ impl TransferOwnershipTrait for AccessControl {
     }
 
     fn get_future_address(&self, role: &Role) -> Address {
-        if !role.is_transfer_delayed() || role.has_many_users() {
+        if !self.is_transfer_delayed(role) || role.has_many_users() {
             panic_with_error!(&self.0, AccessControlError::BadRoleUsage);
         }
 
@@ -41,7 +73,7 @@ impl TransferOwnershipTrait for AccessControl {
     }
 
     fn commit_transfer_ownership(&self, role: &Role, future_address: &Address) {
-        if !role.is_transfer_delayed() || role.has_many_users() {
+        if !self.is_transfer_delayed(role) || role.has_many_users() {
             panic_with_error!(&self.0, AccessControlError::BadRoleUsage);
         }
 
@@ -49,7 +81,7 @@ impl TransferOwnershipTrait for AccessControl {
             panic_with_error!(&self.0, AccessControlError::AnotherActionActive);
         }
 
-        let deadline = self.0.ledger().timestamp() + ADMIN_ACTIONS_DELAY;
+        let deadline = self.0.ledger().timestamp() + get_role_transfer_delay(&self.0, role);
         self.put_transfer_ownership_deadline(role, deadline);
 
         bump_instance(&self.0);
@@ -57,9 +89,22 @@ impl TransferOwnershipTrait for AccessControl {
             .storage()
             .instance()
             .set(&self.get_future_key(role), future_address);
+
+        let current_address = self.0.storage().instance().get(&self.get_key(role));
+        record_audit_action(
+            &self.0,
+            AuditAction::CommitTransfer,
+            Some(role.as_symbol(&self.0)),
+            current_address,
+            Some(future_address.clone()),
+        );
     }
 
     fn apply_transfer_ownership(&self, role: &Role) -> Address {
+        if role.has_many_users() {
+            panic_with_error!(&self.0, AccessControlError::BadRoleUsage);
+        }
+
         let storage = self.0.storage().instance();
         let role_key = self.get_key(role);
         let has_value = storage.has(&role_key);
@@ -77,13 +122,113 @@ impl TransferOwnershipTrait for AccessControl {
                 None => panic_with_error!(&self.0, StorageError::ValueNotInitialized),
             };
 
+        let current_address: Option<Address> = storage.get(&role_key);
         bump_instance(&self.0);
         storage.set(&self.get_key(role), &future_address);
+        self.clear_role_expiry(role, &future_address);
+
+        record_audit_action(
+            &self.0,
+            AuditAction::SetRole,
+            Some(role.as_symbol(&self.0)),
+            current_address,
+            Some(future_address.clone()),
+        );
 
         future_address
     }
 
     fn revert_transfer_ownership(&self, role: &Role) {
         self.put_transfer_ownership_deadline(role, 0);
+        record_audit_action(
+            &self.0,
+            AuditAction::RevertTransfer,
+            Some(role.as_symbol(&self.0)),
+            None,
+            None,
+        );
+    }
+
+    // same as apply, but completed by the incoming address itself
+    fn accept_transfer_ownership(&self, role: &Role, new_address: &Address) {
+        if self.get_transfer_ownership_deadline(role) == 0 {
+            panic_with_error!(&self.0, AccessControlError::NoActionActive);
+        }
+        if &self.get_future_address(role) != new_address {
+            panic_with_error!(&self.0, AccessControlError::FutureAddressMismatch);
+        }
+
+        self.apply_transfer_ownership(role);
+    }
+
+    fn get_future_addresses(&self, role: &Role) -> Vec<Address> {
+        if !self.is_transfer_delayed(role) || !role.has_many_users() {
+            panic_with_error!(&self.0, AccessControlError::BadRoleUsage);
+        }
+
+        match self.0.storage().instance().get(&self.get_future_key(role)) {
+            Some(v) => v,
+            None => panic_with_error!(&self.0, AccessControlError::NoActionActive),
+        }
+    }
+
+    fn commit_transfer_addresses(&self, role: &Role, future_addresses: &Vec<Address>) {
+        if !self.is_transfer_delayed(role) || !role.has_many_users() {
+            panic_with_error!(&self.0, AccessControlError::BadRoleUsage);
+        }
+
+        if self.get_transfer_ownership_deadline(role) != 0 {
+            panic_with_error!(&self.0, AccessControlError::AnotherActionActive);
+        }
+
+        let deadline = self.0.ledger().timestamp() + get_role_transfer_delay(&self.0, role);
+        self.put_transfer_ownership_deadline(role, deadline);
+
+        bump_instance(&self.0);
+        self.0
+            .storage()
+            .instance()
+            .set(&self.get_future_key(role), future_addresses);
+
+        record_audit_action(
+            &self.0,
+            AuditAction::CommitTransfer,
+            Some(role.as_symbol(&self.0)),
+            None,
+            None,
+        );
+    }
+
+    fn apply_transfer_addresses(&self, role: &Role) -> Vec<Address> {
+        if !role.has_many_users() {
+            panic_with_error!(&self.0, AccessControlError::BadRoleUsage);
+        }
+
+        let storage = self.0.storage().instance();
+        let has_value = storage.has(&self.get_key(role));
+        if has_value && self.0.ledger().timestamp() < self.get_transfer_ownership_deadline(role) {
+            panic_with_error!(&self.0, AccessControlError::ActionNotReadyYet);
+        }
+        if self.get_transfer_ownership_deadline(role) == 0 {
+            panic_with_error!(&self.0, AccessControlError::NoActionActive);
+        }
+
+        self.put_transfer_ownership_deadline(role, 0);
+        let future_addresses: Vec<Address> = match storage.get(&self.get_future_key(role)) {
+            Some(v) => v,
+            None => panic_with_error!(&self.0, StorageError::ValueNotInitialized),
+        };
+
+        let current_addresses: Vec<Address> = storage
+            .get(&self.get_key(role))
+            .unwrap_or(Vec::new(&self.0));
+        bump_instance(&self.0);
+        storage.set(&self.get_key(role), &future_addresses);
+        for address in future_addresses.iter() {
+            self.clear_role_expiry(role, &address);
+        }
+        record_addresses_change(&self.0, role, &current_addresses, &future_addresses);
+
+        future_addresses
     }
 }
@@ -1,7 +1,8 @@
 use crate::access::{AccessControl, AccessControlTrait};
 use crate::errors::AccessControlError;
+use crate::registry::RoleRegistryTrait;
 use crate::role::Role;
-use soroban_sdk::{panic_with_error, Address, Env};
+use soroban_sdk::{panic_with_error, Address, Env, Symbol};
 
 pub fn require_rewards_admin_or_owner(e: &Env, address: &Address) {
     let access_control = AccessControl::new(e);
@@ -31,3 +32,15 @@ pub fn require_pause_admin_or_owner(e: &Env, address: &Address) {
         || access_control.address_has_role(address, &Role::Admin)
         || panic_with_error!(e, AccessControlError::Unauthorized);
 }
+
+// role declared at runtime may be missing, in this case only owner is authorized
+pub fn require_declared_role_or_owner(e: &Env, address: &Address, role_name: &Symbol) {
+    let access_control = AccessControl::new(e);
+    let has_declared_role = match access_control.get_declared_role(role_name) {
+        Some(role) => access_control.address_has_role(address, &role),
+        None => false,
+    };
+    let _ = has_declared_role
+        || access_control.address_has_role(address, &Role::Admin)
+        || panic_with_error!(e, AccessControlError::Unauthorized);
+}
@@ -0,0 +1,116 @@
+use crate::interface::CouncilInterface;
+use access_control::access::AccessControl;
+use access_control::council::{CouncilAction, CouncilProposal, CouncilTrait};
+use access_control::events::Events as AccessControlEvents;
+use soroban_sdk::{contract, contractimpl, Address, Env, Val, Vec};
+
+// Admin council is a multisig contract intended to be set as `Admin` of other contracts.
+// Target contracts keep using `assert_address_has_role` as the gate,
+// while the council calls their entrypoints only once the proposal is approved by enough signers.
+#[contract]
+pub struct AdminCouncil;
+
+#[contractimpl]
+impl AdminCouncil {
+    // Initializes the council.
+    //
+    // # Arguments
+    //
+    // * `signers` - The addresses of the council signers.
+    // * `threshold` - The number of approvals required to execute proposal.
+    pub fn __constructor(e: Env, signers: Vec<Address>, threshold: u32) {
+        AccessControl::new(&e).set_council(&signers, threshold);
+        AccessControlEvents::new(&e).set_council(signers, threshold);
+    }
+}
+
+#[contractimpl]
+impl CouncilInterface for AdminCouncil {
+    // Creates a new proposal. The proposer approves it implicitly.
+    //
+    // # Arguments
+    //
+    // * `signer` - The address of the council signer.
+    // * `action` - The action to be executed before the proposal expires. Can be one of the following:
+    //     * `Invoke(contract, function, args)` - call the target contract on behalf of the council.
+    //     * `SetSigners(signers, threshold)` - replace the council configuration.
+    //
+    // # Returns
+    //
+    // The id of the created proposal.
+    fn propose(e: Env, signer: Address, action: CouncilAction) -> u32 {
+        signer.require_auth();
+        let proposal_id = AccessControl::new(&e).create_proposal(&signer, &action);
+        AccessControlEvents::new(&e).council_propose(proposal_id, signer);
+        proposal_id
+    }
+
+    // Approves the proposal.
+    //
+    // # Arguments
+    //
+    // * `signer` - The address of the council signer.
+    // * `proposal_id` - The id of the proposal.
+    fn approve(e: Env, signer: Address, proposal_id: u32) {
+        signer.require_auth();
+        AccessControl::new(&e).approve_proposal(&signer, proposal_id);
+        AccessControlEvents::new(&e).council_approve(proposal_id, signer);
+    }
+
+    // Cancels the pending proposal. Only the signer who created the proposal is able to cancel it.
+    //
+    // # Arguments
+    //
+    // * `signer` - The address of the council signer.
+    // * `proposal_id` - The id of the proposal.
+    fn cancel(e: Env, signer: Address, proposal_id: u32) {
+        signer.require_auth();
+        AccessControl::new(&e).cancel_proposal(&signer, proposal_id);
+        AccessControlEvents::new(&e).council_cancel(proposal_id, signer);
+    }
+
+    // Executes the proposal once the approvals threshold is reached.
+    // Proposal expires if not executed within the lifetime or once the council signers are changed.
+    // Execution is permissionless as the proposal is already approved by the council.
+    //
+    // # Arguments
+    //
+    // * `proposal_id` - The id of the proposal.
+    fn execute(e: Env, proposal_id: u32) {
+        let access_control = AccessControl::new(&e);
+        match access_control.execute_proposal(proposal_id) {
+            CouncilAction::Invoke(contract, function, args) => {
+                e.invoke_contract::<Val>(&contract, &function, args);
+            }
+            CouncilAction::SetSigners(signers, threshold) => {
+                access_control.set_council(&signers, threshold);
+                AccessControlEvents::new(&e).set_council(signers, threshold);
+            }
+        }
+        AccessControlEvents::new(&e).council_execute(proposal_id);
+    }
+
+    // Returns the proposal details.
+    //
+    // # Arguments
+    //
+    // * `proposal_id` - The id of the proposal.
+    fn get_proposal(e: Env, proposal_id: u32) -> CouncilProposal {
+        AccessControl::new(&e).get_proposal(proposal_id)
+    }
+
+    // Returns the number of created proposals.
+    fn get_proposals_count(e: Env) -> u32 {
+        AccessControl::new(&e).get_proposals_count()
+    }
+
+    // Returns the council signers.
+    fn get_signers(e: Env) -> Vec<Address> {
+        AccessControl::new(&e).get_council_signers()
+    }
+
+    // Returns the number of approvals required to execute proposal.
+    fn get_threshold(e: Env) -> u32 {
+        AccessControl::new(&e).get_council_threshold()
+    }
+}
@@ -0,0 +1,28 @@
+use access_control::council::{CouncilAction, CouncilProposal};
+use soroban_sdk::{Address, Env, Vec};
+
+pub trait CouncilInterface {
+    // Create proposal. Proposer approves it implicitly
+    fn propose(e: Env, signer: Address, action: CouncilAction) -> u32;
+
+    // Approve proposal
+    fn approve(e: Env, signer: Address, proposal_id: u32);
+
+    // Cancel pending proposal
+    fn cancel(e: Env, signer: Address, proposal_id: u32);
+
+    // Execute proposal once approvals threshold is reached
+    fn execute(e: Env, proposal_id: u32);
+
+    // Get proposal details
+    fn get_proposal(e: Env, proposal_id: u32) -> CouncilProposal;
+
+    // Get number of created proposals
+    fn get_proposals_count(e: Env) -> u32;
+
+    // Get council signers
+    fn get_signers(e: Env) -> Vec<Address>;
+
+    // Get number of approvals required to execute proposal
+    fn get_threshold(e: Env) -> u32;
+}
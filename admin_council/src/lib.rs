@@ -0,0 +1,8 @@
+#![no_std]
+
+mod contract;
+mod interface;
+mod test;
+mod testutils;
+
+pub use crate::contract::{AdminCouncil, AdminCouncilClient};
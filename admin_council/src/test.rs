@@ -0,0 +1,217 @@
+#![cfg(test)]
+
+use crate::testutils::Setup;
+use access_control::constants::COUNCIL_PROPOSAL_LIFETIME;
+use access_control::council::CouncilAction;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, IntoVal, Symbol, Vec};
+use utils::test_utils::jump;
+
+fn set_operations_admin_action(setup: &Setup, operations_admin: &Address) -> CouncilAction {
+    CouncilAction::Invoke(
+        setup.target.address.clone(),
+        Symbol::new(&setup.env, "set_privileged_addrs"),
+        vec![
+            &setup.env,
+            setup.council.address.into_val(&setup.env),
+            operations_admin.into_val(&setup.env),
+        ],
+    )
+}
+
+#[test]
+fn test_execute_invoke() {
+    let setup = Setup::default();
+    let operations_admin = Address::generate(&setup.env);
+
+    let proposal_id = setup.council.propose(
+        &setup.signers[0],
+        &set_operations_admin_action(&setup, &operations_admin),
+    );
+    assert_eq!(proposal_id, 0);
+    assert_eq!(setup.council.get_proposals_count(), 1);
+    assert!(setup.council.try_execute(&proposal_id).is_err());
+
+    setup.council.approve(&setup.signers[2], &proposal_id);
+    setup.council.execute(&proposal_id);
+
+    assert!(setup.council.get_proposal(&proposal_id).executed);
+    assert_eq!(
+        setup
+            .target
+            .get_privileged_addrs()
+            .get(Symbol::new(&setup.env, "OperationsAdmin"))
+            .unwrap(),
+        Vec::from_array(&setup.env, [operations_admin])
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #109)")]
+fn test_execute_threshold_not_reached() {
+    let setup = Setup::default();
+    let operations_admin = Address::generate(&setup.env);
+
+    let proposal_id = setup.council.propose(
+        &setup.signers[0],
+        &set_operations_admin_action(&setup, &operations_admin),
+    );
+    setup.council.execute(&proposal_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #108)")]
+fn test_execute_twice() {
+    let setup = Setup::default();
+    let operations_admin = Address::generate(&setup.env);
+
+    let proposal_id = setup.council.propose(
+        &setup.signers[0],
+        &set_operations_admin_action(&setup, &operations_admin),
+    );
+    setup.council.approve(&setup.signers[1], &proposal_id);
+    setup.council.execute(&proposal_id);
+    setup.council.execute(&proposal_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #107)")]
+fn test_approve_twice() {
+    let setup = Setup::default();
+    let operations_admin = Address::generate(&setup.env);
+
+    let proposal_id = setup.council.propose(
+        &setup.signers[0],
+        &set_operations_admin_action(&setup, &operations_admin),
+    );
+    setup.council.approve(&setup.signers[0], &proposal_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_propose_not_signer() {
+    let setup = Setup::default();
+    let operations_admin = Address::generate(&setup.env);
+
+    setup.council.propose(
+        &Address::generate(&setup.env),
+        &set_operations_admin_action(&setup, &operations_admin),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #106)")]
+fn test_approve_missing_proposal() {
+    let setup = Setup::default();
+    setup.council.approve(&setup.signers[0], &0);
+}
+
+#[test]
+fn test_set_signers() {
+    let setup = Setup::default();
+    let new_signer = Address::generate(&setup.env);
+    let new_signers = Vec::from_array(
+        &setup.env,
+        [
+            setup.signers[0].clone(),
+            setup.signers[1].clone(),
+            new_signer.clone(),
+        ],
+    );
+    let operations_admin = Address::generate(&setup.env);
+
+    // pending proposal approved by signer being removed
+    let pending_id = setup.council.propose(
+        &setup.signers[2],
+        &set_operations_admin_action(&setup, &operations_admin),
+    );
+
+    let proposal_id = setup.council.propose(
+        &setup.signers[0],
+        &CouncilAction::SetSigners(new_signers.clone(), 3),
+    );
+    setup.council.approve(&setup.signers[1], &proposal_id);
+    setup.council.execute(&proposal_id);
+
+    assert_eq!(setup.council.get_signers(), new_signers);
+    assert_eq!(setup.council.get_threshold(), 3);
+
+    // proposals created for the previous signers set are not valid anymore
+    assert!(setup.council.try_approve(&new_signer, &pending_id).is_err());
+    assert!(setup.council.try_execute(&pending_id).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #114)")]
+fn test_execute_expired() {
+    let setup = Setup::default();
+    let operations_admin = Address::generate(&setup.env);
+
+    let proposal_id = setup.council.propose(
+        &setup.signers[0],
+        &set_operations_admin_action(&setup, &operations_admin),
+    );
+    setup.council.approve(&setup.signers[1], &proposal_id);
+    jump(&setup.env, COUNCIL_PROPOSAL_LIFETIME + 1);
+    setup.council.execute(&proposal_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #115)")]
+fn test_execute_cancelled() {
+    let setup = Setup::default();
+    let operations_admin = Address::generate(&setup.env);
+
+    let proposal_id = setup.council.propose(
+        &setup.signers[0],
+        &set_operations_admin_action(&setup, &operations_admin),
+    );
+    setup.council.approve(&setup.signers[1], &proposal_id);
+    setup.council.cancel(&setup.signers[0], &proposal_id);
+    assert!(setup.council.get_proposal(&proposal_id).cancelled);
+    setup.council.execute(&proposal_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_cancel_not_signer() {
+    let setup = Setup::default();
+    let operations_admin = Address::generate(&setup.env);
+
+    let proposal_id = setup.council.propose(
+        &setup.signers[0],
+        &set_operations_admin_action(&setup, &operations_admin),
+    );
+    setup
+        .council
+        .cancel(&Address::generate(&setup.env), &proposal_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_cancel_not_proposer() {
+    let setup = Setup::default();
+    let new_signers = Vec::from_array(
+        &setup.env,
+        [setup.signers[0].clone(), setup.signers[1].clone()],
+    );
+
+    // signer being removed is not able to block the proposal
+    let proposal_id = setup.council.propose(
+        &setup.signers[0],
+        &CouncilAction::SetSigners(new_signers, 2),
+    );
+    setup.council.cancel(&setup.signers[2], &proposal_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #105)")]
+fn test_set_signers_bad_threshold() {
+    let setup = Setup::default();
+    let proposal_id = setup.council.propose(
+        &setup.signers[0],
+        &CouncilAction::SetSigners(Vec::from_array(&setup.env, [setup.signers[0].clone()]), 2),
+    );
+    setup.council.approve(&setup.signers[1], &proposal_id);
+    setup.council.execute(&proposal_id);
+}
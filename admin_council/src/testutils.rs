@@ -0,0 +1,73 @@
+#![cfg(test)]
+
+use crate::{AdminCouncil, AdminCouncilClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, Vec};
+
+pub(crate) mod locker_feed {
+    soroban_sdk::contractimport!(
+        file = "../target/wasm32-unknown-unknown/release/soroban_locker_feed_contract.wasm"
+    );
+}
+
+pub fn create_council_contract<'a>(
+    e: &Env,
+    signers: &Vec<Address>,
+    threshold: u32,
+) -> AdminCouncilClient<'a> {
+    AdminCouncilClient::new(
+        e,
+        &e.register(AdminCouncil {}, (signers.clone(), threshold)),
+    )
+}
+
+pub fn create_locker_feed_contract<'a>(
+    e: &Env,
+    admin: &Address,
+    operations_admin: &Address,
+    emergency_admin: &Address,
+) -> locker_feed::Client<'a> {
+    locker_feed::Client::new(
+        e,
+        &e.register(
+            locker_feed::WASM,
+            locker_feed::Args::__constructor(admin, operations_admin, emergency_admin),
+        ),
+    )
+}
+
+pub(crate) struct Setup<'a> {
+    pub(crate) env: Env,
+
+    pub(crate) signers: [Address; 3],
+    pub(crate) council: AdminCouncilClient<'a>,
+    pub(crate) target: locker_feed::Client<'a>,
+}
+
+impl Default for Setup<'_> {
+    // Create 2-of-3 council and target contract owned by it
+    fn default() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let signers = [
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+        ];
+        let council = create_council_contract(&env, &Vec::from_array(&env, signers.clone()), 2);
+        let target = create_locker_feed_contract(
+            &env,
+            &council.address,
+            &Address::generate(&env),
+            &Address::generate(&env),
+        );
+
+        Setup {
+            env,
+            signers,
+            council,
+            target,
+        }
+    }
+}
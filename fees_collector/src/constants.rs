@@ -0,0 +1,4 @@
+pub(crate) const BPS_DENOMINATOR: u32 = 10_000; // beneficiary weights and slippage are in basis points
+pub(crate) const MAX_BENEFICIARIES: u32 = 10;
+pub(crate) const FEE_ADMIN_ROLE: &str = "FeeAdmin"; // declared in the roles registry on first assignment
+pub(crate) const KEEPER_ROLE: &str = "Keeper"; // declared by the admin through the roles registry
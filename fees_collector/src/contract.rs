@@ -1,14 +1,42 @@
-use soroban_sdk::{contract, contractimpl, panic_with_error, Address, BytesN, Env, Symbol, Vec};
+use soroban_sdk::auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation};
+use soroban_sdk::token::Client as SorobanTokenClient;
+use soroban_sdk::{
+    contract, contractimpl, panic_with_error, vec, Address, BytesN, Env, IntoVal, Symbol, Vec,
+};
 
-use crate::interface::AdminInterface;
+use crate::constants::{BPS_DENOMINATOR, FEE_ADMIN_ROLE, KEEPER_ROLE, MAX_BENEFICIARIES};
+use crate::errors::FeesCollectorError;
+use crate::events::Events;
+use crate::interface::{
+    AdminInterface, ConversionInterface, DistributionInterface, FeesCollectorInterface,
+};
+use crate::storage::{
+    get_beneficiaries, get_beneficiaries_deadline, get_conversion_config, get_conversion_path,
+    get_conversion_period_state, get_future_beneficiaries, get_token_allowed, get_token_balance,
+    get_tokens, remove_conversion_path, set_beneficiaries, set_beneficiaries_deadline,
+    set_conversion_config, set_conversion_path, set_conversion_period_state,
+    set_future_beneficiaries, set_token_allowed, set_token_balance, Beneficiary, ConversionConfig,
+    ConversionPath,
+};
 use access_control::access::{AccessControl, AccessControlTrait};
-use access_control::emergency::{get_emergency_mode, set_emergency_mode};
+use access_control::audit::{get_audit_log, get_audit_log_len, AuditRecord};
+use access_control::delays::{apply_delay, commit_delay, get_delay, revert_delay, DelayTarget};
+use access_control::emergency::{
+    get_emergency_mode, get_emergency_mode_expiration, get_emergency_quorum, set_emergency_mode,
+    set_emergency_quorum, vote_emergency_mode,
+};
 use access_control::errors::AccessControlError;
 use access_control::events::Events as AccessControlEvents;
-use access_control::interface::TransferableContract;
+use access_control::grant::RoleGrantTrait;
+use access_control::interface::{
+    AuditLogContract, ConfigurableDelaysContract, EmergencyQuorumContract, RoleGrantsContract,
+    RolesRegistryContract, TransferableContract,
+};
 use access_control::management::SingleAddressManagementTrait;
+use access_control::registry::RoleRegistryTrait;
 use access_control::role::{Role, SymbolRepresentation};
 use access_control::transfer::TransferOwnershipTrait;
+use access_control::utils::require_declared_role_or_owner;
 use upgrade::events::Events as UpgradeEvents;
 use upgrade::interface::UpgradeableContract;
 use upgrade::{apply_upgrade, commit_upgrade, revert_upgrade};
@@ -19,6 +47,106 @@ use crate::certora_specs::ACCESS_CONTROL;
 #[contract]
 pub struct FeesCollector;
 
+// accounts tokens transferred to the contract directly
+fn assert_token_allowed(e: &Env, token: &Address) {
+    if !get_token_allowed(e, token) {
+        panic_with_error!(e, FeesCollectorError::TokenNotAllowed);
+    }
+}
+
+fn sync_token_balance(e: &Env, token: &Address) -> u128 {
+    assert_token_allowed(e, token);
+    let actual_balance =
+        SorobanTokenClient::new(e, token).balance(&e.current_contract_address()) as u128;
+    let accounted_balance = get_token_balance(e, token);
+    if actual_balance <= accounted_balance {
+        return 0;
+    }
+
+    let received = actual_balance - accounted_balance;
+    set_token_balance(e, token, actual_balance);
+    Events::new(e).receive(token.clone(), received);
+    received
+}
+
+// estimates output of the swaps chain hop by hop using the router
+fn estimate_chained(
+    e: &Env,
+    router: &Address,
+    swaps_chain: &Vec<(Vec<Address>, BytesN<32>, Address)>,
+    token_in: &Address,
+    in_amount: u128,
+) -> u128 {
+    let mut token_in_local = token_in.clone();
+    let mut amount = in_amount;
+    for (tokens, pool_index, token_out) in swaps_chain.iter() {
+        amount = e.invoke_contract(
+            router,
+            &Symbol::new(e, "estimate_swap"),
+            Vec::from_array(
+                e,
+                [
+                    tokens.into_val(e),
+                    token_in_local.into_val(e),
+                    token_out.into_val(e),
+                    pool_index.into_val(e),
+                    amount.into_val(e),
+                ],
+            ),
+        );
+        token_in_local = token_out;
+    }
+    amount
+}
+
+// path should be a continuous chain of hops from the token to the target token
+fn validate_conversion_path(
+    e: &Env,
+    config: &ConversionConfig,
+    token: &Address,
+    path: &ConversionPath,
+) {
+    if token == &config.target_token || path.swaps_chain.len() == 0 {
+        panic_with_error!(e, FeesCollectorError::BadConversionPath);
+    }
+    if path.period_cap == 0 {
+        panic_with_error!(e, FeesCollectorError::BadConversionConfig);
+    }
+
+    let mut token_in = token.clone();
+    for (tokens, _, token_out) in path.swaps_chain.iter() {
+        if token_out == token_in || !tokens.contains(&token_in) || !tokens.contains(&token_out) {
+            panic_with_error!(e, FeesCollectorError::BadConversionPath);
+        }
+        token_in = token_out;
+    }
+    if token_in != config.target_token {
+        panic_with_error!(e, FeesCollectorError::BadConversionPath);
+    }
+}
+
+fn validate_beneficiaries(e: &Env, beneficiaries: &Vec<Beneficiary>) {
+    if beneficiaries.len() == 0 || beneficiaries.len() > MAX_BENEFICIARIES {
+        panic_with_error!(e, FeesCollectorError::BadBeneficiariesConfig);
+    }
+
+    let mut total_weight = 0;
+    for (idx, beneficiary) in beneficiaries.iter().enumerate() {
+        if beneficiary.weight == 0 {
+            panic_with_error!(e, FeesCollectorError::BadBeneficiariesConfig);
+        }
+        for other in beneficiaries.slice(idx as u32 + 1..).iter() {
+            if other.address == beneficiary.address {
+                panic_with_error!(e, FeesCollectorError::BadBeneficiariesConfig);
+            }
+        }
+        total_weight += beneficiary.weight;
+    }
+    if total_weight != BPS_DENOMINATOR {
+        panic_with_error!(e, FeesCollectorError::BadBeneficiariesConfig);
+    }
+}
+
 #[contractimpl]
 impl AdminInterface for FeesCollector {
     // Initializes the admin user.
@@ -37,6 +165,438 @@ impl AdminInterface for FeesCollector {
             ACCESS_CONTROL = Some(access_control.clone());
         }
     }
+
+    // Sets the address allowed to withdraw collected fees.
+    // The fee admin role is declared in the roles registry on the first call.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `fee_admin` - The address of the fee admin.
+    fn set_fee_admin(e: Env, admin: Address, fee_admin: Address) {
+        admin.require_auth();
+        let access_control = AccessControl::new(&e);
+        access_control.assert_address_has_role(&admin, &Role::Admin);
+        let role_name = Symbol::new(&e, FEE_ADMIN_ROLE);
+        let role = match access_control.get_declared_role(&role_name) {
+            Some(role) => role,
+            None => access_control.declare_role(&role_name, false, false),
+        };
+        access_control.set_role_address(&role, &fee_admin);
+        Events::new(&e).set_fee_admin(fee_admin);
+    }
+
+    // Allows or disallows collecting the token through `deposit` and `sync_balance`.
+    // Already accounted balance of a disallowed token may still be distributed or withdrawn.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `token` - The address of the token.
+    // * `allowed` - Whether the token is accepted.
+    fn set_token_allowed(e: Env, admin: Address, token: Address, allowed: bool) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        set_token_allowed(&e, &token, allowed);
+        Events::new(&e).set_token_allowed(token, allowed);
+    }
+
+    // Returns whether the token is accepted by the collector.
+    //
+    // # Arguments
+    //
+    // * `token` - The address of the token.
+    fn is_token_allowed(e: Env, token: Address) -> bool {
+        get_token_allowed(&e, &token)
+    }
+}
+
+#[contractimpl]
+impl FeesCollectorInterface for FeesCollector {
+    // Transfers fees from the sender to the collector and accounts them.
+    // Only tokens allowed by the admin are accepted.
+    //
+    // # Arguments
+    //
+    // * `from` - The address of the sender.
+    // * `token` - The address of the token.
+    // * `amount` - The amount to transfer.
+    fn deposit(e: Env, from: Address, token: Address, amount: u128) {
+        from.require_auth();
+        if amount == 0 {
+            panic_with_error!(&e, FeesCollectorError::ZeroAmount);
+        }
+        assert_token_allowed(&e, &token);
+
+        SorobanTokenClient::new(&e, &token).transfer(
+            &from,
+            &e.current_contract_address(),
+            &(amount as i128),
+        );
+        set_token_balance(&e, &token, get_token_balance(&e, &token) + amount);
+        Events::new(&e).deposit(token, from, amount);
+    }
+
+    // Accounts fees transferred to the collector directly, e.g. by the pools.
+    //
+    // # Arguments
+    //
+    // * `token` - The address of the token.
+    //
+    // # Returns
+    //
+    // The amount received since the last sync.
+    fn sync_balance(e: Env, token: Address) -> u128 {
+        sync_token_balance(&e, &token)
+    }
+
+    // Pays out collected fees. Only fee admin or owner can withdraw.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the fee admin or owner.
+    // * `token` - The address of the token.
+    // * `amount` - The amount to withdraw.
+    // * `to` - The address of the recipient.
+    fn withdraw(e: Env, admin: Address, token: Address, amount: u128, to: Address) {
+        admin.require_auth();
+        require_declared_role_or_owner(&e, &admin, &Symbol::new(&e, FEE_ADMIN_ROLE));
+        if amount == 0 {
+            panic_with_error!(&e, FeesCollectorError::ZeroAmount);
+        }
+
+        let balance = get_token_balance(&e, &token);
+        if amount > balance {
+            panic_with_error!(&e, FeesCollectorError::InsufficientBalance);
+        }
+
+        set_token_balance(&e, &token, balance - amount);
+        SorobanTokenClient::new(&e, &token).transfer(
+            &e.current_contract_address(),
+            &to,
+            &(amount as i128),
+        );
+        Events::new(&e).withdraw(token, to, amount);
+    }
+
+    // Returns the collected fees amount for the token.
+    //
+    // # Arguments
+    //
+    // * `token` - The address of the token.
+    fn get_balance(e: Env, token: Address) -> u128 {
+        get_token_balance(&e, &token)
+    }
+
+    // Returns the tokens with non-zero collected fees.
+    fn get_tokens(e: Env) -> Vec<Address> {
+        get_tokens(&e)
+    }
+}
+
+#[contractimpl]
+impl DistributionInterface for FeesCollector {
+    // Commits the new beneficiaries table.
+    // The table may be applied after the admin actions delay. The first table is applied without delay.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `beneficiaries` - The beneficiaries with weights in basis points. Weights should sum up to 100%.
+    fn commit_beneficiaries(e: Env, admin: Address, beneficiaries: Vec<Beneficiary>) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+
+        if get_beneficiaries_deadline(&e) != 0 {
+            panic_with_error!(&e, AccessControlError::AnotherActionActive);
+        }
+        validate_beneficiaries(&e, &beneficiaries);
+
+        let deadline = e.ledger().timestamp() + get_delay(&e, &DelayTarget::AdminActions);
+        set_beneficiaries_deadline(&e, &deadline);
+        set_future_beneficiaries(&e, &beneficiaries);
+
+        Events::new(&e).commit_beneficiaries(beneficiaries);
+    }
+
+    // Applies the committed beneficiaries table.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    fn apply_beneficiaries(e: Env, admin: Address) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+
+        let deadline = get_beneficiaries_deadline(&e);
+        if deadline == 0 {
+            panic_with_error!(&e, AccessControlError::NoActionActive);
+        }
+        if get_beneficiaries(&e).is_some() && e.ledger().timestamp() < deadline {
+            panic_with_error!(&e, AccessControlError::ActionNotReadyYet);
+        }
+
+        set_beneficiaries_deadline(&e, &0);
+        let beneficiaries = match get_future_beneficiaries(&e) {
+            Some(v) => v,
+            None => panic_with_error!(&e, FeesCollectorError::BeneficiariesNotSet),
+        };
+        set_beneficiaries(&e, &beneficiaries);
+
+        Events::new(&e).apply_beneficiaries(beneficiaries);
+    }
+
+    // Reverts the committed beneficiaries table.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    fn revert_beneficiaries(e: Env, admin: Address) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+
+        set_beneficiaries_deadline(&e, &0);
+        Events::new(&e).revert_beneficiaries();
+    }
+
+    // Returns the current beneficiaries table.
+    fn get_beneficiaries(e: Env) -> Vec<Beneficiary> {
+        match get_beneficiaries(&e) {
+            Some(v) => v,
+            None => panic_with_error!(&e, FeesCollectorError::BeneficiariesNotSet),
+        }
+    }
+
+    // Returns the pending beneficiaries table.
+    fn get_future_beneficiaries(e: Env) -> Vec<Beneficiary> {
+        if get_beneficiaries_deadline(&e) == 0 {
+            panic_with_error!(&e, AccessControlError::NoActionActive);
+        }
+        match get_future_beneficiaries(&e) {
+            Some(v) => v,
+            None => panic_with_error!(&e, FeesCollectorError::BeneficiariesNotSet),
+        }
+    }
+
+    // Splits collected fees between beneficiaries according to their weights.
+    // Rounding dust stays in the collector and is distributed next time.
+    //
+    // # Arguments
+    //
+    // * `token` - The address of the token.
+    //
+    // # Returns
+    //
+    // The amount distributed.
+    fn distribute(e: Env, token: Address) -> u128 {
+        let beneficiaries = match get_beneficiaries(&e) {
+            Some(v) => v,
+            None => panic_with_error!(&e, FeesCollectorError::BeneficiariesNotSet),
+        };
+
+        // accounted balance of a disallowed token is still distributed, only new inflow is ignored
+        if get_token_allowed(&e, &token) {
+            sync_token_balance(&e, &token);
+        }
+        let balance = get_token_balance(&e, &token);
+
+        let token_client = SorobanTokenClient::new(&e, &token);
+        let events = Events::new(&e);
+        let mut distributed = 0;
+        for beneficiary in beneficiaries.iter() {
+            let amount = balance * beneficiary.weight as u128 / BPS_DENOMINATOR as u128;
+            if amount == 0 {
+                continue;
+            }
+            token_client.transfer(
+                &e.current_contract_address(),
+                &beneficiary.address,
+                &(amount as i128),
+            );
+            events.distribute(token.clone(), beneficiary.address, amount);
+            distributed += amount;
+        }
+
+        set_token_balance(&e, &token, balance - distributed);
+        distributed
+    }
+}
+
+#[contractimpl]
+impl ConversionInterface for FeesCollector {
+    // Configures conversion of collected fees into the target token.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `config` - The router, target token, max slippage relative to the router estimate
+    //   and the conversion cap period.
+    fn set_conversion_config(e: Env, admin: Address, config: ConversionConfig) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+
+        if config.max_slippage > BPS_DENOMINATOR || config.period == 0 {
+            panic_with_error!(&e, FeesCollectorError::BadConversionConfig);
+        }
+
+        set_conversion_config(&e, &config);
+        Events::new(&e).set_conversion_config(config);
+    }
+
+    // Returns the conversion config.
+    fn get_conversion_config(e: Env) -> ConversionConfig {
+        match get_conversion_config(&e) {
+            Some(v) => v,
+            None => panic_with_error!(&e, FeesCollectorError::ConversionNotConfigured),
+        }
+    }
+
+    // Approves the swap path used to convert the token into the target token.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `token` - The address of the token to convert.
+    // * `path` - The swaps chain ending with the target token
+    //   and the cap of the token sold per period.
+    fn set_conversion_path(e: Env, admin: Address, token: Address, path: ConversionPath) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+
+        let config = match get_conversion_config(&e) {
+            Some(v) => v,
+            None => panic_with_error!(&e, FeesCollectorError::ConversionNotConfigured),
+        };
+        validate_conversion_path(&e, &config, &token, &path);
+
+        set_conversion_path(&e, &token, &path);
+        Events::new(&e).set_conversion_path(token, path);
+    }
+
+    // Removes the approved swap path for the token.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `token` - The address of the token.
+    fn remove_conversion_path(e: Env, admin: Address, token: Address) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+
+        remove_conversion_path(&e, &token);
+        Events::new(&e).remove_conversion_path(token);
+    }
+
+    // Returns the approved swap path for the token.
+    //
+    // # Arguments
+    //
+    // * `token` - The address of the token.
+    fn get_conversion_path(e: Env, token: Address) -> ConversionPath {
+        match get_conversion_path(&e, &token) {
+            Some(v) => v,
+            None => panic_with_error!(&e, FeesCollectorError::BadConversionPath),
+        }
+    }
+
+    // Swaps the collected token into the target token through the path approved by the admin.
+    // Only keeper or owner can convert. The amount of the token sold per period is capped
+    // and `out_min` should be close to the router estimate.
+    //
+    // # Arguments
+    //
+    // * `keeper` - The address of the keeper or owner.
+    // * `token` - The address of the token to convert.
+    // * `amount` - The amount of the token to sell.
+    // * `out_min` - The minimum amount of the target token to receive.
+    //
+    // # Returns
+    //
+    // The amount of the target token received.
+    fn convert(e: Env, keeper: Address, token: Address, amount: u128, out_min: u128) -> u128 {
+        keeper.require_auth();
+        require_declared_role_or_owner(&e, &keeper, &Symbol::new(&e, KEEPER_ROLE));
+
+        let config = match get_conversion_config(&e) {
+            Some(v) => v,
+            None => panic_with_error!(&e, FeesCollectorError::ConversionNotConfigured),
+        };
+        let path = match get_conversion_path(&e, &token) {
+            Some(v) => v,
+            None => panic_with_error!(&e, FeesCollectorError::BadConversionPath),
+        };
+        // target token may have been changed after the path approval
+        validate_conversion_path(&e, &config, &token, &path);
+
+        if amount == 0 {
+            panic_with_error!(&e, FeesCollectorError::ZeroAmount);
+        }
+        sync_token_balance(&e, &token);
+        let balance = get_token_balance(&e, &token);
+        if amount > balance {
+            panic_with_error!(&e, FeesCollectorError::InsufficientBalance);
+        }
+
+        let now = e.ledger().timestamp();
+        let (mut period_start, mut converted) = get_conversion_period_state(&e, &token);
+        if now >= period_start + config.period {
+            period_start = now;
+            converted = 0;
+        }
+        converted += amount;
+        if converted > path.period_cap {
+            panic_with_error!(&e, FeesCollectorError::ConversionCapExceeded);
+        }
+        set_conversion_period_state(&e, &token, period_start, converted);
+
+        let estimate = estimate_chained(&e, &config.router, &path.swaps_chain, &token, amount);
+        let min_allowed =
+            estimate * (BPS_DENOMINATOR - config.max_slippage) as u128 / BPS_DENOMINATOR as u128;
+        if out_min < min_allowed {
+            panic_with_error!(&e, FeesCollectorError::SlippageTooHigh);
+        }
+
+        e.authorize_as_current_contract(vec![
+            &e,
+            InvokerContractAuthEntry::Contract(SubContractInvocation {
+                context: ContractContext {
+                    contract: token.clone(),
+                    fn_name: Symbol::new(&e, "transfer"),
+                    args: (
+                        e.current_contract_address(),
+                        config.router.clone(),
+                        amount as i128,
+                    )
+                        .into_val(&e),
+                },
+                sub_invocations: vec![&e],
+            }),
+        ]);
+        let out_amount: u128 = e.invoke_contract(
+            &config.router,
+            &Symbol::new(&e, "swap_chained"),
+            Vec::from_array(
+                &e,
+                [
+                    e.current_contract_address().to_val(),
+                    path.swaps_chain.to_val(),
+                    token.clone().to_val(),
+                    amount.into_val(&e),
+                    out_min.into_val(&e),
+                ],
+            ),
+        );
+
+        set_token_balance(&e, &token, balance - amount);
+        set_token_balance(
+            &e,
+            &config.target_token,
+            get_token_balance(&e, &config.target_token) + out_amount,
+        );
+
+        Events::new(&e).convert(token, config.target_token, amount, out_amount);
+        out_amount
+    }
 }
 
 // The `UpgradeableContract` trait provides the interface for upgrading the contract.
@@ -127,6 +687,43 @@ impl UpgradeableContract for FeesCollector {
     }
 }
 
+// The `EmergencyQuorumContract` trait provides the interface for emergency mode quorum.
+#[contractimpl]
+impl EmergencyQuorumContract for FeesCollector {
+    // Configures the number of emergency admins votes required to enable the emergency mode.
+    // Once quorum is configured, the emergency admin is no longer able to enable the emergency mode alone.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `threshold` - The number of votes required. Zero disables the quorum.
+    fn set_emergency_quorum(e: Env, admin: Address, threshold: u32) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        set_emergency_quorum(&e, threshold);
+    }
+
+    // Returns the emergency mode voters (emergency admin and `EmergencyVoter` role holders) and votes threshold.
+    fn get_emergency_quorum(e: Env) -> (Vec<Address>, u32) {
+        get_emergency_quorum(&e)
+    }
+
+    // Votes for the emergency mode. The emergency mode is enabled once the threshold is reached.
+    //
+    // # Arguments
+    //
+    // * `voter` - The address of the voter.
+    fn vote_emergency_mode(e: Env, voter: Address) {
+        voter.require_auth();
+        vote_emergency_mode(&e, &voter);
+    }
+
+    // Returns the timestamp at which the emergency mode expires. Zero if it's not active.
+    fn get_emergency_mode_expiration(e: Env) -> u64 {
+        get_emergency_mode_expiration(&e)
+    }
+}
+
 // The `TransferableContract` trait provides the interface for transferring ownership of the contract.
 #[contractimpl]
 impl TransferableContract for FeesCollector {
@@ -185,6 +782,23 @@ impl TransferableContract for FeesCollector {
         AccessControlEvents::new(&e).revert_transfer_ownership(role);
     }
 
+    // Accepts the committed ownership transfer.
+    // Alternative to `apply_transfer_ownership` which has to be signed by the future address,
+    // proving it controls its key before taking over the role.
+    //
+    // # Arguments
+    //
+    // * `new_address` - The future address of the role.
+    // * `role_name` - The name of the role to transfer ownership of.
+    fn accept_ownership(e: Env, new_address: Address, role_name: Symbol) {
+        new_address.require_auth();
+        let access_control = AccessControl::new(&e);
+
+        let role = Role::from_symbol(&e, role_name);
+        access_control.accept_transfer_ownership(&role, &new_address);
+        AccessControlEvents::new(&e).accept_transfer_ownership(role, new_address);
+    }
+
     // Returns the future address for the role.
     // The future address is the address that the ownership of the role will be transferred to.
     // The future address is set using the `commit_transfer_ownership` function.
@@ -207,3 +821,190 @@ impl TransferableContract for FeesCollector {
         }
     }
 }
+
+// The `RolesRegistryContract` trait provides the interface for managing roles declared at runtime.
+#[contractimpl]
+impl RolesRegistryContract for FeesCollector {
+    // Declares a new role. Role flags cannot be changed afterwards.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `role_name` - The name of the role. Must not match any of the built-in roles.
+    // * `has_many_users` - Whether the role is assigned to multiple addresses.
+    // * `is_transfer_delayed` - Whether the role address can be replaced only through
+    //   the commit/apply flow. Not available for multiple addresses roles.
+    fn declare_role(
+        e: Env,
+        admin: Address,
+        role_name: Symbol,
+        has_many_users: bool,
+        is_transfer_delayed: bool,
+    ) {
+        admin.require_auth();
+        let access_control = AccessControl::new(&e);
+        access_control.assert_address_has_role(&admin, &Role::Admin);
+
+        let role = access_control.declare_role(&role_name, has_many_users, is_transfer_delayed);
+        AccessControlEvents::new(&e).declare_role(role, has_many_users, is_transfer_delayed);
+    }
+
+    // Returns the names of the roles declared at runtime.
+    fn get_declared_roles(e: Env) -> Vec<Symbol> {
+        AccessControl::new(&e).get_declared_roles()
+    }
+
+    // Sets the addresses of the role.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `role_name` - The name of the role.
+    // * `addresses` - The addresses of the role. Single address roles expect exactly one address.
+    fn set_role_addrs(e: Env, admin: Address, role_name: Symbol, addresses: Vec<Address>) {
+        admin.require_auth();
+        let access_control = AccessControl::new(&e);
+        access_control.assert_address_has_role(&admin, &Role::Admin);
+
+        let role = Role::from_symbol(&e, role_name);
+        access_control.set_role_addrs(&role, &addresses);
+        AccessControlEvents::new(&e).set_role_addrs(role, addresses);
+    }
+
+    // Returns the addresses of the role.
+    //
+    // # Arguments
+    //
+    // * `role_name` - The name of the role.
+    fn get_role_addrs(e: Env, role_name: Symbol) -> Vec<Address> {
+        let role = Role::from_symbol(&e, role_name);
+        AccessControl::new(&e).get_role_addrs(&role)
+    }
+}
+
+// The `RoleGrantsContract` trait provides the interface for managing role grants lifetime.
+#[contractimpl]
+impl RoleGrantsContract for FeesCollector {
+    // Gives up the role. The address won't hold the role anymore.
+    // For multiple addresses roles only the given address is removed.
+    //
+    // # Arguments
+    //
+    // * `address` - The address of the role holder.
+    // * `role_name` - The name of the role. Admin role cannot be renounced.
+    fn renounce_role(e: Env, address: Address, role_name: Symbol) {
+        address.require_auth();
+        let role = Role::from_symbol(&e, role_name);
+        AccessControl::new(&e).renounce_role(&role, &address);
+        AccessControlEvents::new(&e).renounce_role(role, address);
+    }
+
+    // Sets the grant expiration timestamp. Once expired, the address is no longer authorized for the role.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `role_name` - The name of the role. Admin, EmergencyAdmin and delayed roles grants cannot expire.
+    // * `address` - The address of the role holder.
+    // * `expires_at` - The expiration timestamp, not earlier than the role transfer delay from now.
+    //   Zero means grant never expires.
+    fn set_role_expiry(
+        e: Env,
+        admin: Address,
+        role_name: Symbol,
+        address: Address,
+        expires_at: u64,
+    ) {
+        admin.require_auth();
+        let access_control = AccessControl::new(&e);
+        access_control.assert_address_has_role(&admin, &Role::Admin);
+
+        let role = Role::from_symbol(&e, role_name);
+        access_control.set_role_expiry(&role, &address, expires_at);
+        AccessControlEvents::new(&e).set_role_expiry(role, address, expires_at);
+    }
+
+    // Returns the grant expiration timestamp. Zero means grant never expires.
+    //
+    // # Arguments
+    //
+    // * `role_name` - The name of the role.
+    // * `address` - The address of the role holder.
+    fn get_role_expiry(e: Env, role_name: Symbol, address: Address) -> u64 {
+        let role = Role::from_symbol(&e, role_name);
+        AccessControl::new(&e).get_role_expiry(&role, &address)
+    }
+}
+
+// The `AuditLogContract` trait provides the interface for querying history of privileged actions.
+#[contractimpl]
+impl AuditLogContract for FeesCollector {
+    // Returns the total number of privileged actions recorded.
+    // Only the last `AUDIT_LOG_SIZE` records are kept.
+    fn get_audit_log_len(e: Env) -> u32 {
+        get_audit_log_len(&e)
+    }
+
+    // Returns the recorded privileged actions, oldest first.
+    //
+    // # Arguments
+    //
+    // * `start` - The sequence number of the first record.
+    // * `limit` - The maximum number of records to return.
+    fn get_audit_log(e: Env, start: u32, limit: u32) -> Vec<AuditRecord> {
+        get_audit_log(&e, start, limit)
+    }
+}
+
+// The `ConfigurableDelaysContract` trait provides the interface for configuring admin actions delays.
+#[contractimpl]
+impl ConfigurableDelaysContract for FeesCollector {
+    // Returns the current delay for the target.
+    //
+    // # Arguments
+    //
+    // * `target` - The delay target: admin actions, upgrade or specific role transfer.
+    fn get_delay(e: Env, target: DelayTarget) -> u64 {
+        get_delay(&e, &target)
+    }
+
+    // Commits the delay change. The change may be applied once the current delay passes.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `target` - The delay target: admin actions, upgrade or specific role transfer.
+    // * `value` - The new delay in seconds.
+    fn commit_delay(e: Env, admin: Address, target: DelayTarget, value: u64) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        commit_delay(&e, &target, value);
+        AccessControlEvents::new(&e).commit_delay(target, value);
+    }
+
+    // Applies the committed delay change.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `target` - The delay target.
+    fn apply_delay(e: Env, admin: Address, target: DelayTarget) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        let value = apply_delay(&e, &target);
+        AccessControlEvents::new(&e).apply_delay(target, value);
+    }
+
+    // Reverts the committed delay change.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `target` - The delay target.
+    fn revert_delay(e: Env, admin: Address, target: DelayTarget) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        revert_delay(&e, &target);
+        AccessControlEvents::new(&e).revert_delay(target);
+    }
+}
@@ -0,0 +1,17 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone)]
+#[repr(u32)]
+pub enum FeesCollectorError {
+    ZeroAmount = 2018,
+    InsufficientBalance = 2019,
+    BadBeneficiariesConfig = 801,
+    BeneficiariesNotSet = 802,
+    ConversionNotConfigured = 803,
+    BadConversionConfig = 804,
+    BadConversionPath = 805,
+    SlippageTooHigh = 806,
+    ConversionCapExceeded = 807,
+    TokenNotAllowed = 808,
+}
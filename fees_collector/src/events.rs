@@ -0,0 +1,111 @@
+use crate::storage::{Beneficiary, ConversionConfig, ConversionPath};
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+#[derive(Clone)]
+pub(crate) struct Events(Env);
+
+impl Events {
+    #[inline(always)]
+    pub(crate) fn env(&self) -> &Env {
+        &self.0
+    }
+
+    #[inline(always)]
+    pub(crate) fn new(env: &Env) -> Events {
+        Events(env.clone())
+    }
+
+    pub(crate) fn set_fee_admin(&self, fee_admin: Address) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "set_fee_admin"),), (fee_admin,))
+    }
+
+    pub(crate) fn set_token_allowed(&self, token: Address, allowed: bool) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "set_token_allowed"), token),
+            (allowed,),
+        )
+    }
+
+    // inflow through `deposit`
+    pub(crate) fn deposit(&self, token: Address, from: Address, amount: u128) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "deposit"), token, from), (amount,))
+    }
+
+    // inflow transferred directly and accounted through `sync_balance`
+    pub(crate) fn receive(&self, token: Address, amount: u128) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "receive"), token), (amount,))
+    }
+
+    pub(crate) fn withdraw(&self, token: Address, to: Address, amount: u128) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "withdraw"), token, to), (amount,))
+    }
+
+    pub(crate) fn commit_beneficiaries(&self, beneficiaries: Vec<Beneficiary>) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "commit_beneficiaries"),),
+            (beneficiaries,),
+        )
+    }
+
+    pub(crate) fn apply_beneficiaries(&self, beneficiaries: Vec<Beneficiary>) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "apply_beneficiaries"),),
+            (beneficiaries,),
+        )
+    }
+
+    pub(crate) fn revert_beneficiaries(&self) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "revert_beneficiaries"),), ())
+    }
+
+    pub(crate) fn distribute(&self, token: Address, beneficiary: Address, amount: u128) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "distribute"), token, beneficiary),
+            (amount,),
+        )
+    }
+
+    pub(crate) fn set_conversion_config(&self, config: ConversionConfig) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "set_conversion_config"),),
+            (config,),
+        )
+    }
+
+    pub(crate) fn set_conversion_path(&self, token: Address, path: ConversionPath) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "set_conversion_path"), token),
+            (path,),
+        )
+    }
+
+    pub(crate) fn remove_conversion_path(&self, token: Address) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "remove_conversion_path"), token),
+            (),
+        )
+    }
+
+    pub(crate) fn convert(
+        &self,
+        token_in: Address,
+        token_out: Address,
+        in_amount: u128,
+        out_amount: u128,
+    ) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "convert"), token_in, token_out),
+            (in_amount, out_amount),
+        )
+    }
+}
@@ -1,6 +1,61 @@
-use soroban_sdk::{Address, Env};
+use crate::storage::{Beneficiary, ConversionConfig, ConversionPath};
+use soroban_sdk::{Address, Env, Vec};
 
 pub trait AdminInterface {
     // Initializes the admin user.
     fn init_admin(e: Env, account: Address);
+
+    // Set the address allowed to withdraw collected fees.
+    fn set_fee_admin(e: Env, admin: Address, fee_admin: Address);
+
+    // Allow or disallow collecting the token.
+    fn set_token_allowed(e: Env, admin: Address, token: Address, allowed: bool);
+
+    // Check whether the token is accepted by the collector.
+    fn is_token_allowed(e: Env, token: Address) -> bool;
+}
+
+pub trait FeesCollectorInterface {
+    // Transfer fees from the sender to the collector. Only allowed tokens are accepted.
+    fn deposit(e: Env, from: Address, token: Address, amount: u128);
+
+    // Account fees transferred to the collector directly. Returns amount received.
+    fn sync_balance(e: Env, token: Address) -> u128;
+
+    // Pay out collected fees.
+    fn withdraw(e: Env, admin: Address, token: Address, amount: u128, to: Address);
+
+    // Get collected fees amount for the token.
+    fn get_balance(e: Env, token: Address) -> u128;
+
+    // Get tokens with non-zero collected fees.
+    fn get_tokens(e: Env) -> Vec<Address>;
+}
+
+pub trait DistributionInterface {
+    // Commit new beneficiaries table. Weights are in basis points and should sum up to 100%.
+    fn commit_beneficiaries(e: Env, admin: Address, beneficiaries: Vec<Beneficiary>);
+    fn apply_beneficiaries(e: Env, admin: Address);
+    fn revert_beneficiaries(e: Env, admin: Address);
+
+    // Get current and pending beneficiaries tables
+    fn get_beneficiaries(e: Env) -> Vec<Beneficiary>;
+    fn get_future_beneficiaries(e: Env) -> Vec<Beneficiary>;
+
+    // Split collected fees between beneficiaries. Returns distributed amount
+    fn distribute(e: Env, token: Address) -> u128;
+}
+
+pub trait ConversionInterface {
+    // Configure conversion of collected fees into the target token
+    fn set_conversion_config(e: Env, admin: Address, config: ConversionConfig);
+    fn get_conversion_config(e: Env) -> ConversionConfig;
+
+    // Approve the swap path for the token and cap of the token sold per period
+    fn set_conversion_path(e: Env, admin: Address, token: Address, path: ConversionPath);
+    fn remove_conversion_path(e: Env, admin: Address, token: Address);
+    fn get_conversion_path(e: Env, token: Address) -> ConversionPath;
+
+    // Swap token amount into the target token through the approved path. Returns amount received
+    fn convert(e: Env, keeper: Address, token: Address, amount: u128, out_min: u128) -> u128;
 }
@@ -1,6 +1,9 @@
 #![no_std]
 
+mod constants;
 mod contract;
+mod errors;
+mod events;
 mod interface;
 mod storage;
 mod test;
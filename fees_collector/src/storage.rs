@@ -1 +1,200 @@
+use soroban_sdk::{contracttype, Address, BytesN, Env, Vec};
+use utils::bump::{bump_instance, bump_persistent};
 
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Beneficiary {
+    pub address: Address,
+    pub weight: u32, // basis points
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ConversionConfig {
+    pub router: Address,
+    pub target_token: Address,
+    pub max_slippage: u32, // basis points below router estimate
+    pub period: u64,       // conversion cap period in seconds
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ConversionPath {
+    pub swaps_chain: Vec<(Vec<Address>, BytesN<32>, Address)>, // ends with the target token
+    pub period_cap: u128, // max amount of the source token sold per period
+}
+
+#[derive(Clone)]
+#[contracttype]
+enum DataKey {
+    TokenBalance(Address),
+    Tokens,                // tokens with non-zero balance
+    TokenAllowed(Address), // tokens accepted by the collector
+
+    // fees split table
+    Beneficiaries,
+    FutureBeneficiaries,
+    BeneficiariesDeadline,
+
+    // conversion into target token
+    ConversionConfig,
+    ConversionPath(Address),   // admin approved path per source token
+    ConversionPeriod(Address), // period start and amount sold within it per source token
+}
+
+pub fn get_token_balance(e: &Env, token: &Address) -> u128 {
+    let key = DataKey::TokenBalance(token.clone());
+    match e.storage().persistent().get(&key) {
+        Some(v) => {
+            bump_persistent(e, &key);
+            v
+        }
+        None => 0,
+    }
+}
+
+pub fn set_token_balance(e: &Env, token: &Address, value: u128) {
+    let key = DataKey::TokenBalance(token.clone());
+    let mut tokens = get_tokens(e);
+    let token_idx = tokens.first_index_of(token);
+    if value == 0 {
+        e.storage().persistent().remove(&key);
+        if let Some(idx) = token_idx {
+            tokens.remove(idx);
+            set_tokens(e, &tokens);
+        }
+        return;
+    }
+
+    e.storage().persistent().set(&key, &value);
+    bump_persistent(e, &key);
+    if token_idx.is_none() {
+        tokens.push_back(token.clone());
+        set_tokens(e, &tokens);
+    }
+}
+
+pub fn get_tokens(e: &Env) -> Vec<Address> {
+    bump_instance(e);
+    e.storage()
+        .instance()
+        .get(&DataKey::Tokens)
+        .unwrap_or(Vec::new(e))
+}
+
+fn set_tokens(e: &Env, value: &Vec<Address>) {
+    bump_instance(e);
+    e.storage().instance().set(&DataKey::Tokens, value);
+}
+
+pub fn get_token_allowed(e: &Env, token: &Address) -> bool {
+    let key = DataKey::TokenAllowed(token.clone());
+    match e.storage().persistent().get(&key) {
+        Some(v) => {
+            bump_persistent(e, &key);
+            v
+        }
+        None => false,
+    }
+}
+
+pub fn set_token_allowed(e: &Env, token: &Address, value: bool) {
+    let key = DataKey::TokenAllowed(token.clone());
+    if !value {
+        e.storage().persistent().remove(&key);
+        return;
+    }
+
+    e.storage().persistent().set(&key, &value);
+    bump_persistent(e, &key);
+}
+
+pub fn get_beneficiaries(e: &Env) -> Option<Vec<Beneficiary>> {
+    bump_instance(e);
+    e.storage().instance().get(&DataKey::Beneficiaries)
+}
+
+pub fn set_beneficiaries(e: &Env, value: &Vec<Beneficiary>) {
+    bump_instance(e);
+    e.storage().instance().set(&DataKey::Beneficiaries, value);
+}
+
+pub fn get_future_beneficiaries(e: &Env) -> Option<Vec<Beneficiary>> {
+    bump_instance(e);
+    e.storage().instance().get(&DataKey::FutureBeneficiaries)
+}
+
+pub fn set_future_beneficiaries(e: &Env, value: &Vec<Beneficiary>) {
+    bump_instance(e);
+    e.storage()
+        .instance()
+        .set(&DataKey::FutureBeneficiaries, value);
+}
+
+pub fn get_beneficiaries_deadline(e: &Env) -> u64 {
+    bump_instance(e);
+    e.storage()
+        .instance()
+        .get(&DataKey::BeneficiariesDeadline)
+        .unwrap_or(0)
+}
+
+pub fn set_beneficiaries_deadline(e: &Env, value: &u64) {
+    bump_instance(e);
+    e.storage()
+        .instance()
+        .set(&DataKey::BeneficiariesDeadline, value);
+}
+
+pub fn get_conversion_config(e: &Env) -> Option<ConversionConfig> {
+    bump_instance(e);
+    e.storage().instance().get(&DataKey::ConversionConfig)
+}
+
+pub fn set_conversion_config(e: &Env, value: &ConversionConfig) {
+    bump_instance(e);
+    e.storage()
+        .instance()
+        .set(&DataKey::ConversionConfig, value);
+}
+
+pub fn get_conversion_path(e: &Env, token: &Address) -> Option<ConversionPath> {
+    let key = DataKey::ConversionPath(token.clone());
+    let value = e.storage().persistent().get(&key);
+    if value.is_some() {
+        bump_persistent(e, &key);
+    }
+    value
+}
+
+pub fn set_conversion_path(e: &Env, token: &Address, value: &ConversionPath) {
+    let key = DataKey::ConversionPath(token.clone());
+    e.storage().persistent().set(&key, value);
+    bump_persistent(e, &key);
+}
+
+pub fn remove_conversion_path(e: &Env, token: &Address) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::ConversionPath(token.clone()));
+}
+
+// start of the current conversion period and amount of the token sold within it
+pub fn get_conversion_period_state(e: &Env, token: &Address) -> (u64, u128) {
+    let key = DataKey::ConversionPeriod(token.clone());
+    match e.storage().persistent().get(&key) {
+        Some(v) => {
+            bump_persistent(e, &key);
+            v
+        }
+        None => (0, 0),
+    }
+}
+
+pub fn set_conversion_period_state(e: &Env, token: &Address, period_start: u64, converted: u128) {
+    let key = DataKey::ConversionPeriod(token.clone());
+    e.storage()
+        .persistent()
+        .set(&key, &(period_start, converted));
+    bump_persistent(e, &key);
+}
@@ -1,10 +1,15 @@
 #![cfg(test)]
 extern crate std;
 
-use crate::testutils::{create_contract, install_dummy_wasm, jump, Setup};
+use crate::storage::{Beneficiary, ConversionConfig, ConversionPath};
+use crate::testutils::{
+    create_contract, create_router_with_pool, create_token_contract, get_token_admin_client,
+    install_dummy_wasm, jump, swap_router, Setup,
+};
 use access_control::constants::ADMIN_ACTIONS_DELAY;
 use soroban_sdk::testutils::{Address as _, Events};
-use soroban_sdk::{symbol_short, vec, Address, Env, IntoVal, Symbol};
+use soroban_sdk::token::TokenClient as SorobanTokenClient;
+use soroban_sdk::{symbol_short, vec, Address, Env, IntoVal, Symbol, Vec};
 
 #[test]
 fn test() {
@@ -195,3 +200,502 @@ fn test_regular_upgrade() {
 
     assert_eq!(contract.version(), 130)
 }
+
+#[test]
+fn test_deposit_and_withdraw() {
+    let setup = Setup::default();
+    let collector = setup.collector;
+    let fee_admin = Address::generate(&setup.env);
+    let user = Address::generate(&setup.env);
+    let recipient = Address::generate(&setup.env);
+    let token = create_token_contract(&setup.env, &setup.admin);
+    get_token_admin_client(&setup.env, &token.address).mint(&user, &1000);
+    collector.set_token_allowed(&setup.admin, &token.address, &true);
+
+    collector.set_fee_admin(&setup.admin, &fee_admin);
+    assert_eq!(collector.get_tokens(), Vec::<Address>::new(&setup.env));
+
+    collector.deposit(&user, &token.address, &300);
+    assert_eq!(
+        vec![&setup.env, setup.env.events().all().last().unwrap()],
+        vec![
+            &setup.env,
+            (
+                collector.address.clone(),
+                (
+                    Symbol::new(&setup.env, "deposit"),
+                    token.address.clone(),
+                    user.clone()
+                )
+                    .into_val(&setup.env),
+                (300_u128,).into_val(&setup.env),
+            ),
+        ]
+    );
+    assert_eq!(collector.get_balance(&token.address), 300);
+    assert_eq!(token.balance(&collector.address), 300);
+    assert_eq!(
+        collector.get_tokens(),
+        vec![&setup.env, token.address.clone()]
+    );
+
+    collector.withdraw(&fee_admin, &token.address, &100, &recipient);
+    assert_eq!(
+        vec![&setup.env, setup.env.events().all().last().unwrap()],
+        vec![
+            &setup.env,
+            (
+                collector.address.clone(),
+                (
+                    Symbol::new(&setup.env, "withdraw"),
+                    token.address.clone(),
+                    recipient.clone()
+                )
+                    .into_val(&setup.env),
+                (100_u128,).into_val(&setup.env),
+            ),
+        ]
+    );
+    assert_eq!(collector.get_balance(&token.address), 200);
+    assert_eq!(token.balance(&recipient), 100);
+
+    // owner is also able to withdraw. empty balance is removed from the list
+    collector.withdraw(&setup.admin, &token.address, &200, &recipient);
+    assert_eq!(collector.get_balance(&token.address), 0);
+    assert_eq!(collector.get_tokens(), Vec::<Address>::new(&setup.env));
+}
+
+#[test]
+fn test_sync_balance() {
+    let setup = Setup::default();
+    let collector = setup.collector;
+    let pool = Address::generate(&setup.env);
+    let token = create_token_contract(&setup.env, &setup.admin);
+    let token_admin_client = get_token_admin_client(&setup.env, &token.address);
+    token_admin_client.mint(&pool, &1000);
+    collector.set_token_allowed(&setup.admin, &token.address, &true);
+
+    token.transfer(&pool, &collector.address, &400);
+    assert_eq!(collector.get_balance(&token.address), 0);
+    assert_eq!(collector.sync_balance(&token.address), 400);
+    assert_eq!(
+        vec![&setup.env, setup.env.events().all().last().unwrap()],
+        vec![
+            &setup.env,
+            (
+                collector.address.clone(),
+                (Symbol::new(&setup.env, "receive"), token.address.clone()).into_val(&setup.env),
+                (400_u128,).into_val(&setup.env),
+            ),
+        ]
+    );
+    assert_eq!(collector.get_balance(&token.address), 400);
+    assert_eq!(
+        collector.get_tokens(),
+        vec![&setup.env, token.address.clone()]
+    );
+
+    // nothing new received
+    assert_eq!(collector.sync_balance(&token.address), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2019)")]
+fn test_withdraw_over_balance() {
+    let setup = Setup::default();
+    let collector = setup.collector;
+    let user = Address::generate(&setup.env);
+    let token = create_token_contract(&setup.env, &setup.admin);
+    get_token_admin_client(&setup.env, &token.address).mint(&user, &1000);
+    collector.set_token_allowed(&setup.admin, &token.address, &true);
+
+    collector.deposit(&user, &token.address, &300);
+    collector.withdraw(&setup.admin, &token.address, &301, &user);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2018)")]
+fn test_deposit_zero() {
+    let setup = Setup::default();
+    let collector = setup.collector;
+    let user = Address::generate(&setup.env);
+    let token = create_token_contract(&setup.env, &setup.admin);
+
+    collector.deposit(&user, &token.address, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #808)")]
+fn test_deposit_not_allowed() {
+    let setup = Setup::default();
+    let collector = setup.collector;
+    let user = Address::generate(&setup.env);
+    let token = create_token_contract(&setup.env, &setup.admin);
+    get_token_admin_client(&setup.env, &token.address).mint(&user, &1000);
+
+    collector.deposit(&user, &token.address, &300);
+}
+
+#[test]
+fn test_sync_balance_token_disallowed() {
+    let setup = Setup::default();
+    let collector = setup.collector;
+    let pool = Address::generate(&setup.env);
+    let token = create_token_contract(&setup.env, &setup.admin);
+    get_token_admin_client(&setup.env, &token.address).mint(&pool, &1000);
+
+    collector.set_token_allowed(&setup.admin, &token.address, &true);
+    assert!(collector.is_token_allowed(&token.address));
+    token.transfer(&pool, &collector.address, &400);
+    assert_eq!(collector.sync_balance(&token.address), 400);
+
+    // accounted balance stays withdrawable, new inflow is not accepted
+    collector.set_token_allowed(&setup.admin, &token.address, &false);
+    assert!(!collector.is_token_allowed(&token.address));
+    token.transfer(&pool, &collector.address, &100);
+    assert!(collector.try_sync_balance(&token.address).is_err());
+    collector.withdraw(&setup.admin, &token.address, &400, &pool);
+    assert_eq!(collector.get_balance(&token.address), 0);
+}
+
+#[test]
+fn test_distribute_token_disallowed() {
+    let setup = Setup::default();
+    let collector = setup.collector;
+    let pool = Address::generate(&setup.env);
+    let treasury = Address::generate(&setup.env);
+    let token = create_token_contract(&setup.env, &setup.admin);
+    get_token_admin_client(&setup.env, &token.address).mint(&pool, &1000);
+    collector.set_token_allowed(&setup.admin, &token.address, &true);
+
+    let beneficiaries = vec![
+        &setup.env,
+        Beneficiary {
+            address: treasury.clone(),
+            weight: 10000,
+        },
+    ];
+    collector.commit_beneficiaries(&setup.admin, &beneficiaries);
+    collector.apply_beneficiaries(&setup.admin);
+
+    token.transfer(&pool, &collector.address, &400);
+    assert_eq!(collector.sync_balance(&token.address), 400);
+
+    // accounted balance is distributed, new inflow is not accepted
+    collector.set_token_allowed(&setup.admin, &token.address, &false);
+    token.transfer(&pool, &collector.address, &100);
+    assert_eq!(collector.distribute(&token.address), 400);
+    assert_eq!(token.balance(&treasury), 400);
+    assert_eq!(collector.get_balance(&token.address), 0);
+    assert_eq!(token.balance(&collector.address), 100);
+}
+
+#[test]
+fn test_distribute() {
+    let setup = Setup::default();
+    let collector = setup.collector;
+    let pool = Address::generate(&setup.env);
+    let treasury = Address::generate(&setup.env);
+    let insurance = Address::generate(&setup.env);
+    let lockers = Address::generate(&setup.env);
+    let token = create_token_contract(&setup.env, &setup.admin);
+    get_token_admin_client(&setup.env, &token.address).mint(&pool, &10_000);
+    collector.set_token_allowed(&setup.admin, &token.address, &true);
+
+    let beneficiaries = vec![
+        &setup.env,
+        Beneficiary {
+            address: treasury.clone(),
+            weight: 5000,
+        },
+        Beneficiary {
+            address: insurance.clone(),
+            weight: 3333,
+        },
+        Beneficiary {
+            address: lockers.clone(),
+            weight: 1667,
+        },
+    ];
+    // first table is applied instantly
+    collector.commit_beneficiaries(&setup.admin, &beneficiaries);
+    collector.apply_beneficiaries(&setup.admin);
+    assert_eq!(collector.get_beneficiaries(), beneficiaries);
+
+    token.transfer(&pool, &collector.address, &1001);
+    assert_eq!(collector.distribute(&token.address), 999);
+    assert_eq!(token.balance(&treasury), 500);
+    assert_eq!(token.balance(&insurance), 333);
+    assert_eq!(token.balance(&lockers), 166);
+
+    // dust is carried forward
+    assert_eq!(collector.get_balance(&token.address), 2);
+    token.transfer(&pool, &collector.address, &998);
+    assert_eq!(collector.distribute(&token.address), 999);
+    assert_eq!(token.balance(&treasury), 1000);
+    assert_eq!(token.balance(&insurance), 666);
+    assert_eq!(token.balance(&lockers), 332);
+    assert_eq!(collector.get_balance(&token.address), 1);
+    assert_eq!(token.balance(&collector.address), 1);
+}
+
+#[test]
+fn test_beneficiaries_change_delay() {
+    let setup = Setup::default();
+    let collector = setup.collector;
+    let treasury = Address::generate(&setup.env);
+    let insurance = Address::generate(&setup.env);
+
+    collector.commit_beneficiaries(
+        &setup.admin,
+        &vec![
+            &setup.env,
+            Beneficiary {
+                address: treasury.clone(),
+                weight: 10000,
+            },
+        ],
+    );
+    collector.apply_beneficiaries(&setup.admin);
+
+    let new_beneficiaries = vec![
+        &setup.env,
+        Beneficiary {
+            address: treasury.clone(),
+            weight: 5000,
+        },
+        Beneficiary {
+            address: insurance.clone(),
+            weight: 5000,
+        },
+    ];
+    collector.commit_beneficiaries(&setup.admin, &new_beneficiaries);
+    assert_eq!(collector.get_future_beneficiaries(), new_beneficiaries);
+    jump(&setup.env, ADMIN_ACTIONS_DELAY - 1);
+    assert!(collector.try_apply_beneficiaries(&setup.admin).is_err());
+    jump(&setup.env, 1);
+    collector.apply_beneficiaries(&setup.admin);
+    assert_eq!(collector.get_beneficiaries(), new_beneficiaries);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #801)")]
+fn test_beneficiaries_bad_weights() {
+    let setup = Setup::default();
+    let collector = setup.collector;
+
+    collector.commit_beneficiaries(
+        &setup.admin,
+        &vec![
+            &setup.env,
+            Beneficiary {
+                address: Address::generate(&setup.env),
+                weight: 5000,
+            },
+            Beneficiary {
+                address: Address::generate(&setup.env),
+                weight: 4999,
+            },
+        ],
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #802)")]
+fn test_distribute_without_beneficiaries() {
+    let setup = Setup::default();
+    let token = create_token_contract(&setup.env, &setup.admin);
+    setup.collector.distribute(&token.address);
+}
+
+// configures conversion through a single standard pool. returns collector token, target token and path
+fn setup_conversion<'a>(
+    setup: &Setup<'a>,
+    period_cap: u128,
+) -> (
+    SorobanTokenClient<'a>,
+    SorobanTokenClient<'a>,
+    swap_router::Client<'a>,
+    ConversionPath,
+) {
+    let token = create_token_contract(&setup.env, &setup.admin);
+    let target_token = create_token_contract(&setup.env, &setup.admin);
+    let (router, pool_index) = create_router_with_pool(
+        &setup.env,
+        &setup.admin,
+        &token.address,
+        &target_token.address,
+        1_000_000_0000000,
+    );
+    setup.collector.set_conversion_config(
+        &setup.admin,
+        &ConversionConfig {
+            router: router.address.clone(),
+            target_token: target_token.address.clone(),
+            max_slippage: 100,
+            period: 86400,
+        },
+    );
+    setup
+        .collector
+        .set_token_allowed(&setup.admin, &token.address, &true);
+
+    let mut tokens = vec![
+        &setup.env,
+        token.address.clone(),
+        target_token.address.clone(),
+    ];
+    if target_token.address < token.address {
+        tokens = vec![
+            &setup.env,
+            target_token.address.clone(),
+            token.address.clone(),
+        ];
+    }
+    let path = ConversionPath {
+        swaps_chain: vec![
+            &setup.env,
+            (tokens, pool_index, target_token.address.clone()),
+        ],
+        period_cap,
+    };
+    setup
+        .collector
+        .set_conversion_path(&setup.admin, &token.address, &path);
+    (token, target_token, router, path)
+}
+
+#[test]
+fn test_convert() {
+    let setup = Setup::default();
+    let collector = &setup.collector;
+    let pool_user = Address::generate(&setup.env);
+    let (token, target_token, router, path) = setup_conversion(&setup, 2_000_0000000);
+    assert_eq!(collector.get_conversion_path(&token.address), path);
+    let (tokens, pool_index, _) = path.swaps_chain.get(0).unwrap();
+
+    get_token_admin_client(&setup.env, &token.address).mint(&pool_user, &1_000_0000000);
+    token.transfer(&pool_user, &collector.address, &1_000_0000000);
+    let estimate = router.estimate_swap(
+        &tokens,
+        &token.address,
+        &target_token.address,
+        &pool_index,
+        &600_0000000,
+    );
+
+    // out_min too far from the estimate
+    assert!(collector
+        .try_convert(
+            &setup.admin,
+            &token.address,
+            &600_0000000,
+            &(estimate * 98 / 100)
+        )
+        .is_err());
+
+    // part of the balance is converted
+    assert_eq!(
+        collector.convert(&setup.admin, &token.address, &600_0000000, &estimate),
+        estimate
+    );
+    assert_eq!(collector.get_balance(&token.address), 400_0000000);
+    assert_eq!(collector.get_balance(&target_token.address), estimate);
+    assert_eq!(token.balance(&collector.address), 400_0000000);
+    assert_eq!(target_token.balance(&collector.address), estimate as i128);
+
+    // more than collected
+    assert!(collector
+        .try_convert(&setup.admin, &token.address, &400_0000001, &1)
+        .is_err());
+
+    collector.convert(&setup.admin, &token.address, &400_0000000, &1);
+    assert_eq!(collector.get_balance(&token.address), 0);
+    assert_eq!(
+        collector.get_tokens(),
+        vec![&setup.env, target_token.address.clone()]
+    );
+}
+
+#[test]
+fn test_convert_by_keeper() {
+    let setup = Setup::default();
+    let collector = &setup.collector;
+    let pool_user = Address::generate(&setup.env);
+    let keeper = Address::generate(&setup.env);
+    let (token, _, _, _) = setup_conversion(&setup, 2_000_0000000);
+
+    collector.declare_role(
+        &setup.admin,
+        &Symbol::new(&setup.env, "Keeper"),
+        &true,
+        &false,
+    );
+    collector.set_role_addrs(
+        &setup.admin,
+        &Symbol::new(&setup.env, "Keeper"),
+        &vec![&setup.env, keeper.clone()],
+    );
+
+    get_token_admin_client(&setup.env, &token.address).mint(&pool_user, &1_000_0000000);
+    token.transfer(&pool_user, &collector.address, &1_000_0000000);
+    collector.convert(&keeper, &token.address, &1_000_0000000, &1);
+    assert_eq!(collector.get_balance(&token.address), 0);
+}
+
+#[test]
+fn test_convert_period_cap() {
+    let setup = Setup::default();
+    let collector = &setup.collector;
+    let pool_user = Address::generate(&setup.env);
+    let (token, _, _, _) = setup_conversion(&setup, 1_500_0000000);
+    let token_admin_client = get_token_admin_client(&setup.env, &token.address);
+
+    token_admin_client.mint(&pool_user, &3_000_0000000);
+    token.transfer(&pool_user, &collector.address, &3_000_0000000);
+    collector.convert(&setup.admin, &token.address, &1_000_0000000, &1);
+
+    // input amount within the same period exceeds the cap
+    assert!(collector
+        .try_convert(&setup.admin, &token.address, &500_0000001, &1)
+        .is_err());
+    collector.convert(&setup.admin, &token.address, &500_0000000, &1);
+
+    // cap is reset in the next period
+    jump(&setup.env, 86400);
+    collector.convert(&setup.admin, &token.address, &1_500_0000000, &1);
+    assert_eq!(collector.get_balance(&token.address), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #805)")]
+fn test_convert_path_not_approved() {
+    let setup = Setup::default();
+    let collector = &setup.collector;
+    let pool_user = Address::generate(&setup.env);
+    let (token, _, _, _) = setup_conversion(&setup, 1_000_0000000);
+
+    get_token_admin_client(&setup.env, &token.address).mint(&pool_user, &1_000_0000000);
+    token.transfer(&pool_user, &collector.address, &1_000_0000000);
+    collector.remove_conversion_path(&setup.admin, &token.address);
+    collector.convert(&setup.admin, &token.address, &1_000_0000000, &1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #805)")]
+fn test_set_conversion_path_bad_path() {
+    let setup = Setup::default();
+    let collector = &setup.collector;
+    let (token, _, _, path) = setup_conversion(&setup, 1_000_0000000);
+    let (tokens, pool_index, _) = path.swaps_chain.get(0).unwrap();
+
+    // path does not end with the target token
+    collector.set_conversion_path(
+        &setup.admin,
+        &token.address,
+        &ConversionPath {
+            swaps_chain: vec![&setup.env, (tokens, pool_index, token.address.clone())],
+            period_cap: 1_000_0000000,
+        },
+    );
+}
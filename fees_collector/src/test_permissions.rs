@@ -1,9 +1,10 @@
 #![cfg(test)]
 
+use crate::storage::{Beneficiary, ConversionConfig, ConversionPath};
 use crate::testutils::{create_contract, install_dummy_wasm, jump, Setup};
 use access_control::constants::ADMIN_ACTIONS_DELAY;
 use soroban_sdk::testutils::Address as _;
-use soroban_sdk::{symbol_short, Address, Env, Symbol};
+use soroban_sdk::{symbol_short, vec, Address, Env, Symbol, Vec};
 
 // test admin transfer ownership
 #[test]
@@ -375,3 +376,121 @@ fn test_set_emergency_mode_admin() {
         .try_set_emergency_mode(&setup.emergency_admin, &false)
         .is_ok());
 }
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_withdraw_unauthorized() {
+    let setup = Setup::default();
+    let collector = setup.collector;
+    let user = Address::generate(&setup.env);
+    let token = Address::generate(&setup.env);
+
+    collector.withdraw(&user, &token, &1, &user);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_set_fee_admin_unauthorized() {
+    let setup = Setup::default();
+    let collector = setup.collector;
+    let user = Address::generate(&setup.env);
+
+    collector.set_fee_admin(&user, &user);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_set_token_allowed_unauthorized() {
+    let setup = Setup::default();
+    let collector = setup.collector;
+    let user = Address::generate(&setup.env);
+    let token = Address::generate(&setup.env);
+
+    collector.set_token_allowed(&user, &token, &true);
+}
+
+#[test]
+fn test_fee_admin_transfer() {
+    let setup = Setup::default();
+    let collector = setup.collector;
+    let fee_admin = Address::generate(&setup.env);
+
+    collector.set_fee_admin(&setup.admin, &fee_admin);
+    assert_eq!(
+        collector.get_future_address(&Symbol::new(&setup.env, "FeeAdmin")),
+        fee_admin
+    );
+    // fee admin may be replaced instantly unless delay is enabled
+    let new_fee_admin = Address::generate(&setup.env);
+    collector.set_fee_admin(&setup.admin, &new_fee_admin);
+    assert_eq!(
+        collector.get_future_address(&Symbol::new(&setup.env, "FeeAdmin")),
+        new_fee_admin
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_commit_beneficiaries_unauthorized() {
+    let setup = Setup::default();
+    let collector = setup.collector;
+    let user = Address::generate(&setup.env);
+
+    collector.commit_beneficiaries(
+        &user,
+        &vec![
+            &setup.env,
+            Beneficiary {
+                address: user.clone(),
+                weight: 10000,
+            },
+        ],
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_set_conversion_config_unauthorized() {
+    let setup = Setup::default();
+    let collector = setup.collector;
+    let user = Address::generate(&setup.env);
+
+    collector.set_conversion_config(
+        &user,
+        &ConversionConfig {
+            router: Address::generate(&setup.env),
+            target_token: Address::generate(&setup.env),
+            max_slippage: 100,
+            period: 86400,
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_set_conversion_path_unauthorized() {
+    let setup = Setup::default();
+    let collector = setup.collector;
+    let user = Address::generate(&setup.env);
+    let token = Address::generate(&setup.env);
+
+    collector.set_conversion_path(
+        &user,
+        &token,
+        &ConversionPath {
+            swaps_chain: Vec::new(&setup.env),
+            period_cap: 1,
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_convert_unauthorized() {
+    let setup = Setup::default();
+    let collector = setup.collector;
+    let user = Address::generate(&setup.env);
+    let token = Address::generate(&setup.env);
+
+    collector.convert(&user, &token, &1, &1);
+}
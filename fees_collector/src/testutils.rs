@@ -2,7 +2,10 @@
 
 use crate::FeesCollectorClient;
 use soroban_sdk::testutils::{Address as _, Ledger, LedgerInfo};
-use soroban_sdk::{Address, BytesN, Env, Symbol};
+use soroban_sdk::token::{
+    StellarAssetClient as SorobanTokenAdminClient, TokenClient as SorobanTokenClient,
+};
+use soroban_sdk::{Address, BytesN, Env, Symbol, Vec};
 
 pub fn install_dummy_wasm<'a>(e: &Env) -> BytesN<32> {
     soroban_sdk::contractimport!(file = "../contracts/dummy_contract.wasm");
@@ -14,6 +17,21 @@ pub fn create_contract<'a>(e: &Env) -> FeesCollectorClient<'a> {
     client
 }
 
+pub(crate) fn create_token_contract<'a>(e: &Env, admin: &Address) -> SorobanTokenClient<'a> {
+    SorobanTokenClient::new(
+        e,
+        &e.register_stellar_asset_contract_v2(admin.clone())
+            .address(),
+    )
+}
+
+pub(crate) fn get_token_admin_client<'a>(
+    e: &Env,
+    address: &Address,
+) -> SorobanTokenAdminClient<'a> {
+    SorobanTokenAdminClient::new(e, address)
+}
+
 pub(crate) fn jump(e: &Env, time: u64) {
     e.ledger().set(LedgerInfo {
         timestamp: e.ledger().timestamp().saturating_add(time),
@@ -62,3 +80,92 @@ impl Default for Setup<'_> {
         }
     }
 }
+
+pub mod swap_router {
+    soroban_sdk::contractimport!(
+        file =
+            "../target/wasm32-unknown-unknown/release/soroban_liquidity_pool_router_contract.wasm"
+    );
+}
+
+pub mod liquidity_pool {
+    soroban_sdk::contractimport!(
+        file = "../target/wasm32-unknown-unknown/release/soroban_liquidity_pool_contract.wasm"
+    );
+}
+
+mod reward_boost_feed {
+    soroban_sdk::contractimport!(
+        file = "../target/wasm32-unknown-unknown/release/soroban_locker_feed_contract.wasm"
+    );
+}
+
+fn install_token_wasm(e: &Env) -> BytesN<32> {
+    soroban_sdk::contractimport!(
+        file = "../target/wasm32-unknown-unknown/release/soroban_token_contract.wasm"
+    );
+    e.deployer().upload_contract_wasm(WASM)
+}
+
+fn install_liq_pool_hash(e: &Env) -> BytesN<32> {
+    e.deployer().upload_contract_wasm(liquidity_pool::WASM)
+}
+
+fn install_stableswap_liq_pool_hash(e: &Env) -> BytesN<32> {
+    soroban_sdk::contractimport!(
+        file = "../target/wasm32-unknown-unknown/release/soroban_liquidity_pool_stableswap_contract.wasm"
+    );
+    e.deployer().upload_contract_wasm(WASM)
+}
+
+fn deploy_plane_contract<'a>(e: &Env) -> Address {
+    soroban_sdk::contractimport!(
+        file =
+            "../target/wasm32-unknown-unknown/release/soroban_liquidity_pool_plane_contract.wasm"
+    );
+    Client::new(e, &e.register(WASM, ())).address
+}
+
+// deploys router with single standard pool for the given tokens pair. returns router and pool index
+pub(crate) fn create_router_with_pool<'a>(
+    e: &Env,
+    admin: &Address,
+    token_a: &Address,
+    token_b: &Address,
+    reserve: u128,
+) -> (swap_router::Client<'a>, BytesN<32>) {
+    let router = swap_router::Client::new(e, &e.register(swap_router::WASM, ()));
+    let boost_feed = reward_boost_feed::Client::new(
+        e,
+        &e.register(
+            reward_boost_feed::WASM,
+            reward_boost_feed::Args::__constructor(admin, admin, admin),
+        ),
+    );
+    router.init_admin(admin);
+    router.set_pool_hash(admin, &install_liq_pool_hash(e));
+    router.set_stableswap_pool_hash(admin, &install_stableswap_liq_pool_hash(e));
+    router.set_token_hash(admin, &install_token_wasm(e));
+    router.set_reward_token(admin, token_a);
+    router.set_pools_plane(admin, &deploy_plane_contract(e));
+    router.configure_init_pool_payment(admin, token_a, &0, &0, &router.address);
+    router.set_reward_boost_config(admin, token_a, &boost_feed.address);
+
+    let mut tokens = Vec::from_array(e, [token_a.clone(), token_b.clone()]);
+    if token_b < token_a {
+        tokens = Vec::from_array(e, [token_b.clone(), token_a.clone()]);
+    }
+    let (pool_index, pool_address) = router.init_standard_pool(admin, &tokens, &30);
+
+    let token_a_admin_client = get_token_admin_client(e, token_a);
+    let token_b_admin_client = get_token_admin_client(e, token_b);
+    token_a_admin_client.mint(admin, &(reserve as i128));
+    token_b_admin_client.mint(admin, &(reserve as i128));
+    liquidity_pool::Client::new(e, &pool_address).deposit(
+        admin,
+        &Vec::from_array(e, [reserve, reserve]),
+        &1,
+    );
+
+    (router, pool_index)
+}
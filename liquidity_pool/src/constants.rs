@@ -1 +1,3 @@
 pub(crate) const FEE_MULTIPLIER: u128 = 10_000;
+pub(crate) const PRICE_PRECISION: u128 = 1_000_000_000_000_000_000; // 1e18
+pub(crate) const MINIMUM_LIQUIDITY: u128 = 1000; // shares locked on the first deposit
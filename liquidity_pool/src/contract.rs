@@ -1,28 +1,45 @@
-use crate::constants::FEE_MULTIPLIER;
+use crate::constants::{FEE_MULTIPLIER, MINIMUM_LIQUIDITY};
 use crate::errors::LiquidityPoolError;
+use crate::events::Events;
 use crate::plane::update_plane;
 use crate::plane_interface::Plane;
 use crate::pool;
-use crate::pool::{get_amount_out, get_amount_out_strict_receive};
+use crate::pool::{accrue_protocol_fee, get_amount_out, get_amount_out_strict_receive};
 use crate::pool_interface::{
-    AdminInterfaceTrait, LiquidityPoolCrunch, LiquidityPoolTrait, RewardsTrait,
-    UpgradeableContract, UpgradeableLPTokenTrait,
+    AdminInterfaceTrait, FlashLoanTrait, LiquidityPoolCrunch, LiquidityPoolTrait,
+    ProtocolFeesTrait, ReservesSyncTrait, RewardsTrait, UpgradeableContract,
+    UpgradeableLPTokenTrait,
 };
-use crate::rewards::get_rewards_manager;
+use crate::rewards::{get_rewards_manager, get_rewards_total_shares};
 use crate::storage::{
-    get_fee_fraction, get_is_killed_claim, get_is_killed_deposit, get_is_killed_swap, get_plane,
-    get_reserve_a, get_reserve_b, get_router, get_token_a, get_token_b, get_token_future_wasm,
-    has_plane, put_fee_fraction, put_reserve_a, put_reserve_b, put_token_a, put_token_b,
-    set_is_killed_claim, set_is_killed_deposit, set_is_killed_swap, set_plane, set_router,
-    set_token_future_wasm,
+    get_admin_actions_deadline, get_fee_fraction, get_fees_collector, get_future_fee_fraction,
+    get_future_protocol_fee_fraction, get_is_killed_claim, get_is_killed_deposit,
+    get_is_killed_swap, get_plane, get_price_cumulative_a, get_price_cumulative_b,
+    get_price_timestamp_last, get_protocol_fee_a, get_protocol_fee_b, get_protocol_fee_deadline,
+    get_protocol_fee_fraction, get_reserve_a, get_reserve_b, get_router, get_token_a, get_token_b,
+    get_token_future_wasm, has_plane, put_fee_fraction, put_reserve_a, put_reserve_b, put_token_a,
+    put_token_b, set_admin_actions_deadline, set_fees_collector, set_future_fee_fraction,
+    set_future_protocol_fee_fraction, set_is_killed_claim, set_is_killed_deposit,
+    set_is_killed_swap, set_plane, set_protocol_fee_a, set_protocol_fee_b,
+    set_protocol_fee_deadline, set_protocol_fee_fraction, set_router, set_token_future_wasm,
 };
 use crate::token::{create_contract, transfer_a, transfer_b};
 use access_control::access::{AccessControl, AccessControlTrait};
-use access_control::emergency::{get_emergency_mode, set_emergency_mode};
+use access_control::audit::{get_audit_log, get_audit_log_len, AuditRecord};
+use access_control::delays::{apply_delay, commit_delay, get_delay, revert_delay, DelayTarget};
+use access_control::emergency::{
+    get_emergency_mode, get_emergency_mode_expiration, get_emergency_quorum, set_emergency_mode,
+    set_emergency_quorum, vote_emergency_mode,
+};
 use access_control::errors::AccessControlError;
 use access_control::events::Events as AccessControlEvents;
-use access_control::interface::TransferableContract;
+use access_control::grant::RoleGrantTrait;
+use access_control::interface::{
+    AuditLogContract, ConfigurableDelaysContract, DelayedRolesContract, EmergencyQuorumContract,
+    RoleGrantsContract, RolesRegistryContract, TransferableContract,
+};
 use access_control::management::{MultipleAddressesManagementTrait, SingleAddressManagementTrait};
+use access_control::registry::RoleRegistryTrait;
 use access_control::role::Role;
 use access_control::role::SymbolRepresentation;
 use access_control::transfer::TransferOwnershipTrait;
@@ -40,12 +57,12 @@ use rewards::storage::{
 use soroban_fixed_point_math::SorobanFixedPoint;
 use soroban_sdk::token::TokenClient as SorobanTokenClient;
 use soroban_sdk::{
-    contract, contractimpl, contractmeta, panic_with_error, symbol_short, Address, BytesN, Env,
-    IntoVal, Map, Symbol, Val, Vec, U256,
+    contract, contractimpl, contractmeta, panic_with_error, symbol_short, Address, Bytes, BytesN,
+    Env, IntoVal, Map, Symbol, Val, Vec, U256,
 };
 use token_share::{
-    burn_shares, get_token_share, get_total_shares, get_user_balance_shares, mint_shares,
-    put_token_share, Client as LPTokenClient,
+    burn_shares, get_token_share, get_total_shares, get_user_balance_shares, lock_shares,
+    mint_shares, put_token_share, Client as LPTokenClient,
 };
 use upgrade::events::Events as UpgradeEvents;
 use upgrade::{apply_upgrade, commit_upgrade, revert_upgrade};
@@ -189,6 +206,7 @@ impl LiquidityPoolTrait for LiquidityPool {
         put_token_share(&e, share_contract);
         put_reserve_a(&e, 0);
         put_reserve_b(&e, 0);
+        pool::update_price_cumulative(&e);
 
         // update plane data for every pool update
         update_plane(&e);
@@ -250,15 +268,6 @@ impl LiquidityPoolTrait for LiquidityPool {
         }
 
         let (reserve_a, reserve_b) = (get_reserve_a(&e), get_reserve_b(&e));
-
-        // Before actual changes were made to the pool, update total rewards data and refresh/initialize user reward
-        let rewards = get_rewards_manager(&e);
-        let total_shares = get_total_shares(&e);
-        let user_shares = get_user_balance_shares(&e, &user);
-        rewards
-            .manager()
-            .checkpoint_user(&user, total_shares, user_shares);
-
         let desired_a = desired_amounts.get(0).unwrap();
         let desired_b = desired_amounts.get(1).unwrap();
 
@@ -266,81 +275,100 @@ impl LiquidityPoolTrait for LiquidityPool {
             panic_with_error!(&e, LiquidityPoolValidationError::AllCoinsRequired);
         }
 
-        let token_a_client = SorobanTokenClient::new(&e, &get_token_a(&e));
-        let token_b_client = SorobanTokenClient::new(&e, &get_token_b(&e));
         // transfer full amount then return back remaining parts to have tx auth deterministic
-        token_a_client.transfer(&user, &e.current_contract_address(), &(desired_a as i128));
-        token_b_client.transfer(&user, &e.current_contract_address(), &(desired_b as i128));
-
-        let (min_a, min_b) = (0, 0);
+        SorobanTokenClient::new(&e, &get_token_a(&e)).transfer(
+            &user,
+            &e.current_contract_address(),
+            &(desired_a as i128),
+        );
+        SorobanTokenClient::new(&e, &get_token_b(&e)).transfer(
+            &user,
+            &e.current_contract_address(),
+            &(desired_b as i128),
+        );
 
-        // Calculate deposit amounts
-        let amounts =
-            pool::get_deposit_amounts(&e, desired_a, min_a, desired_b, min_b, reserve_a, reserve_b);
+        Self::_deposit(&e, &user, desired_a, desired_b, min_shares)
+    }
 
-        // Increase reserves
-        put_reserve_a(&e, reserve_a + amounts.0);
-        put_reserve_b(&e, reserve_b + amounts.1);
+    // Deposits a single token into the pool.
+    // Part of the token is swapped internally, so the rest can be deposited in the pool ratio.
+    //
+    // # Arguments
+    //
+    // * `user` - The address of the user depositing the token.
+    // * `in_idx` - The index of the token to deposit.
+    // * `in_amount` - The amount of the token to deposit.
+    // * `min_shares` - The minimum amount of pool tokens to mint.
+    //
+    // # Returns
+    //
+    // A tuple containing a vector of actual amounts of each token deposited after the swap and a u128 representing the amount of pool tokens minted.
+    fn deposit_single(
+        e: Env,
+        user: Address,
+        in_idx: u32,
+        in_amount: u128,
+        min_shares: u128,
+    ) -> (Vec<u128>, u128) {
+        user.require_auth();
 
-        if amounts.0 < desired_a {
-            token_a_client.transfer(
-                &e.current_contract_address(),
-                &user,
-                &((desired_a - amounts.0) as i128),
-            );
+        if get_is_killed_deposit(&e) {
+            panic_with_error!(e, LiquidityPoolError::PoolDepositKilled);
         }
-        if amounts.1 < desired_b {
-            token_b_client.transfer(
-                &e.current_contract_address(),
-                &user,
-                &((desired_b - amounts.1) as i128),
-            );
+
+        if get_is_killed_swap(&e) {
+            panic_with_error!(e, LiquidityPoolError::PoolSwapKilled);
         }
 
-        // Now calculate how many new pool shares to mint
-        let (new_reserve_a, new_reserve_b) = (get_reserve_a(&e), get_reserve_b(&e));
-        let total_shares = get_total_shares(&e);
+        if in_idx > 1 {
+            panic_with_error!(&e, LiquidityPoolValidationError::InTokenOutOfBounds);
+        }
 
-        let zero = 0;
-        let new_total_shares = if reserve_a > zero && reserve_b > zero {
-            let shares_a = new_reserve_a.fixed_mul_floor(&e, &total_shares, &reserve_a);
-            let shares_b = new_reserve_b.fixed_mul_floor(&e, &total_shares, &reserve_b);
-            shares_a.min(shares_b)
-        } else {
-            // if .mul doesn't fail, sqrt also won't -> safe to unwrap
-            U256::from_u128(&e, new_reserve_a)
-                .mul(&U256::from_u128(&e, new_reserve_b))
-                .sqrt()
-                .to_u128()
-                .unwrap()
-        };
+        if in_amount == 0 {
+            panic_with_error!(e, LiquidityPoolValidationError::ZeroAmount);
+        }
 
-        let shares_to_mint = new_total_shares - total_shares;
-        if shares_to_mint < min_shares {
-            panic_with_error!(&e, LiquidityPoolValidationError::OutMinNotSatisfied);
+        let out_idx = 1 - in_idx;
+        let reserves = Self::get_reserves(e.clone());
+        let tokens = Self::get_tokens(e.clone());
+        let reserve_sell = reserves.get(in_idx).unwrap();
+        let reserve_buy = reserves.get(out_idx).unwrap();
+        if reserve_sell == 0 || reserve_buy == 0 {
+            panic_with_error!(&e, LiquidityPoolValidationError::EmptyPool);
         }
-        mint_shares(&e, &user, shares_to_mint as i128);
-        put_reserve_a(&e, new_reserve_a);
-        put_reserve_b(&e, new_reserve_b);
 
-        // Checkpoint resulting working balance
-        rewards.manager().update_working_balance(
+        let sell_token = tokens.get(in_idx).unwrap();
+        SorobanTokenClient::new(&e, &sell_token).transfer(
             &user,
-            new_total_shares,
-            user_shares + shares_to_mint,
+            &e.current_contract_address(),
+            &(in_amount as i128),
         );
 
-        // update plane data for every pool update
-        update_plane(&e);
+        // swap part of the token, bought amount is kept by the pool to be deposited
+        let swap_amount = pool::get_deposit_single_swap_amount(&e, in_amount, reserve_sell);
+        let (out, fee) = get_amount_out(&e, swap_amount, reserve_sell, reserve_buy);
+        pool::update_price_cumulative(&e);
+        let protocol_fee = accrue_protocol_fee(&e, out_idx, fee);
+        let (desired_a, desired_b) = if in_idx == 0 {
+            put_reserve_a(&e, reserve_sell + swap_amount);
+            put_reserve_b(&e, reserve_buy - out - protocol_fee);
+            (in_amount - swap_amount, out)
+        } else {
+            put_reserve_b(&e, reserve_sell + swap_amount);
+            put_reserve_a(&e, reserve_buy - out - protocol_fee);
+            (out, in_amount - swap_amount)
+        };
 
-        let amounts_vec = Vec::from_array(&e, [amounts.0, amounts.1]);
-        PoolEvents::new(&e).deposit_liquidity(
-            Self::get_tokens(e.clone()),
-            amounts_vec.clone(),
-            shares_to_mint,
+        PoolEvents::new(&e).trade(
+            user.clone(),
+            sell_token,
+            tokens.get(out_idx).unwrap(),
+            swap_amount,
+            out,
+            fee,
         );
 
-        (amounts_vec, shares_to_mint)
+        Self::_deposit(&e, &user, desired_a, desired_b, min_shares)
     }
 
     // Swaps tokens in the pool.
@@ -408,6 +436,7 @@ impl LiquidityPoolTrait for LiquidityPool {
         let sell_token_client = SorobanTokenClient::new(&e, &sell_token);
         sell_token_client.transfer(&user, &e.current_contract_address(), &(in_amount as i128));
 
+        pool::update_price_cumulative(&e);
         if in_idx == 0 {
             put_reserve_a(&e, reserve_a + in_amount);
         } else {
@@ -448,12 +477,14 @@ impl LiquidityPoolTrait for LiquidityPool {
             panic_with_error!(&e, LiquidityPoolError::InvariantDoesNotHold);
         }
 
+        // protocol share of the fee is kept by the pool but not counted in the reserves
+        let protocol_fee = accrue_protocol_fee(&e, out_idx, fee);
         if out_idx == 0 {
             transfer_a(&e, &user, out_a);
-            put_reserve_a(&e, reserve_a - out);
+            put_reserve_a(&e, reserve_a - out - protocol_fee);
         } else {
             transfer_b(&e, &user, out_b);
-            put_reserve_b(&e, reserve_b - out);
+            put_reserve_b(&e, reserve_b - out - protocol_fee);
         }
 
         // update plane data for every pool update
@@ -578,6 +609,7 @@ impl LiquidityPoolTrait for LiquidityPool {
             &((in_max - in_amount) as i128),
         );
 
+        pool::update_price_cumulative(&e);
         if in_idx == 0 {
             put_reserve_a(&e, reserve_a + in_amount);
         } else {
@@ -622,12 +654,14 @@ impl LiquidityPoolTrait for LiquidityPool {
             panic_with_error!(&e, LiquidityPoolError::InvariantDoesNotHold);
         }
 
+        // protocol share of the fee is kept by the pool but not counted in the reserves
+        let protocol_fee = accrue_protocol_fee(&e, out_idx, fee);
         if out_idx == 0 {
             transfer_a(&e, &user, out_a);
-            put_reserve_a(&e, reserve_a - out_amount);
+            put_reserve_a(&e, reserve_a - out_amount - protocol_fee);
         } else {
             transfer_b(&e, &user, out_b);
-            put_reserve_b(&e, reserve_b - out_amount);
+            put_reserve_b(&e, reserve_b - out_amount - protocol_fee);
         }
 
         // update plane data for every pool update
@@ -699,10 +733,11 @@ impl LiquidityPoolTrait for LiquidityPool {
         // Before actual changes were made to the pool, update total rewards data and refresh user reward
         let rewards = get_rewards_manager(&e);
         let total_shares = get_total_shares(&e);
+        let rewards_total_shares = get_rewards_total_shares(&e);
         let user_shares = get_user_balance_shares(&e, &user);
         rewards
             .manager()
-            .checkpoint_user(&user, total_shares, user_shares);
+            .checkpoint_user(&user, rewards_total_shares, user_shares);
 
         burn_shares(&e, &user, share_amount);
 
@@ -721,13 +756,14 @@ impl LiquidityPoolTrait for LiquidityPool {
 
         transfer_a(&e, &user, out_a);
         transfer_b(&e, &user, out_b);
+        pool::update_price_cumulative(&e);
         put_reserve_a(&e, reserve_a - out_a);
         put_reserve_b(&e, reserve_b - out_b);
 
         // Checkpoint resulting working balance
         rewards.manager().update_working_balance(
             &user,
-            total_shares - share_amount,
+            rewards_total_shares - share_amount,
             user_shares - share_amount,
         );
 
@@ -744,6 +780,109 @@ impl LiquidityPoolTrait for LiquidityPool {
         withdraw_amounts
     }
 
+    // Calculates the amount received when withdrawing a single token.
+    //
+    // # Arguments
+    //
+    // * `share_amount` - The amount of pool tokens to burn.
+    // * `i` - The index of the token to withdraw.
+    //
+    // # Returns
+    //
+    // The amount of the token to be received.
+    fn calc_withdraw_one_coin(e: Env, share_amount: u128, i: u32) -> u128 {
+        Self::_calc_withdraw_one_coin(&e, share_amount, i).0
+    }
+
+    // Withdraws a single token from the pool.
+    // Share of the other token is swapped internally into the token being withdrawn.
+    //
+    // # Arguments
+    //
+    // * `user` - The address of the user withdrawing the token.
+    // * `share_amount` - The amount of pool tokens to burn.
+    // * `i` - The index of the token to withdraw.
+    // * `min_amount` - The minimum amount of the token to be received.
+    //
+    // # Returns
+    //
+    // A vector of the amounts of each token withdrawn.
+    fn withdraw_one_coin(
+        e: Env,
+        user: Address,
+        share_amount: u128,
+        i: u32,
+        min_amount: u128,
+    ) -> Vec<u128> {
+        user.require_auth();
+
+        // other token share is swapped within the withdrawal
+        if get_is_killed_swap(&e) {
+            panic_with_error!(e, LiquidityPoolError::PoolSwapKilled);
+        }
+        if share_amount == 0 {
+            panic_with_error!(&e, LiquidityPoolValidationError::ZeroAmount);
+        }
+
+        // Before actual changes were made to the pool, update total rewards data and refresh user reward
+        let rewards = get_rewards_manager(&e);
+        let total_shares = get_rewards_total_shares(&e);
+        let user_shares = get_user_balance_shares(&e, &user);
+        rewards
+            .manager()
+            .checkpoint_user(&user, total_shares, user_shares);
+
+        let (amount, swap_in, swap_out, fee) = Self::_calc_withdraw_one_coin(&e, share_amount, i);
+        if amount < min_amount {
+            panic_with_error!(&e, LiquidityPoolValidationError::OutMinNotSatisfied);
+        }
+
+        burn_shares(&e, &user, share_amount);
+
+        let tokens = Self::get_tokens(e.clone());
+        let reserves = Self::get_reserves(e.clone());
+
+        // withdrawn share of the other token is sold back to the pool, so only token `i` reserve changes
+        pool::update_price_cumulative(&e);
+        let protocol_fee = accrue_protocol_fee(&e, i, fee);
+        if i == 0 {
+            transfer_a(&e, &user, amount);
+            put_reserve_a(&e, reserves.get(0).unwrap() - amount - protocol_fee);
+        } else {
+            transfer_b(&e, &user, amount);
+            put_reserve_b(&e, reserves.get(1).unwrap() - amount - protocol_fee);
+        }
+
+        // Checkpoint resulting working balance
+        rewards.manager().update_working_balance(
+            &user,
+            total_shares - share_amount,
+            user_shares - share_amount,
+        );
+
+        // update plane data for every pool update
+        update_plane(&e);
+
+        // report proportional withdrawal followed by the internal swap
+        let mut withdraw_amounts = Vec::from_array(&e, [0, 0]);
+        withdraw_amounts.set(i, amount - swap_out);
+        withdraw_amounts.set(1 - i, swap_in);
+        let pool_events = PoolEvents::new(&e);
+        pool_events.withdraw_liquidity(tokens.clone(), withdraw_amounts, share_amount);
+        pool_events.trade(
+            user,
+            tokens.get(1 - i).unwrap(),
+            tokens.get(i).unwrap(),
+            swap_in,
+            swap_out,
+            fee,
+        );
+
+        let mut amounts = Vec::from_array(&e, [0, 0]);
+        amounts.set(i, amount);
+        amounts
+    }
+
     // Returns the pool's reserves.
     //
     // # Returns
@@ -753,6 +892,26 @@ impl LiquidityPoolTrait for LiquidityPool {
         Vec::from_array(&e, [get_reserve_a(&e), get_reserve_b(&e)])
     }
 
+    // Returns the pool's cumulative prices, updated on every reserves change.
+    // The average price over a period is the difference of the cumulative prices
+    // divided by the time elapsed.
+    //
+    // # Returns
+    //
+    // A vector of the cumulative prices of token a in token b and token b in token a, scaled by 1e18.
+    fn get_price_cumulative(e: Env) -> Vec<U256> {
+        Vec::from_array(&e, [get_price_cumulative_a(&e), get_price_cumulative_b(&e)])
+    }
+
+    // Returns the timestamp of the last cumulative prices update.
+    //
+    // # Returns
+    //
+    // The timestamp of the last cumulative prices update.
+    fn get_price_timestamp_last(e: Env) -> u64 {
+        get_price_timestamp_last(&e)
+    }
+
     // Returns the pool's fee fraction.
     //
     // # Returns
@@ -778,91 +937,362 @@ impl LiquidityPoolTrait for LiquidityPool {
     }
 }
 
-#[contractimpl]
-impl AdminInterfaceTrait for LiquidityPool {
-    // Sets the privileged addresses.
+impl LiquidityPool {
+    // Reward amount configured for the users and not claimed yet.
+    fn _get_reward_liability(e: &Env) -> u128 {
+        let rewards = get_rewards_manager(e);
+        let mut rewards_manager = rewards.manager();
+        let total_shares = get_rewards_total_shares(e);
+        rewards_manager.get_total_configured_reward(total_shares)
+            - rewards_manager.get_total_claimed_reward(total_shares)
+    }
+
+    // Calculates token balances of the pool not accounted in the reserves, protocol fees or rewards.
     //
-    // # Arguments
+    // # Returns
     //
-    // * `admin` - The address of the admin.
-    // * `rewards_admin` - The address of the rewards admin.
-    // * `operations_admin` - The address of the operations admin.
-    // * `pause_admin` - The address of the pause admin.
-    // * `emergency_pause_admin` - The addresses of the emergency pause admins.
-    fn set_privileged_addrs(
-        e: Env,
-        admin: Address,
-        rewards_admin: Address,
-        operations_admin: Address,
-        pause_admin: Address,
-        emergency_pause_admins: Vec<Address>,
-    ) {
-        admin.require_auth();
-        let access_control = AccessControl::new(&e);
-        access_control.assert_address_has_role(&admin, &Role::Admin);
+    // * A vector of the excess amounts of each token.
+    fn _get_excess_balances(e: &Env) -> Vec<u128> {
+        let tokens = Self::get_tokens(e.clone());
+        let reserves = Self::get_reserves(e.clone());
+        let protocol_fees = Self::get_protocol_fees(e.clone());
+        let rewards_storage = get_rewards_manager(e).storage();
+        let reward_token = if rewards_storage.has_reward_token() {
+            Some(rewards_storage.get_reward_token())
+        } else {
+            None
+        };
 
-        access_control.set_role_address(&Role::RewardsAdmin, &rewards_admin);
-        access_control.set_role_address(&Role::OperationsAdmin, &operations_admin);
-        access_control.set_role_address(&Role::PauseAdmin, &pause_admin);
-        access_control.set_role_addresses(&Role::EmergencyPauseAdmin, &emergency_pause_admins);
-        AccessControlEvents::new(&e).set_privileged_addrs(
-            rewards_admin,
-            operations_admin,
-            pause_admin,
-            emergency_pause_admins,
-        );
+        let mut amounts = Vec::new(e);
+        for i in 0..tokens.len() {
+            let token = tokens.get(i).unwrap();
+            let balance = SorobanTokenClient::new(e, &token).balance(&e.current_contract_address());
+            let mut accounted = reserves.get(i).unwrap() + protocol_fees.get(i).unwrap();
+            // reward token balance also backs the rewards configured and not claimed yet
+            if reward_token == Some(token) {
+                accounted += Self::_get_reward_liability(e);
+            }
+            amounts.push_back((balance as u128).saturating_sub(accounted));
+        }
+        amounts
     }
 
-    // Returns a map of privileged roles.
+    // Calculates the amount received when withdrawing a single token.
+    //
+    // # Arguments
+    //
+    // * `share_amount` - The amount of pool tokens to burn.
+    // * `i` - The index of the token to withdraw.
     //
     // # Returns
     //
-    // A map of privileged roles to their respective addresses.
-    fn get_privileged_addrs(e: Env) -> Map<Symbol, Vec<Address>> {
-        let access_control = AccessControl::new(&e);
-        let mut result: Map<Symbol, Vec<Address>> = Map::new(&e);
-        for role in [
-            Role::Admin,
-            Role::EmergencyAdmin,
-            Role::RewardsAdmin,
-            Role::OperationsAdmin,
-            Role::PauseAdmin,
-        ] {
-            result.set(
-                role.as_symbol(&e),
-                match access_control.get_role_safe(&role) {
-                    Some(v) => Vec::from_array(&e, [v]),
-                    None => Vec::new(&e),
-                },
-            );
+    // * (Amount of token `i` to withdraw, amount of the other token swapped, amount of token `i` bought, swap fee)
+    fn _calc_withdraw_one_coin(e: &Env, share_amount: u128, i: u32) -> (u128, u128, u128, u128) {
+        if i > 1 {
+            panic_with_error!(e, LiquidityPoolValidationError::OutTokenOutOfBounds);
         }
 
-        result.set(
-            Role::EmergencyPauseAdmin.as_symbol(&e),
-            access_control.get_role_addresses(&Role::EmergencyPauseAdmin),
-        );
+        let total_shares = get_total_shares(e);
+        if total_shares == 0 {
+            panic_with_error!(e, LiquidityPoolValidationError::EmptyPool);
+        }
+        if share_amount > total_shares {
+            panic_with_error!(e, LiquidityPoolValidationError::TooManySharesBurned);
+        }
 
-        result
+        let reserves = Self::get_reserves(e.clone());
+        let reserve_out = reserves.get(i).unwrap();
+        let reserve_other = reserves.get(1 - i).unwrap();
+        let out = reserve_out.fixed_mul_floor(e, &share_amount, &total_shares);
+        let out_other = reserve_other.fixed_mul_floor(e, &share_amount, &total_shares);
+
+        // the other token is swapped against the reserves left after the proportional withdrawal
+        if reserve_out == out || reserve_other == out_other {
+            panic_with_error!(e, LiquidityPoolValidationError::EmptyPool);
+        }
+        let (swap_out, fee) =
+            get_amount_out(e, out_other, reserve_other - out_other, reserve_out - out);
+        (out + swap_out, out_other, swap_out, fee)
     }
 
-    // Stops the pool deposits instantly.
+    // Deposits tokens already transferred to the pool, returning to the user parts exceeding the pool ratio.
     //
     // # Arguments
     //
-    // * `admin` - The address of the admin.
-    fn kill_deposit(e: Env, admin: Address) {
-        admin.require_auth();
-        require_pause_or_emergency_pause_admin_or_owner(&e, &admin);
-
-        set_is_killed_deposit(&e, &true);
-        PoolEvents::new(&e).kill_deposit();
-    }
-
-    // Stops the pool swaps instantly.
+    // * `user` - The address of the user depositing the tokens.
+    // * `desired_a` - The amount of token a transferred to the pool.
+    // * `desired_b` - The amount of token b transferred to the pool.
+    // * `min_shares` - The minimum amount of pool tokens to mint.
     //
-    // # Arguments
+    // # Returns
     //
-    // * `admin` - The address of the admin.
+    // A tuple containing a vector of actual amounts of each token deposited and a u128 representing the amount of pool tokens minted.
+    fn _deposit(
+        e: &Env,
+        user: &Address,
+        desired_a: u128,
+        desired_b: u128,
+        min_shares: u128,
+    ) -> (Vec<u128>, u128) {
+        let (reserve_a, reserve_b) = (get_reserve_a(e), get_reserve_b(e));
+
+        // Before actual changes were made to the pool, update total rewards data and refresh/initialize user reward
+        let rewards = get_rewards_manager(e);
+        let rewards_total_shares = get_rewards_total_shares(e);
+        let user_shares = get_user_balance_shares(e, user);
+        rewards
+            .manager()
+            .checkpoint_user(user, rewards_total_shares, user_shares);
+
+        let token_a_client = SorobanTokenClient::new(e, &get_token_a(e));
+        let token_b_client = SorobanTokenClient::new(e, &get_token_b(e));
+
+        let (min_a, min_b) = (0, 0);
+
+        // Calculate deposit amounts
+        let amounts =
+            pool::get_deposit_amounts(e, desired_a, min_a, desired_b, min_b, reserve_a, reserve_b);
+
+        // Increase reserves
+        pool::update_price_cumulative(e);
+        put_reserve_a(e, reserve_a + amounts.0);
+        put_reserve_b(e, reserve_b + amounts.1);
+
+        if amounts.0 < desired_a {
+            token_a_client.transfer(
+                &e.current_contract_address(),
+                user,
+                &((desired_a - amounts.0) as i128),
+            );
+        }
+        if amounts.1 < desired_b {
+            token_b_client.transfer(
+                &e.current_contract_address(),
+                user,
+                &((desired_b - amounts.1) as i128),
+            );
+        }
+
+        // Now calculate how many new pool shares to mint
+        let (new_reserve_a, new_reserve_b) = (get_reserve_a(e), get_reserve_b(e));
+        let total_shares = get_total_shares(e);
+
+        let zero = 0;
+        let new_total_shares = if reserve_a > zero && reserve_b > zero {
+            let shares_a = new_reserve_a.fixed_mul_floor(e, &total_shares, &reserve_a);
+            let shares_b = new_reserve_b.fixed_mul_floor(e, &total_shares, &reserve_b);
+            shares_a.min(shares_b)
+        } else {
+            // if .mul doesn't fail, sqrt also won't -> safe to unwrap
+            U256::from_u128(e, new_reserve_a)
+                .mul(&U256::from_u128(e, new_reserve_b))
+                .sqrt()
+                .to_u128()
+                .unwrap()
+        };
+
+        let mut shares_to_mint = new_total_shares - total_shares;
+        if total_shares == 0 {
+            // first deposit: lock minimum liquidity forever so the share price can't be inflated
+            if shares_to_mint <= MINIMUM_LIQUIDITY {
+                panic_with_error!(
+                    e,
+                    LiquidityPoolValidationError::InsufficientInitialLiquidity
+                );
+            }
+            lock_shares(e, MINIMUM_LIQUIDITY);
+            shares_to_mint -= MINIMUM_LIQUIDITY;
+        }
+        if shares_to_mint < min_shares {
+            panic_with_error!(e, LiquidityPoolValidationError::OutMinNotSatisfied);
+        }
+        mint_shares(e, user, shares_to_mint as i128);
+        put_reserve_a(e, new_reserve_a);
+        put_reserve_b(e, new_reserve_b);
+
+        // Checkpoint resulting working balance
+        rewards.manager().update_working_balance(
+            user,
+            rewards_total_shares + shares_to_mint,
+            user_shares + shares_to_mint,
+        );
+
+        // update plane data for every pool update
+        update_plane(e);
+
+        let amounts_vec = Vec::from_array(e, [amounts.0, amounts.1]);
+        PoolEvents::new(e).deposit_liquidity(
+            Self::get_tokens(e.clone()),
+            amounts_vec.clone(),
+            shares_to_mint,
+        );
+
+        (amounts_vec, shares_to_mint)
+    }
+}
+
+#[contractimpl]
+impl AdminInterfaceTrait for LiquidityPool {
+    // Sets the privileged addresses.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `rewards_admin` - The address of the rewards admin.
+    // * `operations_admin` - The address of the operations admin.
+    // * `pause_admin` - The address of the pause admin.
+    // * `emergency_pause_admin` - The addresses of the emergency pause admins.
+    fn set_privileged_addrs(
+        e: Env,
+        admin: Address,
+        rewards_admin: Address,
+        operations_admin: Address,
+        pause_admin: Address,
+        emergency_pause_admins: Vec<Address>,
+    ) {
+        admin.require_auth();
+        let access_control = AccessControl::new(&e);
+        access_control.assert_address_has_role(&admin, &Role::Admin);
+
+        access_control.set_role_address(&Role::RewardsAdmin, &rewards_admin);
+        access_control.set_role_address(&Role::OperationsAdmin, &operations_admin);
+        access_control.set_role_address(&Role::PauseAdmin, &pause_admin);
+        access_control.set_role_addresses(&Role::EmergencyPauseAdmin, &emergency_pause_admins);
+        AccessControlEvents::new(&e).set_privileged_addrs(
+            rewards_admin,
+            operations_admin,
+            pause_admin,
+            emergency_pause_admins,
+        );
+    }
+
+    // Returns a map of privileged roles.
+    //
+    // # Returns
+    //
+    // A map of privileged roles to their respective addresses.
+    fn get_privileged_addrs(e: Env) -> Map<Symbol, Vec<Address>> {
+        let access_control = AccessControl::new(&e);
+        let mut result: Map<Symbol, Vec<Address>> = Map::new(&e);
+        for role in [
+            Role::Admin,
+            Role::EmergencyAdmin,
+            Role::RewardsAdmin,
+            Role::OperationsAdmin,
+            Role::PauseAdmin,
+        ] {
+            result.set(
+                role.as_symbol(&e),
+                match access_control.get_role_safe(&role) {
+                    Some(v) => Vec::from_array(&e, [v]),
+                    None => Vec::new(&e),
+                },
+            );
+        }
+
+        result.set(
+            Role::EmergencyPauseAdmin.as_symbol(&e),
+            access_control.get_role_addresses(&Role::EmergencyPauseAdmin),
+        );
+
+        for role_name in access_control.get_declared_roles() {
+            let role = Role::from_symbol(&e, role_name.clone());
+            result.set(role_name, access_control.get_role_addrs(&role));
+        }
+
+        result
+    }
+
+    // Sets a new fee to be applied in the future.
+    // Fee tier is part of the router pool index, so only the router can change it.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the router.
+    // * `new_fee` - The new fee to be applied.
+    fn commit_new_fee(e: Env, admin: Address, new_fee: u32) {
+        admin.require_auth();
+        if admin != get_router(&e) {
+            panic_with_error!(&e, AccessControlError::Unauthorized);
+        }
+
+        if get_admin_actions_deadline(&e) != 0 {
+            panic_with_error!(&e, LiquidityPoolError::AnotherActionActive);
+        }
+        if new_fee as u128 > FEE_MULTIPLIER - 1 {
+            panic_with_error!(&e, LiquidityPoolValidationError::FeeOutOfBounds);
+        }
+
+        let deadline = e.ledger().timestamp() + get_delay(&e, &DelayTarget::AdminActions);
+        set_admin_actions_deadline(&e, &deadline);
+        set_future_fee_fraction(&e, &new_fee);
+
+        Events::new(&e).commit_new_fee(new_fee);
+    }
+
+    // Applies the committed fee.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the router.
+    fn apply_new_fee(e: Env, admin: Address) {
+        admin.require_auth();
+        if admin != get_router(&e) {
+            panic_with_error!(&e, AccessControlError::Unauthorized);
+        }
+
+        let deadline = get_admin_actions_deadline(&e);
+        if deadline == 0 {
+            panic_with_error!(&e, LiquidityPoolError::NoActionActive);
+        }
+        if e.ledger().timestamp() < deadline {
+            panic_with_error!(&e, LiquidityPoolError::ActionNotReadyYet);
+        }
+
+        set_admin_actions_deadline(&e, &0);
+        let fee = get_future_fee_fraction(&e);
+        put_fee_fraction(&e, fee);
+
+        // update plane data for every pool update
+        update_plane(&e);
+
+        Events::new(&e).apply_new_fee(fee);
+    }
+
+    // Reverts the committed parameters to their current values.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the router.
+    fn revert_new_parameters(e: Env, admin: Address) {
+        admin.require_auth();
+        if admin != get_router(&e) {
+            panic_with_error!(&e, AccessControlError::Unauthorized);
+        }
+
+        set_admin_actions_deadline(&e, &0);
+
+        Events::new(&e).revert_new_parameters();
+    }
+
+    // Stops the pool deposits instantly.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    fn kill_deposit(e: Env, admin: Address) {
+        admin.require_auth();
+        require_pause_or_emergency_pause_admin_or_owner(&e, &admin);
+
+        set_is_killed_deposit(&e, &true);
+        PoolEvents::new(&e).kill_deposit();
+    }
+
+    // Stops the pool swaps instantly.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
     fn kill_swap(e: Env, admin: Address) {
         admin.require_auth();
         require_pause_or_emergency_pause_admin_or_owner(&e, &admin);
@@ -871,71 +1301,367 @@ impl AdminInterfaceTrait for LiquidityPool {
         PoolEvents::new(&e).kill_swap();
     }
 
-    // Stops the pool claims instantly.
-    //
-    // # Arguments
-    //
-    // * `admin` - The address of the admin.
-    fn kill_claim(e: Env, admin: Address) {
-        admin.require_auth();
-        require_pause_or_emergency_pause_admin_or_owner(&e, &admin);
+    // Stops the pool claims instantly.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    fn kill_claim(e: Env, admin: Address) {
+        admin.require_auth();
+        require_pause_or_emergency_pause_admin_or_owner(&e, &admin);
+
+        set_is_killed_claim(&e, &true);
+        PoolEvents::new(&e).kill_claim();
+    }
+
+    // Resumes the pool deposits.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    fn unkill_deposit(e: Env, admin: Address) {
+        admin.require_auth();
+        require_pause_admin_or_owner(&e, &admin);
+
+        set_is_killed_deposit(&e, &false);
+        PoolEvents::new(&e).unkill_deposit();
+    }
+
+    // Resumes the pool swaps.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    fn unkill_swap(e: Env, admin: Address) {
+        admin.require_auth();
+        require_pause_admin_or_owner(&e, &admin);
+
+        set_is_killed_swap(&e, &false);
+        PoolEvents::new(&e).unkill_swap();
+    }
+
+    // Resumes the pool claims.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    fn unkill_claim(e: Env, admin: Address) {
+        admin.require_auth();
+        require_pause_admin_or_owner(&e, &admin);
+
+        set_is_killed_claim(&e, &false);
+        PoolEvents::new(&e).unkill_claim();
+    }
+
+    // Get deposit killswitch status.
+    fn get_is_killed_deposit(e: Env) -> bool {
+        get_is_killed_deposit(&e)
+    }
+
+    // Get swap killswitch status.
+    fn get_is_killed_swap(e: Env) -> bool {
+        get_is_killed_swap(&e)
+    }
+
+    // Get claim killswitch status.
+    fn get_is_killed_claim(e: Env) -> bool {
+        get_is_killed_claim(&e)
+    }
+}
+
+// The `ProtocolFeesTrait` trait provides the interface for managing the protocol share of the swap fee.
+#[contractimpl]
+impl ProtocolFeesTrait for LiquidityPool {
+    // Commits a new protocol share of the swap fee to be applied after the admin actions delay.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the router or the admin.
+    // * `new_fraction` - The share of the swap fee taken by the protocol. 1 = 0.01%, 10000 = 100%
+    fn commit_protocol_fee(e: Env, admin: Address, new_fraction: u32) {
+        admin.require_auth();
+
+        // owner and router are privileged to configure the protocol fee
+        if admin != get_router(&e) {
+            AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        }
+
+        if get_protocol_fee_deadline(&e) != 0 {
+            panic_with_error!(&e, LiquidityPoolError::AnotherActionActive);
+        }
+        if new_fraction as u128 > FEE_MULTIPLIER {
+            panic_with_error!(&e, LiquidityPoolValidationError::AdminFeeOutOfBounds);
+        }
+
+        let deadline = e.ledger().timestamp() + get_delay(&e, &DelayTarget::AdminActions);
+        set_protocol_fee_deadline(&e, &deadline);
+        set_future_protocol_fee_fraction(&e, &new_fraction);
+
+        Events::new(&e).commit_protocol_fee(new_fraction);
+    }
+
+    // Applies the committed protocol fee share.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the router or the admin.
+    fn apply_protocol_fee(e: Env, admin: Address) {
+        admin.require_auth();
+        if admin != get_router(&e) {
+            AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        }
+
+        let deadline = get_protocol_fee_deadline(&e);
+        if deadline == 0 {
+            panic_with_error!(&e, LiquidityPoolError::NoActionActive);
+        }
+        if e.ledger().timestamp() < deadline {
+            panic_with_error!(&e, LiquidityPoolError::ActionNotReadyYet);
+        }
+
+        set_protocol_fee_deadline(&e, &0);
+        let fraction = get_future_protocol_fee_fraction(&e);
+        set_protocol_fee_fraction(&e, &fraction);
+
+        Events::new(&e).apply_protocol_fee(fraction);
+    }
+
+    // Reverts the committed protocol fee share.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the router or the admin.
+    fn revert_protocol_fee(e: Env, admin: Address) {
+        admin.require_auth();
+        if admin != get_router(&e) {
+            AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        }
+
+        set_protocol_fee_deadline(&e, &0);
+
+        Events::new(&e).revert_protocol_fee();
+    }
+
+    // Returns the share of the swap fee taken by the protocol. 1 = 0.01%
+    fn get_protocol_fee_fraction(e: Env) -> u32 {
+        get_protocol_fee_fraction(&e)
+    }
+
+    // Returns the protocol fees accrued for each token and not claimed yet.
+    fn get_protocol_fees(e: Env) -> Vec<u128> {
+        Vec::from_array(&e, [get_protocol_fee_a(&e), get_protocol_fee_b(&e)])
+    }
+
+    // Sets the address receiving the claimed protocol fees.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the router or the admin.
+    // * `fees_collector` - The address of the fees collector.
+    fn set_fees_collector(e: Env, admin: Address, fees_collector: Address) {
+        admin.require_auth();
+        if admin != get_router(&e) {
+            AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        }
+
+        set_fees_collector(&e, &fees_collector);
+
+        Events::new(&e).set_fees_collector(fees_collector);
+    }
+
+    // Returns the address receiving the claimed protocol fees.
+    fn get_fees_collector(e: Env) -> Address {
+        get_fees_collector(&e)
+    }
+
+    // Sends the accrued protocol fees to the fees collector.
+    // Can be called by anyone.
+    //
+    // # Returns
+    //
+    // The amounts of each token sent.
+    fn claim_protocol_fees(e: Env) -> Vec<u128> {
+        let fees_collector = get_fees_collector(&e);
+        let (fee_a, fee_b) = (get_protocol_fee_a(&e), get_protocol_fee_b(&e));
+
+        set_protocol_fee_a(&e, &0);
+        set_protocol_fee_b(&e, &0);
+        if fee_a > 0 {
+            SorobanTokenClient::new(&e, &get_token_a(&e)).transfer(
+                &e.current_contract_address(),
+                &fees_collector,
+                &(fee_a as i128),
+            );
+        }
+        if fee_b > 0 {
+            SorobanTokenClient::new(&e, &get_token_b(&e)).transfer(
+                &e.current_contract_address(),
+                &fees_collector,
+                &(fee_b as i128),
+            );
+        }
+
+        let amounts = Vec::from_array(&e, [fee_a, fee_b]);
+        Events::new(&e).claim_protocol_fees(fees_collector, amounts.clone());
+        amounts
+    }
+}
+
+// The `FlashLoanTrait` trait provides the interface for lending pool tokens within a single transaction.
+#[contractimpl]
+impl FlashLoanTrait for LiquidityPool {
+    // Lends pool tokens to the receiver contract and invokes `on_flash_loan` callback on it.
+    // By the end of the callback, the borrowed amounts plus the flash fee should be returned to the pool.
+    // The flash fee is charged at the pool fee rate and distributed the same way as the swap fee.
+    //
+    // # Arguments
+    //
+    // * `receiver` - The address of the contract receiving the tokens.
+    // * `amounts` - The amounts of tokens to borrow, in the pool tokens order.
+    // * `data` - Arbitrary data passed to the receiver callback.
+    //
+    // # Returns
+    //
+    // The flash fees charged for every token.
+    fn flash_loan(e: Env, receiver: Address, amounts: Vec<u128>, data: Bytes) -> Vec<u128> {
+        receiver.require_auth();
+
+        if get_is_killed_swap(&e) {
+            panic_with_error!(e, LiquidityPoolError::PoolSwapKilled);
+        }
+
+        if amounts.len() != 2 {
+            panic_with_error!(&e, LiquidityPoolValidationError::WrongInputVecSize);
+        }
+
+        if amounts.iter().all(|amount| amount == 0) {
+            panic_with_error!(&e, LiquidityPoolValidationError::ZeroAmount);
+        }
+
+        let tokens = Self::get_tokens(e.clone());
+        let reserves = Self::get_reserves(e.clone());
+        let fee_fraction = get_fee_fraction(&e) as u128;
+
+        let mut fees = Vec::new(&e);
+        let mut balances_before = Vec::new(&e);
+        for i in 0..tokens.len() {
+            let amount = amounts.get(i).unwrap();
+            if amount > reserves.get(i).unwrap() {
+                panic_with_error!(&e, LiquidityPoolValidationError::InsufficientBalance);
+            }
+            fees.push_back(amount.fixed_mul_ceil(&e, &fee_fraction, &FEE_MULTIPLIER));
+
+            let token_client = SorobanTokenClient::new(&e, &tokens.get(i).unwrap());
+            balances_before.push_back(token_client.balance(&e.current_contract_address()));
+            if amount > 0 {
+                token_client.transfer(&e.current_contract_address(), &receiver, &(amount as i128));
+            }
+        }
+
+        // re-entrance is not allowed, so the pool state can't be changed within the callback
+        e.invoke_contract::<()>(
+            &receiver,
+            &Symbol::new(&e, "on_flash_loan"),
+            Vec::from_array(
+                &e,
+                [
+                    tokens.into_val(&e),
+                    amounts.into_val(&e),
+                    fees.into_val(&e),
+                    data.into_val(&e),
+                ],
+            ),
+        );
+
+        for i in 0..tokens.len() {
+            let balance = SorobanTokenClient::new(&e, &tokens.get(i).unwrap())
+                .balance(&e.current_contract_address());
+            if balance < balances_before.get(i).unwrap() + fees.get(i).unwrap() as i128 {
+                panic_with_error!(&e, LiquidityPoolValidationError::FlashLoanNotRepaid);
+            }
+        }
+
+        // flash fee goes to the reserves, protocol share is kept aside
+        pool::update_price_cumulative(&e);
+        let protocol_fee_a = accrue_protocol_fee(&e, 0, fees.get(0).unwrap());
+        let protocol_fee_b = accrue_protocol_fee(&e, 1, fees.get(1).unwrap());
+        put_reserve_a(
+            &e,
+            reserves.get(0).unwrap() + fees.get(0).unwrap() - protocol_fee_a,
+        );
+        put_reserve_b(
+            &e,
+            reserves.get(1).unwrap() + fees.get(1).unwrap() - protocol_fee_b,
+        );
+
+        // update plane data for every pool update
+        update_plane(&e);
+
+        PoolEvents::new(&e).flash_loan(receiver, tokens, amounts, fees.clone());
 
-        set_is_killed_claim(&e, &true);
-        PoolEvents::new(&e).kill_claim();
+        fees
     }
+}
 
-    // Resumes the pool deposits.
+// The `ReservesSyncTrait` trait provides the interface for reconciling the reserves with actual token balances.
+#[contractimpl]
+impl ReservesSyncTrait for LiquidityPool {
+    // Sends token balances exceeding the reserves to the given address.
+    // Tokens sent to the pool directly are recovered this way.
     //
     // # Arguments
     //
     // * `admin` - The address of the admin.
-    fn unkill_deposit(e: Env, admin: Address) {
-        admin.require_auth();
-        require_pause_admin_or_owner(&e, &admin);
-
-        set_is_killed_deposit(&e, &false);
-        PoolEvents::new(&e).unkill_deposit();
-    }
-
-    // Resumes the pool swaps.
+    // * `to` - The address receiving the tokens.
     //
-    // # Arguments
+    // # Returns
     //
-    // * `admin` - The address of the admin.
-    fn unkill_swap(e: Env, admin: Address) {
+    // A vector of the amounts of each token sent.
+    fn skim(e: Env, admin: Address, to: Address) -> Vec<u128> {
         admin.require_auth();
-        require_pause_admin_or_owner(&e, &admin);
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
 
-        set_is_killed_swap(&e, &false);
-        PoolEvents::new(&e).unkill_swap();
+        let tokens = Self::get_tokens(e.clone());
+        let amounts = Self::_get_excess_balances(&e);
+        for i in 0..tokens.len() {
+            let amount = amounts.get(i).unwrap();
+            if amount > 0 {
+                SorobanTokenClient::new(&e, &tokens.get(i).unwrap()).transfer(
+                    &e.current_contract_address(),
+                    &to,
+                    &(amount as i128),
+                );
+            }
+        }
+
+        Events::new(&e).skim(to, amounts.clone());
+        amounts
     }
 
-    // Resumes the pool claims.
+    // Adds token balances exceeding the reserves to the reserves, distributing them to liquidity providers.
     //
-    // # Arguments
+    // # Returns
     //
-    // * `admin` - The address of the admin.
-    fn unkill_claim(e: Env, admin: Address) {
-        admin.require_auth();
-        require_pause_admin_or_owner(&e, &admin);
-
-        set_is_killed_claim(&e, &false);
-        PoolEvents::new(&e).unkill_claim();
-    }
+    // A vector of the pool's reserves after the update.
+    fn sync(e: Env) -> Vec<u128> {
+        // donation to the empty pool would break the shares calculation for the first depositor
+        if get_total_shares(&e) == 0 {
+            panic_with_error!(&e, LiquidityPoolValidationError::EmptyPool);
+        }
 
-    // Get deposit killswitch status.
-    fn get_is_killed_deposit(e: Env) -> bool {
-        get_is_killed_deposit(&e)
-    }
+        let excess = Self::_get_excess_balances(&e);
+        let reserve_a = get_reserve_a(&e) + excess.get(0).unwrap();
+        let reserve_b = get_reserve_b(&e) + excess.get(1).unwrap();
+        pool::update_price_cumulative(&e);
+        put_reserve_a(&e, reserve_a);
+        put_reserve_b(&e, reserve_b);
 
-    // Get swap killswitch status.
-    fn get_is_killed_swap(e: Env) -> bool {
-        get_is_killed_swap(&e)
-    }
+        // update plane data for every pool update
+        update_plane(&e);
 
-    // Get claim killswitch status.
-    fn get_is_killed_claim(e: Env) -> bool {
-        get_is_killed_claim(&e)
+        let reserves = Vec::from_array(&e, [reserve_a, reserve_b]);
+        Events::new(&e).sync(reserves.clone());
+        reserves
     }
 }
 
@@ -1037,6 +1763,43 @@ impl UpgradeableContract for LiquidityPool {
     }
 }
 
+// The `EmergencyQuorumContract` trait provides the interface for emergency mode quorum.
+#[contractimpl]
+impl EmergencyQuorumContract for LiquidityPool {
+    // Configures the number of emergency admins votes required to enable the emergency mode.
+    // Once quorum is configured, the emergency admin is no longer able to enable the emergency mode alone.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `threshold` - The number of votes required. Zero disables the quorum.
+    fn set_emergency_quorum(e: Env, admin: Address, threshold: u32) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        set_emergency_quorum(&e, threshold);
+    }
+
+    // Returns the emergency mode voters (emergency admin and `EmergencyVoter` role holders) and votes threshold.
+    fn get_emergency_quorum(e: Env) -> (Vec<Address>, u32) {
+        get_emergency_quorum(&e)
+    }
+
+    // Votes for the emergency mode. The emergency mode is enabled once the threshold is reached.
+    //
+    // # Arguments
+    //
+    // * `voter` - The address of the voter.
+    fn vote_emergency_mode(e: Env, voter: Address) {
+        voter.require_auth();
+        vote_emergency_mode(&e, &voter);
+    }
+
+    // Returns the timestamp at which the emergency mode expires. Zero if it's not active.
+    fn get_emergency_mode_expiration(e: Env) -> u64 {
+        get_emergency_mode_expiration(&e)
+    }
+}
+
 #[contractimpl]
 impl UpgradeableLPTokenTrait for LiquidityPool {
     // legacy upgrade. not compatible with token contract version 140+ due to different arguments
@@ -1115,7 +1878,7 @@ impl RewardsTrait for LiquidityPool {
         }
 
         let rewards = get_rewards_manager(&e);
-        let total_shares = get_total_shares(&e);
+        let total_shares = get_rewards_total_shares(&e);
         rewards
             .manager()
             .set_reward_config(total_shares, expired_at, tps);
@@ -1125,10 +1888,7 @@ impl RewardsTrait for LiquidityPool {
     // Get difference between the actual balance and the total unclaimed reward minus the reserves
     fn get_unused_reward(e: Env) -> u128 {
         let rewards = get_rewards_manager(&e);
-        let mut rewards_manager = rewards.manager();
-        let total_shares = get_total_shares(&e);
-        let mut reward_balance_to_keep = rewards_manager.get_total_configured_reward(total_shares)
-            - rewards_manager.get_total_claimed_reward(total_shares);
+        let mut reward_balance_to_keep = Self::_get_reward_liability(&e);
 
         let reward_token = rewards.storage().get_reward_token();
         let reward_balance = SorobanTokenClient::new(&e, &reward_token)
@@ -1137,7 +1897,9 @@ impl RewardsTrait for LiquidityPool {
         match Self::get_tokens(e.clone()).first_index_of(reward_token) {
             Some(idx) => {
                 // since reward token is in the reserves, we need to keep also the reserves value
+                // and the protocol fees not claimed yet
                 reward_balance_to_keep += Self::get_reserves(e.clone()).get(idx).unwrap();
+                reward_balance_to_keep += Self::get_protocol_fees(e.clone()).get(idx).unwrap();
             }
             None => {}
         };
@@ -1186,7 +1948,7 @@ impl RewardsTrait for LiquidityPool {
         let mut manager = rewards.manager();
         let storage = rewards.storage();
         let config = storage.get_pool_reward_config();
-        let total_shares = get_total_shares(&e);
+        let total_shares = get_rewards_total_shares(&e);
         let user_shares = get_user_balance_shares(&e, &user);
 
         // pre-fill result dict with stored values
@@ -1256,7 +2018,7 @@ impl RewardsTrait for LiquidityPool {
     // The amount of reward tokens available for the user to claim as a u128.
     fn get_user_reward(e: Env, user: Address) -> u128 {
         let rewards = get_rewards_manager(&e);
-        let total_shares = get_total_shares(&e);
+        let total_shares = get_rewards_total_shares(&e);
         let user_shares = get_user_balance_shares(&e, &user);
         rewards
             .manager()
@@ -1270,7 +2032,7 @@ impl RewardsTrait for LiquidityPool {
             panic_with_error!(&e, AccessControlError::Unauthorized);
         }
         let rewards = get_rewards_manager(&e);
-        let total_shares = get_total_shares(&e);
+        let total_shares = get_rewards_total_shares(&e);
         rewards
             .manager()
             .checkpoint_user(&user, total_shares, user_shares);
@@ -1288,7 +2050,7 @@ impl RewardsTrait for LiquidityPool {
             panic_with_error!(&e, AccessControlError::Unauthorized);
         }
         let rewards = get_rewards_manager(&e);
-        let total_shares = get_total_shares(&e);
+        let total_shares = get_rewards_total_shares(&e);
         rewards
             .manager()
             .update_working_balance(&user, total_shares, user_shares);
@@ -1305,7 +2067,7 @@ impl RewardsTrait for LiquidityPool {
     // The total amount of accumulated reward for the pool as a u128.
     fn get_total_accumulated_reward(e: Env) -> u128 {
         let rewards = get_rewards_manager(&e);
-        let total_shares = get_total_shares(&e);
+        let total_shares = get_rewards_total_shares(&e);
         rewards.manager().get_total_accumulated_reward(total_shares)
     }
 
@@ -1320,7 +2082,7 @@ impl RewardsTrait for LiquidityPool {
     // The total amount of configured reward for the pool as a u128.
     fn get_total_configured_reward(e: Env) -> u128 {
         let rewards = get_rewards_manager(&e);
-        let total_shares = get_total_shares(&e);
+        let total_shares = get_rewards_total_shares(&e);
         rewards.manager().get_total_configured_reward(total_shares)
     }
 
@@ -1335,7 +2097,7 @@ impl RewardsTrait for LiquidityPool {
     // The total amount of claimed reward for the pool as a u128.
     fn get_total_claimed_reward(e: Env) -> u128 {
         let rewards = get_rewards_manager(&e);
-        let total_shares = get_total_shares(&e);
+        let total_shares = get_rewards_total_shares(&e);
         rewards.manager().get_total_claimed_reward(total_shares)
     }
 
@@ -1355,7 +2117,7 @@ impl RewardsTrait for LiquidityPool {
         }
 
         let rewards = get_rewards_manager(&e);
-        let total_shares = get_total_shares(&e);
+        let total_shares = get_rewards_total_shares(&e);
         let user_shares = get_user_balance_shares(&e, &user);
         let mut rewards_manager = rewards.manager();
         let rewards_storage = rewards.storage();
@@ -1442,6 +2204,9 @@ impl TransferableContract for LiquidityPool {
     // * `role_name` - The name of the role to transfer ownership of. The role must be one of the following:
     //     * `Admin`
     //     * `EmergencyAdmin`
+    //     * `RewardsAdmin` (if transfer delay is enabled)
+    //     * `OperationsAdmin` (if transfer delay is enabled)
+    //     * `PauseAdmin` (if transfer delay is enabled)
     // * `new_address` - New address for the role
     fn commit_transfer_ownership(e: Env, admin: Address, role_name: Symbol, new_address: Address) {
         admin.require_auth();
@@ -1461,6 +2226,9 @@ impl TransferableContract for LiquidityPool {
     // * `role_name` - The name of the role to transfer ownership of. The role must be one of the following:
     //     * `Admin`
     //     * `EmergencyAdmin`
+    //     * `RewardsAdmin` (if transfer delay is enabled)
+    //     * `OperationsAdmin` (if transfer delay is enabled)
+    //     * `PauseAdmin` (if transfer delay is enabled)
     fn apply_transfer_ownership(e: Env, admin: Address, role_name: Symbol) {
         admin.require_auth();
         let access_control = AccessControl::new(&e);
@@ -1479,6 +2247,9 @@ impl TransferableContract for LiquidityPool {
     // * `role_name` - The name of the role to transfer ownership of. The role must be one of the following:
     //     * `Admin`
     //     * `EmergencyAdmin`
+    //     * `RewardsAdmin` (if transfer delay is enabled)
+    //     * `OperationsAdmin` (if transfer delay is enabled)
+    //     * `PauseAdmin` (if transfer delay is enabled)
     fn revert_transfer_ownership(e: Env, admin: Address, role_name: Symbol) {
         admin.require_auth();
         let access_control = AccessControl::new(&e);
@@ -1489,6 +2260,23 @@ impl TransferableContract for LiquidityPool {
         AccessControlEvents::new(&e).revert_transfer_ownership(role);
     }
 
+    // Accepts the committed ownership transfer.
+    // Alternative to `apply_transfer_ownership` which has to be signed by the future address,
+    // proving it controls its key before taking over the role.
+    //
+    // # Arguments
+    //
+    // * `new_address` - The future address of the role.
+    // * `role_name` - The name of the role to transfer ownership of.
+    fn accept_ownership(e: Env, new_address: Address, role_name: Symbol) {
+        new_address.require_auth();
+        let access_control = AccessControl::new(&e);
+
+        let role = Role::from_symbol(&e, role_name);
+        access_control.accept_transfer_ownership(&role, &new_address);
+        AccessControlEvents::new(&e).accept_transfer_ownership(role, new_address);
+    }
+
     // Returns the future address for the role.
     // The future address is the address that the ownership of the role will be transferred to.
     // The future address is set using the `commit_transfer_ownership` function.
@@ -1499,6 +2287,9 @@ impl TransferableContract for LiquidityPool {
     // * `role_name` - The name of the role to get the future address for. The role must be one of the following:
     //    * `Admin`
     //    * `EmergencyAdmin`
+    //    * `RewardsAdmin` (if transfer delay is enabled)
+    //    * `OperationsAdmin` (if transfer delay is enabled)
+    //    * `PauseAdmin` (if transfer delay is enabled)
     fn get_future_address(e: Env, role_name: Symbol) -> Address {
         let access_control = AccessControl::new(&e);
         let role = Role::from_symbol(&e, role_name);
@@ -1511,3 +2302,283 @@ impl TransferableContract for LiquidityPool {
         }
     }
 }
+
+// The `DelayedRolesContract` trait provides the interface for delayed changes of privileged roles.
+#[contractimpl]
+impl DelayedRolesContract for LiquidityPool {
+    // Enables transfer delay for the privileged role.
+    // Once enabled, the role can only be changed through the commit/apply flow
+    // and the delay cannot be disabled.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `role_name` - The name of the role. The role must be one of the following:
+    //     * `RewardsAdmin`
+    //     * `OperationsAdmin`
+    //     * `PauseAdmin`
+    //     * `EmergencyPauseAdmin`
+    fn enable_transfer_delay(e: Env, admin: Address, role_name: Symbol) {
+        admin.require_auth();
+        let access_control = AccessControl::new(&e);
+        access_control.assert_address_has_role(&admin, &Role::Admin);
+
+        let role = Role::from_symbol(&e, role_name);
+        access_control.enable_transfer_delay(&role);
+        AccessControlEvents::new(&e).enable_transfer_delay(role);
+    }
+
+    // Returns whether the role can be changed only through the commit/apply flow.
+    //
+    // # Arguments
+    //
+    // * `role_name` - The name of the role.
+    fn get_transfer_delay_enabled(e: Env, role_name: Symbol) -> bool {
+        let role = Role::from_symbol(&e, role_name);
+        AccessControl::new(&e).is_transfer_delayed(&role)
+    }
+
+    // Commits a change of the multiple addresses role.
+    // Pending change can be reverted with `revert_transfer_ownership`.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `role_name` - The name of the role. The role must be one of the following:
+    //     * `EmergencyPauseAdmin` (if transfer delay is enabled)
+    // * `new_addresses` - New addresses for the role
+    fn commit_transfer_addresses(
+        e: Env,
+        admin: Address,
+        role_name: Symbol,
+        new_addresses: Vec<Address>,
+    ) {
+        admin.require_auth();
+        let access_control = AccessControl::new(&e);
+        access_control.assert_address_has_role(&admin, &Role::Admin);
+
+        let role = Role::from_symbol(&e, role_name);
+        access_control.commit_transfer_addresses(&role, &new_addresses);
+        AccessControlEvents::new(&e).commit_transfer_addresses(role, new_addresses);
+    }
+
+    // Applies the committed change of the multiple addresses role.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `role_name` - The name of the role. The role must be one of the following:
+    //     * `EmergencyPauseAdmin`
+    fn apply_transfer_addresses(e: Env, admin: Address, role_name: Symbol) {
+        admin.require_auth();
+        let access_control = AccessControl::new(&e);
+        access_control.assert_address_has_role(&admin, &Role::Admin);
+
+        let role = Role::from_symbol(&e, role_name);
+        let new_addresses = access_control.apply_transfer_addresses(&role);
+        AccessControlEvents::new(&e).apply_transfer_addresses(role, new_addresses);
+    }
+
+    // Returns the future addresses for the multiple addresses role.
+    // The addresses will be defaulted to the current ones if the change is not committed.
+    //
+    // # Arguments
+    //
+    // * `role_name` - The name of the role. The role must be one of the following:
+    //     * `EmergencyPauseAdmin`
+    fn get_future_addresses(e: Env, role_name: Symbol) -> Vec<Address> {
+        let access_control = AccessControl::new(&e);
+        let role = Role::from_symbol(&e, role_name);
+        match access_control.get_transfer_ownership_deadline(&role) {
+            0 => access_control.get_role_addresses(&role),
+            _ => access_control.get_future_addresses(&role),
+        }
+    }
+}
+
+// The `RolesRegistryContract` trait provides the interface for managing roles declared at runtime.
+#[contractimpl]
+impl RolesRegistryContract for LiquidityPool {
+    // Declares a new role. Role flags cannot be changed afterwards.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `role_name` - The name of the role. Must not match any of the built-in roles.
+    // * `has_many_users` - Whether the role is assigned to multiple addresses.
+    // * `is_transfer_delayed` - Whether the role address can be replaced only through
+    //   the commit/apply flow. Not available for multiple addresses roles.
+    fn declare_role(
+        e: Env,
+        admin: Address,
+        role_name: Symbol,
+        has_many_users: bool,
+        is_transfer_delayed: bool,
+    ) {
+        admin.require_auth();
+        let access_control = AccessControl::new(&e);
+        access_control.assert_address_has_role(&admin, &Role::Admin);
+
+        let role = access_control.declare_role(&role_name, has_many_users, is_transfer_delayed);
+        AccessControlEvents::new(&e).declare_role(role, has_many_users, is_transfer_delayed);
+    }
+
+    // Returns the names of the roles declared at runtime.
+    fn get_declared_roles(e: Env) -> Vec<Symbol> {
+        AccessControl::new(&e).get_declared_roles()
+    }
+
+    // Sets the addresses of the role.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `role_name` - The name of the role.
+    // * `addresses` - The addresses of the role. Single address roles expect exactly one address.
+    fn set_role_addrs(e: Env, admin: Address, role_name: Symbol, addresses: Vec<Address>) {
+        admin.require_auth();
+        let access_control = AccessControl::new(&e);
+        access_control.assert_address_has_role(&admin, &Role::Admin);
+
+        let role = Role::from_symbol(&e, role_name);
+        access_control.set_role_addrs(&role, &addresses);
+        AccessControlEvents::new(&e).set_role_addrs(role, addresses);
+    }
+
+    // Returns the addresses of the role.
+    //
+    // # Arguments
+    //
+    // * `role_name` - The name of the role.
+    fn get_role_addrs(e: Env, role_name: Symbol) -> Vec<Address> {
+        let role = Role::from_symbol(&e, role_name);
+        AccessControl::new(&e).get_role_addrs(&role)
+    }
+}
+
+// The `RoleGrantsContract` trait provides the interface for managing role grants lifetime.
+#[contractimpl]
+impl RoleGrantsContract for LiquidityPool {
+    // Gives up the role. The address won't hold the role anymore.
+    // For multiple addresses roles only the given address is removed.
+    //
+    // # Arguments
+    //
+    // * `address` - The address of the role holder.
+    // * `role_name` - The name of the role. Admin role cannot be renounced.
+    fn renounce_role(e: Env, address: Address, role_name: Symbol) {
+        address.require_auth();
+        let role = Role::from_symbol(&e, role_name);
+        AccessControl::new(&e).renounce_role(&role, &address);
+        AccessControlEvents::new(&e).renounce_role(role, address);
+    }
+
+    // Sets the grant expiration timestamp. Once expired, the address is no longer authorized for the role.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `role_name` - The name of the role. Admin, EmergencyAdmin and delayed roles grants cannot expire.
+    // * `address` - The address of the role holder.
+    // * `expires_at` - The expiration timestamp, not earlier than the role transfer delay from now.
+    //   Zero means grant never expires.
+    fn set_role_expiry(
+        e: Env,
+        admin: Address,
+        role_name: Symbol,
+        address: Address,
+        expires_at: u64,
+    ) {
+        admin.require_auth();
+        let access_control = AccessControl::new(&e);
+        access_control.assert_address_has_role(&admin, &Role::Admin);
+
+        let role = Role::from_symbol(&e, role_name);
+        access_control.set_role_expiry(&role, &address, expires_at);
+        AccessControlEvents::new(&e).set_role_expiry(role, address, expires_at);
+    }
+
+    // Returns the grant expiration timestamp. Zero means grant never expires.
+    //
+    // # Arguments
+    //
+    // * `role_name` - The name of the role.
+    // * `address` - The address of the role holder.
+    fn get_role_expiry(e: Env, role_name: Symbol, address: Address) -> u64 {
+        let role = Role::from_symbol(&e, role_name);
+        AccessControl::new(&e).get_role_expiry(&role, &address)
+    }
+}
+
+// The `AuditLogContract` trait provides the interface for querying history of privileged actions.
+#[contractimpl]
+impl AuditLogContract for LiquidityPool {
+    // Returns the total number of privileged actions recorded.
+    // Only the last `AUDIT_LOG_SIZE` records are kept.
+    fn get_audit_log_len(e: Env) -> u32 {
+        get_audit_log_len(&e)
+    }
+
+    // Returns the recorded privileged actions, oldest first.
+    //
+    // # Arguments
+    //
+    // * `start` - The sequence number of the first record.
+    // * `limit` - The maximum number of records to return.
+    fn get_audit_log(e: Env, start: u32, limit: u32) -> Vec<AuditRecord> {
+        get_audit_log(&e, start, limit)
+    }
+}
+
+// The `ConfigurableDelaysContract` trait provides the interface for configuring admin actions delays.
+#[contractimpl]
+impl ConfigurableDelaysContract for LiquidityPool {
+    // Returns the current delay for the target.
+    //
+    // # Arguments
+    //
+    // * `target` - The delay target: admin actions, upgrade or specific role transfer.
+    fn get_delay(e: Env, target: DelayTarget) -> u64 {
+        get_delay(&e, &target)
+    }
+
+    // Commits the delay change. The change may be applied once the current delay passes.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `target` - The delay target: admin actions, upgrade or specific role transfer.
+    // * `value` - The new delay in seconds.
+    fn commit_delay(e: Env, admin: Address, target: DelayTarget, value: u64) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        commit_delay(&e, &target, value);
+        AccessControlEvents::new(&e).commit_delay(target, value);
+    }
+
+    // Applies the committed delay change.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `target` - The delay target.
+    fn apply_delay(e: Env, admin: Address, target: DelayTarget) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        let value = apply_delay(&e, &target);
+        AccessControlEvents::new(&e).apply_delay(target, value);
+    }
+
+    // Reverts the committed delay change.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `target` - The delay target.
+    fn revert_delay(e: Env, admin: Address, target: DelayTarget) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        revert_delay(&e, &target);
+        AccessControlEvents::new(&e).revert_delay(target);
+    }
+}
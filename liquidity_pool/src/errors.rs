@@ -13,4 +13,7 @@ pub enum LiquidityPoolError {
     PoolClaimKilled = 207,
     FutureShareIdNotSet = 208,
     // pool specific validation errors
+    AnotherActionActive = 2906,
+    NoActionActive = 2907,
+    ActionNotReadyYet = 2908,
 }
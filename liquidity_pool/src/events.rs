@@ -0,0 +1,91 @@
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+#[derive(Clone)]
+pub struct Events(Env);
+
+impl Events {
+    #[inline(always)]
+    pub fn env(&self) -> &Env {
+        &self.0
+    }
+
+    #[inline(always)]
+    pub fn new(env: &Env) -> Events {
+        Events(env.clone())
+    }
+
+    pub fn commit_new_fee(&self, new_fee: u32) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "commit_new_fee"),), (new_fee,))
+    }
+
+    pub fn apply_new_fee(&self, new_fee: u32) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "apply_new_fee"),), (new_fee,))
+    }
+
+    pub fn revert_new_parameters(&self) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "revert_new_parameters"),), ())
+    }
+
+    pub fn commit_protocol_fee(&self, new_fraction: u32) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "commit_protocol_fee"),),
+            (new_fraction,),
+        )
+    }
+
+    pub fn apply_protocol_fee(&self, new_fraction: u32) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "apply_protocol_fee"),),
+            (new_fraction,),
+        )
+    }
+
+    pub fn revert_protocol_fee(&self) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "revert_protocol_fee"),), ())
+    }
+
+    pub fn set_fees_collector(&self, fees_collector: Address) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "set_fees_collector"),),
+            (fees_collector,),
+        )
+    }
+
+    // Part of the swap fee taken by the protocol. The rest of the fee reported in the trade event
+    // stays with liquidity providers.
+    pub fn protocol_fee(&self, token: Address, amount: u128) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "protocol_fee"), token), (amount,))
+    }
+
+    pub fn claim_protocol_fees(&self, fees_collector: Address, amounts: Vec<u128>) {
+        self.env().events().publish(
+            (
+                Symbol::new(self.env(), "claim_protocol_fees"),
+                fees_collector,
+            ),
+            (amounts,),
+        )
+    }
+
+    pub fn skim(&self, to: Address, amounts: Vec<u128>) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "skim"), to), (amounts,))
+    }
+
+    pub fn sync(&self, reserves: Vec<u128>) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "sync"),), (reserves,))
+    }
+}
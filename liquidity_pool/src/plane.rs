@@ -7,7 +7,10 @@ pub mod pool_plane {
 
 pub use crate::plane::pool_plane::Client as PoolPlaneClient;
 
-use crate::storage::{get_fee_fraction, get_plane, get_reserve_a, get_reserve_b};
+use crate::storage::{
+    get_fee_fraction, get_plane, get_price_cumulative_a, get_price_cumulative_b,
+    get_price_timestamp_last, get_reserve_a, get_reserve_b,
+};
 use soroban_sdk::{symbol_short, Env, Vec};
 
 fn get_pool_data(e: &Env) -> (Vec<u128>, Vec<u128>) {
@@ -19,10 +22,16 @@ fn get_pool_data(e: &Env) -> (Vec<u128>, Vec<u128>) {
 
 pub fn update_plane(e: &Env) {
     let (init_args, reserves) = get_pool_data(e);
-    PoolPlaneClient::new(e, &get_plane(e)).update(
+    let plane_client = PoolPlaneClient::new(e, &get_plane(e));
+    plane_client.update(
         &e.current_contract_address(),
         &symbol_short!("standard"),
         &init_args,
         &reserves,
     );
+    plane_client.update_price_cumulative(
+        &e.current_contract_address(),
+        &Vec::from_array(e, [get_price_cumulative_a(e), get_price_cumulative_b(e)]),
+        &get_price_timestamp_last(e),
+    );
 }
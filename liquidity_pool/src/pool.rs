@@ -1,8 +1,14 @@
-use crate::constants::FEE_MULTIPLIER;
-use crate::storage::get_fee_fraction;
+use crate::constants::{FEE_MULTIPLIER, PRICE_PRECISION};
+use crate::events::Events;
+use crate::storage::{
+    get_fee_fraction, get_price_cumulative_a, get_price_cumulative_b, get_price_timestamp_last,
+    get_protocol_fee_a, get_protocol_fee_b, get_protocol_fee_fraction, get_reserve_a,
+    get_reserve_b, get_token_a, get_token_b, has_price_timestamp_last, set_price_cumulative_a,
+    set_price_cumulative_b, set_price_timestamp_last, set_protocol_fee_a, set_protocol_fee_b,
+};
 use liquidity_pool_validation_errors::LiquidityPoolValidationError;
 use soroban_fixed_point_math::SorobanFixedPoint;
-use soroban_sdk::{panic_with_error, Env};
+use soroban_sdk::{panic_with_error, Env, U256};
 
 pub fn get_deposit_amounts(
     e: &Env,
@@ -49,6 +55,40 @@ pub fn get_amount_out(
     (result - fee, fee)
 }
 
+// Calculates the amount of the deposited token to be swapped,
+// so the rest and the swap result are in the pool ratio after the swap.
+// Solves s^2 + s * (reserve_in * (2 - f) - amount * f) - amount * reserve_in = 0, where f is the fee fraction.
+pub fn get_deposit_single_swap_amount(e: &Env, in_amount: u128, reserve_in: u128) -> u128 {
+    let fee_fraction = get_fee_fraction(e) as u128;
+    let multiplier = U256::from_u128(e, FEE_MULTIPLIER);
+    let amount = U256::from_u128(e, in_amount);
+    let reserve = U256::from_u128(e, reserve_in);
+
+    // coefficients scaled by FEE_MULTIPLIER
+    let b_positive = reserve.mul(&U256::from_u128(e, 2 * FEE_MULTIPLIER - fee_fraction));
+    let b_negative = amount.mul(&U256::from_u128(e, fee_fraction));
+    let b_abs = if b_positive > b_negative {
+        b_positive.sub(&b_negative)
+    } else {
+        b_negative.sub(&b_positive)
+    };
+    let discriminant_sqrt = b_abs
+        .pow(2)
+        .add(
+            &U256::from_u32(e, 4)
+                .mul(&amount)
+                .mul(&reserve)
+                .mul(&multiplier.pow(2)),
+        )
+        .sqrt();
+    discriminant_sqrt
+        .add(&b_negative)
+        .sub(&b_positive)
+        .div(&U256::from_u32(e, 2).mul(&multiplier))
+        .to_u128()
+        .unwrap()
+}
+
 pub fn get_amount_out_strict_receive(
     e: &Env,
     out_amount: u128,
@@ -74,3 +114,57 @@ pub fn get_amount_out_strict_receive(
         + 1;
     (result, dy_w_fee - out_amount)
 }
+
+// Takes the protocol share out of the swap fee charged in the out token.
+// Returns the amount to be excluded from the pool reserves.
+pub fn accrue_protocol_fee(e: &Env, out_idx: u32, fee: u128) -> u128 {
+    let protocol_fee =
+        fee.fixed_mul_floor(e, &(get_protocol_fee_fraction(e) as u128), &FEE_MULTIPLIER);
+    if protocol_fee == 0 {
+        return 0;
+    }
+
+    let token = if out_idx == 0 {
+        set_protocol_fee_a(e, &(get_protocol_fee_a(e) + protocol_fee));
+        get_token_a(e)
+    } else {
+        set_protocol_fee_b(e, &(get_protocol_fee_b(e) + protocol_fee));
+        get_token_b(e)
+    };
+    Events::new(e).protocol_fee(token, protocol_fee);
+
+    protocol_fee
+}
+
+// Accumulates prices weighted by the time elapsed since the last update.
+// Should be called before the reserves are changed, so the previous reserves are accounted.
+pub fn update_price_cumulative(e: &Env) {
+    let now = e.ledger().timestamp();
+    // nothing to accumulate on the very first update
+    if has_price_timestamp_last(e) {
+        let last_timestamp = get_price_timestamp_last(e);
+        if now == last_timestamp {
+            return;
+        }
+
+        let reserve_a = get_reserve_a(e);
+        let reserve_b = get_reserve_b(e);
+        if reserve_a > 0 && reserve_b > 0 {
+            let elapsed = U256::from_u128(e, (now - last_timestamp) as u128);
+            let precision = U256::from_u128(e, PRICE_PRECISION);
+            let reserve_a = U256::from_u128(e, reserve_a);
+            let reserve_b = U256::from_u128(e, reserve_b);
+            set_price_cumulative_a(
+                e,
+                &get_price_cumulative_a(e)
+                    .add(&reserve_b.mul(&precision).mul(&elapsed).div(&reserve_a)),
+            );
+            set_price_cumulative_b(
+                e,
+                &get_price_cumulative_b(e)
+                    .add(&reserve_a.mul(&precision).mul(&elapsed).div(&reserve_b)),
+            );
+        }
+    }
+    set_price_timestamp_last(e, &now);
+}
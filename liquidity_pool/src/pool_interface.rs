@@ -1,4 +1,4 @@
-use soroban_sdk::{Address, BytesN, Env, Map, Symbol, Val, Vec};
+use soroban_sdk::{Address, Bytes, BytesN, Env, Map, Symbol, Val, Vec, U256};
 
 pub trait LiquidityPoolCrunch {
     // Initialize pool completely to reduce calculations cost
@@ -48,6 +48,16 @@ pub trait LiquidityPoolTrait {
         min_shares: u128,
     ) -> (Vec<u128>, u128);
 
+    // Deposit a single token, swapping part of it internally to match the pool ratio.
+    // Returns amounts deposited after the swap and the amount of pool shares minted.
+    fn deposit_single(
+        e: Env,
+        user: Address,
+        in_idx: u32,
+        in_amount: u128,
+        min_shares: u128,
+    ) -> (Vec<u128>, u128);
+
     // Perform an exchange between two coins.
     // in_idx: Index value for the coin to send
     // out_idx: Index value of the coin to receive
@@ -89,9 +99,33 @@ pub trait LiquidityPoolTrait {
     // Returns amount of tokens withdrawn
     fn withdraw(e: Env, user: Address, share_amount: u128, min_amounts: Vec<u128>) -> Vec<u128>;
 
+    // Calculate the amount received when withdrawing a single token.
+    // share_amount: Amount of pool shares to burn in the withdrawal
+    // i: Index value of the token to withdraw
+    fn calc_withdraw_one_coin(e: Env, share_amount: u128, i: u32) -> u128;
+
+    // Withdraw a single token from the pool, swapping the other token share internally.
+    // share_amount: Amount of pool shares to burn in the withdrawal
+    // i: Index value of the token to withdraw
+    // min_amount: Minimum amount of token to receive
+    // Returns amounts of tokens withdrawn
+    fn withdraw_one_coin(
+        e: Env,
+        user: Address,
+        share_amount: u128,
+        i: u32,
+        min_amount: u128,
+    ) -> Vec<u128>;
+
     // Get pool reserves
     fn get_reserves(e: Env) -> Vec<u128>;
 
+    // Get cumulative prices of token a in token b and token b in token a, scaled by 1e18
+    fn get_price_cumulative(e: Env) -> Vec<U256>;
+
+    // Get timestamp of the last cumulative prices update
+    fn get_price_timestamp_last(e: Env) -> u64;
+
     // Fee fraction getter. 1 = 0.01%
     fn get_fee_fraction(e: Env) -> u32;
 
@@ -113,6 +147,16 @@ pub trait AdminInterfaceTrait {
     // Get map of privileged roles
     fn get_privileged_addrs(e: Env) -> Map<Symbol, Vec<Address>>;
 
+    // Set new fee to be applied in future. Fee tier is part of the router pool index,
+    // so only the router can change it
+    fn commit_new_fee(e: Env, admin: Address, new_fee: u32);
+
+    // Apply committed fee
+    fn apply_new_fee(e: Env, admin: Address);
+
+    // Revert committed parameters to current values
+    fn revert_new_parameters(e: Env, admin: Address);
+
     // Stop pool instantly
     fn kill_deposit(e: Env, admin: Address);
     fn kill_swap(e: Env, admin: Address);
@@ -212,3 +256,45 @@ pub trait RewardsTrait {
     // returns amount of tokens rewarded to the user
     fn claim(e: Env, user: Address) -> u128;
 }
+
+pub trait ProtocolFeesTrait {
+    // Set new protocol share of the swap fee to be applied in future. 1 = 0.01% of the fee
+    fn commit_protocol_fee(e: Env, admin: Address, new_fraction: u32);
+
+    // Apply committed protocol fee share
+    fn apply_protocol_fee(e: Env, admin: Address);
+
+    // Revert committed protocol fee share
+    fn revert_protocol_fee(e: Env, admin: Address);
+
+    // Get current protocol share of the swap fee
+    fn get_protocol_fee_fraction(e: Env) -> u32;
+
+    // Get protocol fees accrued per token and not claimed yet
+    fn get_protocol_fees(e: Env) -> Vec<u128>;
+
+    // Set address receiving claimed protocol fees
+    fn set_fees_collector(e: Env, admin: Address, fees_collector: Address);
+    fn get_fees_collector(e: Env) -> Address;
+
+    // Send accrued protocol fees to the fees collector. Can be called by anyone.
+    // Returns amounts of tokens sent
+    fn claim_protocol_fees(e: Env) -> Vec<u128>;
+}
+
+pub trait FlashLoanTrait {
+    // Lend pool tokens to the receiver contract, invoking `on_flash_loan(tokens, amounts, fees, data)` on it.
+    // Borrowed amounts plus flash fees should be returned to the pool by the end of the callback.
+    // Returns flash fees charged per token
+    fn flash_loan(e: Env, receiver: Address, amounts: Vec<u128>, data: Bytes) -> Vec<u128>;
+}
+
+pub trait ReservesSyncTrait {
+    // Send token balances not accounted in the reserves, protocol fees or rewards to the given address
+    // Returns amounts sent
+    fn skim(e: Env, admin: Address, to: Address) -> Vec<u128>;
+
+    // Add token balances not accounted in the reserves, protocol fees or rewards to the reserves.
+    // Can be called by anyone. Returns updated reserves
+    fn sync(e: Env) -> Vec<u128>;
+}
@@ -1,8 +1,9 @@
 use paste::paste;
-use soroban_sdk::{contracttype, panic_with_error, Address, BytesN, Env};
+use soroban_sdk::{contracttype, panic_with_error, Address, BytesN, Env, U256};
 pub use utils::bump::bump_instance;
 use utils::storage_errors::StorageError;
 use utils::{
+    generate_instance_storage_getter, generate_instance_storage_getter_and_setter,
     generate_instance_storage_getter_and_setter_with_default,
     generate_instance_storage_getter_with_default, generate_instance_storage_setter,
 };
@@ -15,6 +16,8 @@ enum DataKey {
     ReserveA,
     ReserveB,
     FeeFraction, // 1 = 0.01%
+    FutureFeeFraction,
+    AdminActionsDeadline,
     Plane,
     Router,
     IsKilledSwap,
@@ -22,6 +25,17 @@ enum DataKey {
     IsKilledClaim,
 
     TokenFutureWASM,
+
+    ProtocolFeeFraction, // share of the swap fee, 1 = 0.01%
+    FutureProtocolFeeFraction,
+    ProtocolFeeDeadline,
+    ProtocolFeeA,
+    ProtocolFeeB,
+    FeesCollector,
+
+    PriceCumulativeA, // price of token a in token b, scaled by PRICE_PRECISION
+    PriceCumulativeB,
+    PriceTimestampLast,
 }
 
 generate_instance_storage_getter_and_setter_with_default!(
@@ -108,6 +122,19 @@ pub fn put_fee_fraction(e: &Env, value: u32) {
     e.storage().instance().set(&DataKey::FeeFraction, &value)
 }
 
+generate_instance_storage_getter_and_setter_with_default!(
+    future_fee_fraction,
+    DataKey::FutureFeeFraction,
+    u32,
+    0
+);
+generate_instance_storage_getter_and_setter_with_default!(
+    admin_actions_deadline,
+    DataKey::AdminActionsDeadline,
+    u64,
+    0
+);
+
 pub(crate) fn set_plane(e: &Env, plane: &Address) {
     let key = DataKey::Plane;
     bump_instance(e);
@@ -153,3 +180,81 @@ pub(crate) fn get_token_future_wasm(e: &Env) -> BytesN<32> {
         None => panic_with_error!(e, StorageError::ValueNotInitialized),
     }
 }
+
+// protocol fee
+generate_instance_storage_getter_and_setter_with_default!(
+    protocol_fee_fraction,
+    DataKey::ProtocolFeeFraction,
+    u32,
+    0
+);
+generate_instance_storage_getter_and_setter_with_default!(
+    future_protocol_fee_fraction,
+    DataKey::FutureProtocolFeeFraction,
+    u32,
+    0
+);
+generate_instance_storage_getter_and_setter_with_default!(
+    protocol_fee_deadline,
+    DataKey::ProtocolFeeDeadline,
+    u64,
+    0
+);
+
+// protocol fees accrued per token, not counted in the reserves
+generate_instance_storage_getter_and_setter_with_default!(
+    protocol_fee_a,
+    DataKey::ProtocolFeeA,
+    u128,
+    0
+);
+generate_instance_storage_getter_and_setter_with_default!(
+    protocol_fee_b,
+    DataKey::ProtocolFeeB,
+    u128,
+    0
+);
+
+generate_instance_storage_getter_and_setter!(fees_collector, DataKey::FeesCollector, Address);
+
+// cumulative prices
+pub fn get_price_cumulative_a(e: &Env) -> U256 {
+    bump_instance(e);
+    e.storage()
+        .instance()
+        .get(&DataKey::PriceCumulativeA)
+        .unwrap_or(U256::from_u32(e, 0))
+}
+
+pub fn set_price_cumulative_a(e: &Env, value: &U256) {
+    bump_instance(e);
+    e.storage()
+        .instance()
+        .set(&DataKey::PriceCumulativeA, value)
+}
+
+pub fn get_price_cumulative_b(e: &Env) -> U256 {
+    bump_instance(e);
+    e.storage()
+        .instance()
+        .get(&DataKey::PriceCumulativeB)
+        .unwrap_or(U256::from_u32(e, 0))
+}
+
+pub fn set_price_cumulative_b(e: &Env, value: &U256) {
+    bump_instance(e);
+    e.storage()
+        .instance()
+        .set(&DataKey::PriceCumulativeB, value)
+}
+
+generate_instance_storage_getter_and_setter_with_default!(
+    price_timestamp_last,
+    DataKey::PriceTimestampLast,
+    u64,
+    0
+);
+
+pub fn has_price_timestamp_last(e: &Env) -> bool {
+    e.storage().instance().has(&DataKey::PriceTimestampLast)
+}
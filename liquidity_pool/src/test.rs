@@ -1,9 +1,11 @@
 #![cfg(test)]
 extern crate std;
 
+use crate::constants::MINIMUM_LIQUIDITY;
 use crate::testutils::{
-    create_liqpool_contract, create_plane_contract, create_reward_boost_feed_contract,
-    create_token_contract, get_token_admin_client, install_token_wasm, Setup, TestConfig,
+    create_flash_loan_receiver, create_liqpool_contract, create_plane_contract,
+    create_reward_boost_feed_contract, create_token_contract, get_token_admin_client,
+    install_token_wasm, Setup, TestConfig,
 };
 use access_control::constants::ADMIN_ACTIONS_DELAY;
 use core::cmp::min;
@@ -12,7 +14,8 @@ use soroban_sdk::token::{
     StellarAssetClient as SorobanTokenAdminClient, TokenClient as SorobanTokenClient,
 };
 use soroban_sdk::{
-    symbol_short, testutils::Address as _, vec, Address, Env, Error, IntoVal, Symbol, Val, Vec,
+    symbol_short, testutils::Address as _, vec, Address, Bytes, Env, Error, IntoVal, Symbol, Val,
+    Vec, U256,
 };
 use token_share::Client as ShareTokenClient;
 use utils::test_utils::{assert_approx_eq_abs, install_dummy_wasm, jump};
@@ -34,7 +37,8 @@ fn test() {
     let reward_2_tps = 20_0000000_u128;
     let reward_3_tps = 6_0000000_u128;
     let total_reward_1 = reward_1_tps * 60;
-    let amount_to_deposit = 100_0000000;
+    // minimum liquidity is locked on the first deposit, so we deposit a bit more to get round share amount
+    let amount_to_deposit = 100_0000000 + MINIMUM_LIQUIDITY;
     let desired_amounts = Vec::from_array(&e, [amount_to_deposit, amount_to_deposit]);
 
     liq_pool.deposit(&user1, &desired_amounts, &0);
@@ -124,9 +128,13 @@ fn test() {
     );
 
     // when we deposit equal amounts, we gotta have deposited amount of share tokens
-    let expected_share_amount = amount_to_deposit as i128;
+    // except the minimum liquidity locked by the pool on the first deposit
+    let expected_share_amount = 100_0000000_i128;
     assert_eq!(token_share.balance(&user1), expected_share_amount);
-    assert_eq!(token_share.balance(&liq_pool.address), 0);
+    assert_eq!(
+        token_share.balance(&liq_pool.address),
+        MINIMUM_LIQUIDITY as i128
+    );
     assert_eq!(
         token1.balance(&user1),
         i128::MAX - amount_to_deposit as i128
@@ -195,10 +203,8 @@ fn test() {
         amount_to_deposit as i128 - expected_swap_result as i128
     );
 
-    let withdraw_amounts = [
-        amount_to_deposit + swap_in_amount,
-        amount_to_deposit - expected_swap_result,
-    ];
+    // share of the locked minimum liquidity stays in the pool
+    let withdraw_amounts = [1009999990, 990128722];
     liq_pool.withdraw(
         &user1,
         &(expected_share_amount as u128),
@@ -225,10 +231,7 @@ fn test() {
                     function: AuthorizedFunction::Contract((
                         token_share.address.clone(),
                         Symbol::new(&e, "burn"),
-                        Vec::from_array(
-                            &e,
-                            [user1.to_val(), (amount_to_deposit as i128).into_val(&e),]
-                        ),
+                        Vec::from_array(&e, [user1.to_val(), expected_share_amount.into_val(&e),]),
                     )),
                     sub_invocations: std::vec![],
                 }],
@@ -243,12 +246,15 @@ fn test() {
         total_reward_1 + total_reward_2 + total_reward_3
     );
 
-    assert_eq!(token1.balance(&user1), i128::MAX);
-    assert_eq!(token2.balance(&user1), i128::MAX);
+    assert_eq!(token1.balance(&user1), i128::MAX - 1010);
+    assert_eq!(token2.balance(&user1), i128::MAX - 991);
     assert_eq!(token_share.balance(&user1), 0);
-    assert_eq!(token1.balance(&liq_pool.address), 0);
-    assert_eq!(token2.balance(&liq_pool.address), 0);
-    assert_eq!(token_share.balance(&liq_pool.address), 0);
+    assert_eq!(token1.balance(&liq_pool.address), 1010);
+    assert_eq!(token2.balance(&liq_pool.address), 991);
+    assert_eq!(
+        token_share.balance(&liq_pool.address),
+        MINIMUM_LIQUIDITY as i128
+    );
 }
 
 #[test]
@@ -334,6 +340,7 @@ fn test_events() {
     let token2 = setup.token2;
     let user1 = setup.users[0].clone();
     let amount_to_deposit = 100_0000000;
+    let expected_share_amount = amount_to_deposit - MINIMUM_LIQUIDITY;
     let desired_amounts = Vec::from_array(&e, [amount_to_deposit, amount_to_deposit]);
 
     liq_pool.deposit(&user1, &desired_amounts, &0);
@@ -352,7 +359,7 @@ fn test_events() {
                 (
                     amount_to_deposit as i128,
                     amount_to_deposit as i128,
-                    amount_to_deposit as i128
+                    expected_share_amount as i128
                 )
                     .into_val(&e),
             ),
@@ -378,9 +385,10 @@ fn test_events() {
         ]
     );
 
-    let amounts_out = liq_pool.withdraw(&user1, &amount_to_deposit, &Vec::from_array(&e, [0, 0]));
-    assert_eq!(amounts_out.get(0).unwrap(), 1000000100);
-    assert_eq!(amounts_out.get(1).unwrap(), 999999902);
+    let amounts_out =
+        liq_pool.withdraw(&user1, &expected_share_amount, &Vec::from_array(&e, [0, 0]));
+    assert_eq!(amounts_out.get(0).unwrap(), 999999099);
+    assert_eq!(amounts_out.get(1).unwrap(), 999998902);
     assert_eq!(
         vec![&e, e.events().all().last().unwrap()],
         vec![
@@ -394,7 +402,7 @@ fn test_events() {
                 )
                     .into_val(&e),
                 (
-                    amount_to_deposit as i128,
+                    expected_share_amount as i128,
                     amounts_out.get(0).unwrap() as i128,
                     amounts_out.get(1).unwrap() as i128
                 )
@@ -425,6 +433,64 @@ fn test_deposit_min_mint() {
     liq_pool.deposit(&user1, &Vec::from_array(&setup.env, [1, 1]), &10);
 }
 
+#[test]
+fn test_locked_minimum_liquidity() {
+    let setup = Setup::new_with_config(&TestConfig {
+        mint_to_user: 1000000_0000000,
+        ..TestConfig::default()
+    });
+    let e = setup.env;
+    let liq_pool = setup.liq_pool;
+    let user1 = setup.users[0].clone();
+    let user2 = setup.users[1].clone();
+
+    // minimum liquidity is locked by the pool itself on the first deposit
+    assert_eq!(
+        liq_pool.deposit(&user1, &Vec::from_array(&e, [100_0000000, 100_0000000]), &0),
+        (
+            Vec::from_array(&e, [100_0000000, 100_0000000]),
+            100_0000000 - MINIMUM_LIQUIDITY
+        )
+    );
+    assert_eq!(liq_pool.get_total_shares(), 100_0000000);
+    assert_eq!(
+        setup.token_share.balance(&liq_pool.address),
+        MINIMUM_LIQUIDITY as i128
+    );
+
+    // pool can't be emptied, so the share price can't be reset by the next depositor
+    liq_pool.withdraw(
+        &user1,
+        &(100_0000000 - MINIMUM_LIQUIDITY),
+        &Vec::from_array(&e, [0, 0]),
+    );
+    assert_eq!(liq_pool.get_total_shares(), MINIMUM_LIQUIDITY);
+    assert_eq!(
+        liq_pool.get_reserves(),
+        Vec::from_array(&e, [MINIMUM_LIQUIDITY, MINIMUM_LIQUIDITY])
+    );
+    assert_eq!(
+        liq_pool.deposit(&user2, &Vec::from_array(&e, [10_0000000, 10_0000000]), &0),
+        (Vec::from_array(&e, [10_0000000, 10_0000000]), 10_0000000)
+    );
+    assert_eq!(
+        setup.token_share.balance(&liq_pool.address),
+        MINIMUM_LIQUIDITY as i128
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2022)")]
+fn test_insufficient_initial_liquidity() {
+    let setup = Setup::default();
+    let user1 = setup.users[0].clone();
+    setup.liq_pool.deposit(
+        &user1,
+        &Vec::from_array(&setup.env, [MINIMUM_LIQUIDITY, MINIMUM_LIQUIDITY]),
+        &0,
+    );
+}
+
 #[test]
 #[should_panic(expected = "Error(Contract, #2004)")]
 fn test_zero_initial_deposit() {
@@ -440,7 +506,14 @@ fn test_zero_deposit_ok() {
     let setup = Setup::default();
     let liq_pool = setup.liq_pool;
     let user1 = setup.users[0].clone();
-    liq_pool.deposit(&user1, &Vec::from_array(&setup.env, [100, 100]), &0);
+    liq_pool.deposit(
+        &user1,
+        &Vec::from_array(
+            &setup.env,
+            [100 + MINIMUM_LIQUIDITY, 100 + MINIMUM_LIQUIDITY],
+        ),
+        &0,
+    );
     liq_pool.deposit(&user1, &Vec::from_array(&setup.env, [100, 0]), &0);
 }
 
@@ -518,53 +591,48 @@ fn test_custom_fee() {
         (3000, 6930693_u128), // 30%
         (5000, 4950495_u128), // 50%
     ] {
-        let liqpool = create_liqpool_contract(
-            &setup.env,
-            &Address::generate(&setup.env),
-            &setup.users[0],
-            &install_token_wasm(&setup.env),
-            &Vec::from_array(
+        // pool reserves can't be reset with full withdraw because of the locked liquidity,
+        // so every swap kind is checked against a fresh pool
+        for strict_receive in [false, true] {
+            let liqpool = create_liqpool_contract(
                 &setup.env,
-                [setup.token1.address.clone(), setup.token2.address.clone()],
-            ),
-            &setup.token_reward.address,
-            &setup.reward_boost_token.address,
-            &setup.reward_boost_feed.address,
-            fee_config.0, // ten percent
-            &setup.plane.address,
-        );
-        liqpool.deposit(
-            &setup.users[0],
-            &Vec::from_array(&setup.env, [100_0000000, 100_0000000]),
-            &0,
-        );
-        assert_eq!(liqpool.estimate_swap(&1, &0, &1_0000000), fee_config.1);
-        assert_eq!(
-            liqpool.swap(&setup.users[0], &1, &0, &1_0000000, &0),
-            fee_config.1
-        );
-
-        // full withdraw & deposit to reset pool reserves
-        liqpool.withdraw(
-            &setup.users[0],
-            &(SorobanTokenClient::new(&setup.env, &liqpool.share_id()).balance(&setup.users[0])
-                as u128),
-            &Vec::from_array(&setup.env, [0, 0]),
-        );
-        liqpool.deposit(
-            &setup.users[0],
-            &Vec::from_array(&setup.env, [100_0000000, 100_0000000]),
-            &0,
-        );
-        assert_eq!(liqpool.estimate_swap(&0, &1, &1_0000000), fee_config.1); // re-check swap result didn't change
-        assert_eq!(
-            liqpool.estimate_swap_strict_receive(&0, &1, &fee_config.1),
-            1_0000000
-        );
-        assert_eq!(
-            liqpool.swap_strict_receive(&setup.users[0], &0, &1, &fee_config.1, &1_0000000),
-            1_0000000
-        );
+                &Address::generate(&setup.env),
+                &setup.users[0],
+                &install_token_wasm(&setup.env),
+                &Vec::from_array(
+                    &setup.env,
+                    [setup.token1.address.clone(), setup.token2.address.clone()],
+                ),
+                &setup.token_reward.address,
+                &setup.reward_boost_token.address,
+                &setup.reward_boost_feed.address,
+                fee_config.0, // ten percent
+                &setup.plane.address,
+            );
+            liqpool.deposit(
+                &setup.users[0],
+                &Vec::from_array(&setup.env, [100_0000000, 100_0000000]),
+                &0,
+            );
+
+            if strict_receive {
+                assert_eq!(liqpool.estimate_swap(&0, &1, &1_0000000), fee_config.1);
+                assert_eq!(
+                    liqpool.estimate_swap_strict_receive(&0, &1, &fee_config.1),
+                    1_0000000
+                );
+                assert_eq!(
+                    liqpool.swap_strict_receive(&setup.users[0], &0, &1, &fee_config.1, &1_0000000),
+                    1_0000000
+                );
+            } else {
+                assert_eq!(liqpool.estimate_swap(&1, &0, &1_0000000), fee_config.1);
+                assert_eq!(
+                    liqpool.swap(&setup.users[0], &1, &0, &1_0000000, &0),
+                    fee_config.1
+                );
+            }
+        }
     }
 }
 
@@ -590,7 +658,11 @@ fn test_simple_ongoing_reward() {
     );
     assert_eq!(liq_pool.get_total_claimed_reward(), 0);
 
-    liq_pool.deposit(&users[0], &Vec::from_array(&env, [100, 100]), &0);
+    liq_pool.deposit(
+        &users[0],
+        &Vec::from_array(&env, [100 + MINIMUM_LIQUIDITY, 100 + MINIMUM_LIQUIDITY]),
+        &0,
+    );
 
     assert_eq!(token_reward.balance(&users[0]), 0);
     // 30 seconds passed, half of the reward is available for the user
@@ -651,7 +723,11 @@ fn test_estimate_ongoing_reward() {
 
     // 10 seconds passed since config, user depositing
     jump(&env, 10);
-    liq_pool.deposit(&users[0], &Vec::from_array(&env, [100, 100]), &0);
+    liq_pool.deposit(
+        &users[0],
+        &Vec::from_array(&env, [100 + MINIMUM_LIQUIDITY, 100 + MINIMUM_LIQUIDITY]),
+        &0,
+    );
 
     assert_eq!(token_reward.balance(&users[0]), 0);
     // 30 seconds passed, half of the reward is available for the user
@@ -672,7 +748,11 @@ fn test_simple_reward() {
 
     // 10 seconds. user depositing
     jump(&env, 10);
-    liq_pool.deposit(&users[0], &Vec::from_array(&env, [100, 100]), &0);
+    liq_pool.deposit(
+        &users[0],
+        &Vec::from_array(&env, [100 + MINIMUM_LIQUIDITY, 100 + MINIMUM_LIQUIDITY]),
+        &0,
+    );
 
     // 20 seconds. rewards set up for 60 seconds
     jump(&env, 10);
@@ -713,7 +793,11 @@ fn test_two_users_rewards() {
 
     // two users make deposit for equal value. second after 30 seconds after rewards start,
     //  so it gets only 1/4 of total reward
-    liq_pool.deposit(&users[0], &Vec::from_array(&env, [100, 100]), &0);
+    liq_pool.deposit(
+        &users[0],
+        &Vec::from_array(&env, [100 + MINIMUM_LIQUIDITY, 100 + MINIMUM_LIQUIDITY]),
+        &0,
+    );
     jump(&env, 30);
     assert_eq!(liq_pool.claim(&users[0]), total_reward_1 / 2);
     liq_pool.deposit(&users[1], &Vec::from_array(&env, [100, 100]), &0);
@@ -745,7 +829,11 @@ fn test_boosted_rewards() {
 
     // two users make deposit for equal value. second after 30 seconds after rewards start,
     //  so it gets only 1/4 of total reward
-    liq_pool.deposit(&users[0], &Vec::from_array(&env, [100, 100]), &0);
+    liq_pool.deposit(
+        &users[0],
+        &Vec::from_array(&env, [100 + MINIMUM_LIQUIDITY, 100 + MINIMUM_LIQUIDITY]),
+        &0,
+    );
     jump(&env, 30);
     assert_eq!(liq_pool.claim(&users[0]), total_reward_1 / 2);
 
@@ -884,7 +972,11 @@ fn test_lazy_user_rewards() {
 
     let total_reward_1 = &TestConfig::default().reward_tps * 60;
 
-    liq_pool.deposit(&users[0], &Vec::from_array(&env, [100, 100]), &0);
+    liq_pool.deposit(
+        &users[0],
+        &Vec::from_array(&env, [100 + MINIMUM_LIQUIDITY, 100 + MINIMUM_LIQUIDITY]),
+        &0,
+    );
     jump(&env, 59);
     liq_pool.deposit(&users[1], &Vec::from_array(&env, [1000, 1000]), &0);
     jump(&env, 100);
@@ -913,7 +1005,11 @@ fn test_rewards_disable_before_expiration() {
     let users = setup.users;
 
     // user 1 has 10% of total reward
-    liq_pool.deposit(&users[0], &Vec::from_array(&env, [900, 900]), &0);
+    liq_pool.deposit(
+        &users[0],
+        &Vec::from_array(&env, [900 + MINIMUM_LIQUIDITY, 900 + MINIMUM_LIQUIDITY]),
+        &0,
+    );
     liq_pool.deposit(&users[1], &Vec::from_array(&env, [100, 100]), &0);
 
     jump(&env, 10);
@@ -953,7 +1049,11 @@ fn test_rewards_disable_after_expiration() {
     let users = setup.users;
 
     // user 1 has 10% of total reward
-    liq_pool.deposit(&users[0], &Vec::from_array(&env, [900, 900]), &0);
+    liq_pool.deposit(
+        &users[0],
+        &Vec::from_array(&env, [900 + MINIMUM_LIQUIDITY, 900 + MINIMUM_LIQUIDITY]),
+        &0,
+    );
     liq_pool.deposit(&users[1], &Vec::from_array(&env, [100, 100]), &0);
 
     jump(&env, 10);
@@ -980,7 +1080,11 @@ fn test_rewards_set_new_after_expiration() {
     let users = setup.users;
 
     // user 1 has 10% of total reward
-    liq_pool.deposit(&users[0], &Vec::from_array(&env, [900, 900]), &0);
+    liq_pool.deposit(
+        &users[0],
+        &Vec::from_array(&env, [900 + MINIMUM_LIQUIDITY, 900 + MINIMUM_LIQUIDITY]),
+        &0,
+    );
     liq_pool.deposit(&users[1], &Vec::from_array(&env, [100, 100]), &0);
 
     jump(&env, 10);
@@ -1116,12 +1220,12 @@ fn test_deposit_inequal_return_change() {
     let token2 = setup.token2;
     let users = setup.users;
     let user1 = users[0].clone();
-    liq_pool.deposit(&user1, &Vec::from_array(&e, [100, 100]), &0);
-    assert_eq!(token1.balance(&liq_pool.address), 100);
-    assert_eq!(token2.balance(&liq_pool.address), 100);
+    liq_pool.deposit(&user1, &Vec::from_array(&e, [1100, 1100]), &0);
+    assert_eq!(token1.balance(&liq_pool.address), 1100);
+    assert_eq!(token2.balance(&liq_pool.address), 1100);
     liq_pool.deposit(&user1, &Vec::from_array(&e, [200, 100]), &0);
-    assert_eq!(token1.balance(&liq_pool.address), 200);
-    assert_eq!(token2.balance(&liq_pool.address), 200);
+    assert_eq!(token1.balance(&liq_pool.address), 1200);
+    assert_eq!(token2.balance(&liq_pool.address), 1200);
 }
 
 #[test]
@@ -1175,7 +1279,11 @@ fn test_config_rewards_override() {
     let users = setup.users;
     let router = setup.router;
 
-    liq_pool.deposit(&users[1], &Vec::from_array(&env, [100, 100]), &0);
+    liq_pool.deposit(
+        &users[1],
+        &Vec::from_array(&env, [100 + MINIMUM_LIQUIDITY, 100 + MINIMUM_LIQUIDITY]),
+        &0,
+    );
 
     assert_eq!(liq_pool.get_total_accumulated_reward(), 0);
     assert_eq!(liq_pool.get_total_configured_reward(), 0);
@@ -1233,9 +1341,13 @@ fn test_large_numbers() {
     liq_pool.deposit(&user1, &desired_amounts, &0);
 
     // when we deposit equal amounts, we gotta have deposited amount of share tokens
-    let expected_share_amount = amount_to_deposit as i128;
+    // except the minimum liquidity locked by the pool on the first deposit
+    let expected_share_amount = (amount_to_deposit - MINIMUM_LIQUIDITY) as i128;
     assert_eq!(token_share.balance(&user1), expected_share_amount);
-    assert_eq!(token_share.balance(&liq_pool.address), 0);
+    assert_eq!(
+        token_share.balance(&liq_pool.address),
+        MINIMUM_LIQUIDITY as i128
+    );
     assert_eq!(
         token1.balance(&user1),
         i128::MAX - amount_to_deposit as i128
@@ -1274,22 +1386,27 @@ fn test_large_numbers() {
         amount_to_deposit as i128 - estimate_swap_result as i128
     );
 
-    let withdraw_amounts = [
-        amount_to_deposit + swap_in,
-        amount_to_deposit - estimate_swap_result,
-    ];
+    // share of the locked minimum liquidity stays in the pool
+    let reserve_a = amount_to_deposit + swap_in;
+    let reserve_b = amount_to_deposit - estimate_swap_result;
+    let locked_a = (reserve_a * MINIMUM_LIQUIDITY).div_ceil(amount_to_deposit);
+    let locked_b = (reserve_b * MINIMUM_LIQUIDITY).div_ceil(amount_to_deposit);
+    let withdraw_amounts = [reserve_a - locked_a, reserve_b - locked_b];
     liq_pool.withdraw(
         &user1,
         &(expected_share_amount as u128),
         &Vec::from_array(&e, withdraw_amounts),
     );
 
-    assert_eq!(token1.balance(&user1), i128::MAX);
-    assert_eq!(token2.balance(&user1), i128::MAX);
+    assert_eq!(token1.balance(&user1), i128::MAX - locked_a as i128);
+    assert_eq!(token2.balance(&user1), i128::MAX - locked_b as i128);
     assert_eq!(token_share.balance(&user1), 0);
-    assert_eq!(token1.balance(&liq_pool.address), 0);
-    assert_eq!(token2.balance(&liq_pool.address), 0);
-    assert_eq!(token_share.balance(&liq_pool.address), 0);
+    assert_eq!(token1.balance(&liq_pool.address), locked_a as i128);
+    assert_eq!(token2.balance(&liq_pool.address), locked_b as i128);
+    assert_eq!(
+        token_share.balance(&liq_pool.address),
+        MINIMUM_LIQUIDITY as i128
+    );
 }
 
 #[test]
@@ -1445,7 +1562,11 @@ fn test_claim_killed() {
 
     // 10 seconds. user depositing
     jump(&env, 10);
-    liq_pool.deposit(&users[1], &Vec::from_array(&env, [100, 100]), &0);
+    liq_pool.deposit(
+        &users[1],
+        &Vec::from_array(&env, [100 + MINIMUM_LIQUIDITY, 100 + MINIMUM_LIQUIDITY]),
+        &0,
+    );
 
     // 20 seconds. rewards set up for 60 seconds
     jump(&env, 10);
@@ -1525,12 +1646,18 @@ fn test_withdraw_rewards() {
     );
     let token_share = ShareTokenClient::new(&e, &liq_pool.share_id());
 
-    token1_admin_client.mint(&user1, &100_0000000);
-    token2_admin_client.mint(&user1, &100_0000000);
-    liq_pool.deposit(&user1, &Vec::from_array(&e, [100_0000000, 100_0000000]), &0);
+    // first deposit covers the locked minimum liquidity
+    let first_deposit = 100_0000000 + MINIMUM_LIQUIDITY;
+    token1_admin_client.mint(&user1, &(first_deposit as i128));
+    token2_admin_client.mint(&user1, &(first_deposit as i128));
+    liq_pool.deposit(
+        &user1,
+        &Vec::from_array(&e, [first_deposit, first_deposit]),
+        &0,
+    );
     assert_eq!(
         liq_pool.get_reserves(),
-        Vec::from_array(&e, [100_0000000, 100_0000000])
+        Vec::from_array(&e, [first_deposit, first_deposit])
     );
 
     liq_pool.set_rewards_config(
@@ -1550,18 +1677,27 @@ fn test_withdraw_rewards() {
     );
     assert_eq!(
         liq_pool.get_reserves(),
-        Vec::from_array(&e, [1_100_0000000, 1_100_0000000])
+        Vec::from_array(
+            &e,
+            [1_000_0000000 + first_deposit, 1_000_0000000 + first_deposit]
+        )
     );
 
     assert_eq!(
         liq_pool.get_reserves(),
-        Vec::from_array(&e, [1_100_0000000, 1_100_0000000])
+        Vec::from_array(
+            &e,
+            [1_000_0000000 + first_deposit, 1_000_0000000 + first_deposit]
+        )
     );
     assert_eq!(
         token1.balance(&liq_pool.address),
-        1_100_0000000 + 1_000_0000000 * 100
+        (1_000_0000000 + first_deposit + 1_000_0000000 * 100) as i128
+    );
+    assert_eq!(
+        token2.balance(&liq_pool.address),
+        (1_000_0000000 + first_deposit) as i128
     );
-    assert_eq!(token2.balance(&liq_pool.address), 1_100_0000000);
 
     assert_eq!(
         liq_pool.withdraw(
@@ -1573,13 +1709,13 @@ fn test_withdraw_rewards() {
     );
     assert_eq!(
         liq_pool.get_reserves(),
-        Vec::from_array(&e, [100_0000000, 100_0000000])
+        Vec::from_array(&e, [first_deposit, first_deposit])
     );
     assert_eq!(
         token1.balance(&liq_pool.address),
-        100_0000000 + 1_000_0000000 * 100
+        (first_deposit + 1_000_0000000 * 100) as i128
     );
-    assert_eq!(token2.balance(&liq_pool.address), 100_0000000);
+    assert_eq!(token2.balance(&liq_pool.address), first_deposit as i128);
     assert_eq!(token1.balance(&user2), 1_000_0000000);
     assert_eq!(token2.balance(&user2), 1_000_0000000);
 
@@ -1802,12 +1938,18 @@ fn test_claim_rewards() {
         &plane.address,
     );
 
-    token1_admin_client.mint(&user1, &100_0000000);
-    token2_admin_client.mint(&user1, &100_0000000);
-    liq_pool.deposit(&user1, &Vec::from_array(&e, [100_0000000, 100_0000000]), &0);
+    // first deposit covers the locked minimum liquidity
+    let first_deposit = 100_0000000 + MINIMUM_LIQUIDITY;
+    token1_admin_client.mint(&user1, &(first_deposit as i128));
+    token2_admin_client.mint(&user1, &(first_deposit as i128));
+    liq_pool.deposit(
+        &user1,
+        &Vec::from_array(&e, [first_deposit, first_deposit]),
+        &0,
+    );
     assert_eq!(
         liq_pool.get_reserves(),
-        Vec::from_array(&e, [100_0000000, 100_0000000])
+        Vec::from_array(&e, [first_deposit, first_deposit])
     );
 
     liq_pool.set_rewards_config(&admin, &e.ledger().timestamp().saturating_add(100), &1000);
@@ -1824,7 +1966,7 @@ fn test_drain_reward() {
         users_count: 5,
         reward_tps: 10_5000000,
         rewards_count: 10_5000000 * 60,
-        mint_to_user: 1000_0000000,
+        mint_to_user: 1000_0000000 + MINIMUM_LIQUIDITY as i128,
         ..TestConfig::default()
     });
     let env = setup.env;
@@ -1837,7 +1979,13 @@ fn test_drain_reward() {
 
     liq_pool.deposit(
         &users[0],
-        &Vec::from_array(&env, [1000_0000000, 1000_0000000]),
+        &Vec::from_array(
+            &env,
+            [
+                1000_0000000 + MINIMUM_LIQUIDITY,
+                1000_0000000 + MINIMUM_LIQUIDITY,
+            ],
+        ),
         &0,
     );
     let (_, lp_amount) = liq_pool.deposit(
@@ -1916,12 +2064,13 @@ fn test_drain_reserves() {
     token_reward_admin_client.mint(&liq_pool.address, &(1_000_0000000 * 100));
     assert_eq!(liq_pool.get_reserves(), Vec::from_array(&e, [0, 0]));
 
-    // first user deposits
-    token1_admin_client.mint(&user1, &1_000_000_0000000);
-    token2_admin_client.mint(&user1, &1_000_000_0000000);
+    // first user deposits, covering the locked minimum liquidity
+    let first_deposit = 1_000_000_0000000 + MINIMUM_LIQUIDITY;
+    token1_admin_client.mint(&user1, &(first_deposit as i128));
+    token2_admin_client.mint(&user1, &(first_deposit as i128));
     liq_pool.deposit(
         &user1,
-        &Vec::from_array(&e, [1_000_000_0000000, 1_000_000_0000000]),
+        &Vec::from_array(&e, [first_deposit, first_deposit]),
         &0,
     );
 
@@ -1956,10 +2105,22 @@ fn test_drain_reserves() {
     // check reserves
     assert_eq!(
         liq_pool.get_reserves(),
-        Vec::from_array(&e, [1_300_000_0000000, 1_300_000_0000000])
+        Vec::from_array(
+            &e,
+            [
+                300_000_0000000 + first_deposit,
+                300_000_0000000 + first_deposit
+            ]
+        )
+    );
+    assert_eq!(
+        token1.balance(&liq_pool.address),
+        (300_000_0000000 + first_deposit + 1) as i128
+    ); // 1 token left on balance because of rounding
+    assert_eq!(
+        token2.balance(&liq_pool.address),
+        (300_000_0000000 + first_deposit) as i128
     );
-    assert_eq!(token1.balance(&liq_pool.address), 1_300_000_0000001); // 1 token left on balance because of rounding
-    assert_eq!(token2.balance(&liq_pool.address), 1_300_000_0000000);
 }
 
 #[test]
@@ -2513,9 +2674,9 @@ fn test_claim_event() {
 
     let user = Address::generate(&setup.env);
 
-    token_1_admin_client.mint(&user, &1000);
-    token_2_admin_client.mint(&user, &1000);
-    liq_pool.deposit(&user, &Vec::from_array(&setup.env, [1000, 1000]), &0);
+    token_1_admin_client.mint(&user, &2000);
+    token_2_admin_client.mint(&user, &2000);
+    liq_pool.deposit(&user, &Vec::from_array(&setup.env, [2000, 2000]), &0);
     token_reward_admin_client.mint(&liq_pool.address, &1_000_000_0000000);
     let reward_1_tps = 10_5000000_u128;
     let total_reward_1 = reward_1_tps * 70;
@@ -2544,3 +2705,534 @@ fn test_claim_event() {
         ]
     );
 }
+
+#[test]
+fn test_protocol_fee() {
+    let setup = Setup::new_with_config(&TestConfig {
+        mint_to_user: 1000000_0000000,
+        ..TestConfig::default()
+    });
+    let liq_pool = setup.liq_pool;
+    let user = setup.users[1].clone();
+    let fees_collector = Address::generate(&setup.env);
+
+    liq_pool.commit_protocol_fee(&setup.router, &5000);
+    assert_eq!(liq_pool.get_protocol_fee_fraction(), 0);
+    jump(&setup.env, ADMIN_ACTIONS_DELAY + 1);
+    liq_pool.apply_protocol_fee(&setup.router);
+    assert_eq!(liq_pool.get_protocol_fee_fraction(), 5000);
+    liq_pool.set_fees_collector(&setup.router, &fees_collector);
+
+    liq_pool.deposit(
+        &user,
+        &Vec::from_array(&setup.env, [100_0000000, 100_0000000]),
+        &0,
+    );
+
+    // user output doesn't depend on the protocol fee: 29703 fee charged, half of it taken by the protocol
+    assert_eq!(liq_pool.swap(&user, &1, &0, &1_0000000, &0), 9871287);
+    assert_eq!(
+        setup
+            .env
+            .events()
+            .all()
+            .get(setup.env.events().all().len() - 3),
+        Some((
+            liq_pool.address.clone(),
+            (
+                Symbol::new(&setup.env, "protocol_fee"),
+                setup.token1.address.clone()
+            )
+                .into_val(&setup.env),
+            (14851_u128,).into_val(&setup.env),
+        ))
+    );
+    assert_eq!(
+        liq_pool.get_protocol_fees(),
+        Vec::from_array(&setup.env, [14851, 0])
+    );
+    assert_eq!(
+        liq_pool.get_reserves(),
+        Vec::from_array(&setup.env, [100_0000000 - 9871287 - 14851, 101_0000000])
+    );
+    assert_eq!(
+        setup.token1.balance(&liq_pool.address) as u128,
+        100_0000000 - 9871287
+    );
+
+    assert_eq!(
+        liq_pool.claim_protocol_fees(),
+        Vec::from_array(&setup.env, [14851, 0])
+    );
+    assert_eq!(setup.token1.balance(&fees_collector), 14851);
+    assert_eq!(
+        liq_pool.get_protocol_fees(),
+        Vec::from_array(&setup.env, [0, 0])
+    );
+    assert_eq!(
+        setup.token1.balance(&liq_pool.address) as u128,
+        liq_pool.get_reserves().get(0).unwrap()
+    );
+    assert_eq!(
+        vec![&setup.env, setup.env.events().all().last().unwrap()],
+        vec![
+            &setup.env,
+            (
+                liq_pool.address.clone(),
+                (
+                    Symbol::new(&setup.env, "claim_protocol_fees"),
+                    fees_collector.clone()
+                )
+                    .into_val(&setup.env),
+                (Vec::from_array(&setup.env, [14851_u128, 0]),).into_val(&setup.env),
+            ),
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2908)")]
+fn test_protocol_fee_too_early() {
+    let setup = Setup::default();
+
+    setup.liq_pool.commit_protocol_fee(&setup.router, &5000);
+    jump(&setup.env, ADMIN_ACTIONS_DELAY - 1);
+    setup.liq_pool.apply_protocol_fee(&setup.router);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2012)")]
+fn test_protocol_fee_out_of_bounds() {
+    let setup = Setup::default();
+    setup.liq_pool.commit_protocol_fee(&setup.router, &10001);
+}
+
+#[test]
+fn test_update_fee() {
+    let setup = Setup::new_with_config(&TestConfig {
+        mint_to_user: 1000000_0000000,
+        ..TestConfig::default()
+    });
+    let liq_pool = setup.liq_pool;
+    let user = setup.users[1].clone();
+    liq_pool.deposit(
+        &user,
+        &Vec::from_array(&setup.env, [100_0000000, 100_0000000]),
+        &0,
+    );
+    assert_eq!(liq_pool.estimate_swap(&1, &0, &1_0000000), 9871287);
+
+    liq_pool.commit_new_fee(&setup.router, &100);
+    assert_eq!(
+        vec![&setup.env, setup.env.events().all().last().unwrap()],
+        vec![
+            &setup.env,
+            (
+                liq_pool.address.clone(),
+                (Symbol::new(&setup.env, "commit_new_fee"),).into_val(&setup.env),
+                (100_u32,).into_val(&setup.env),
+            ),
+        ]
+    );
+    assert_eq!(liq_pool.get_fee_fraction(), 30);
+    assert!(liq_pool.try_apply_new_fee(&setup.router).is_err());
+
+    jump(&setup.env, ADMIN_ACTIONS_DELAY + 1);
+    liq_pool.apply_new_fee(&setup.router);
+    assert_eq!(liq_pool.get_fee_fraction(), 100);
+    assert_eq!(liq_pool.estimate_swap(&1, &0, &1_0000000), 9801980);
+
+    liq_pool.commit_new_fee(&setup.router, &10);
+    liq_pool.revert_new_parameters(&setup.router);
+    jump(&setup.env, ADMIN_ACTIONS_DELAY + 1);
+    assert!(liq_pool.try_apply_new_fee(&setup.router).is_err());
+    assert_eq!(liq_pool.get_fee_fraction(), 100);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2906)")]
+fn test_update_fee_another_action_active() {
+    let setup = Setup::default();
+
+    setup.liq_pool.commit_new_fee(&setup.router, &100);
+    setup.liq_pool.commit_new_fee(&setup.router, &10);
+}
+
+#[test]
+fn test_price_cumulative() {
+    let setup = Setup::new_with_config(&TestConfig {
+        mint_to_user: 1000000_0000000,
+        ..TestConfig::default()
+    });
+    let e = setup.env;
+    let liq_pool = setup.liq_pool;
+    let user = setup.users[1].clone();
+    liq_pool.deposit(&user, &Vec::from_array(&e, [100_0000000, 100_0000000]), &0);
+    let zero = U256::from_u32(&e, 0);
+    assert_eq!(
+        liq_pool.get_price_cumulative(),
+        Vec::from_array(&e, [zero.clone(), zero.clone()])
+    );
+    assert_eq!(liq_pool.get_price_timestamp_last(), 0);
+
+    jump(&e, 100);
+    liq_pool.swap(&user, &0, &1, &1_0000000, &0);
+    let price_cumulative = Vec::from_array(
+        &e,
+        [
+            U256::from_u128(&e, 100_000000000000000000),
+            U256::from_u128(&e, 100_000000000000000000),
+        ],
+    );
+    assert_eq!(liq_pool.get_price_cumulative(), price_cumulative);
+    assert_eq!(liq_pool.get_price_timestamp_last(), 100);
+    assert_eq!(
+        setup
+            .plane
+            .get_price_cumulative(&Vec::from_array(&e, [liq_pool.address.clone()])),
+        Vec::from_array(&e, [(price_cumulative, 100)])
+    );
+
+    // reserves are 101 / 99.0128713 since the swap
+    jump(&e, 50);
+    liq_pool.swap(&user, &0, &1, &1_0000000, &0);
+    assert_eq!(
+        liq_pool.get_price_cumulative(),
+        Vec::from_array(
+            &e,
+            [
+                U256::from_u128(&e, 149_016272920792079207),
+                U256::from_u128(&e, 151_003469889272870728),
+            ]
+        )
+    );
+    assert_eq!(liq_pool.get_price_timestamp_last(), 150);
+}
+
+#[test]
+fn test_flash_loan() {
+    let setup = Setup::new_with_config(&TestConfig {
+        mint_to_user: 1000000_0000000,
+        ..TestConfig::default()
+    });
+    let e = setup.env;
+    let liq_pool = setup.liq_pool;
+    let user = setup.users[1].clone();
+    liq_pool.deposit(&user, &Vec::from_array(&e, [100_0000000, 100_0000000]), &0);
+
+    let receiver = create_flash_loan_receiver(&e, &liq_pool.address);
+    // receiver pays flash fees from its own funds
+    get_token_admin_client(&e, &setup.token1.address).mint(&receiver.address, &1_0000000);
+    get_token_admin_client(&e, &setup.token2.address).mint(&receiver.address, &1_0000000);
+
+    let fees = liq_pool.flash_loan(
+        &receiver.address,
+        &Vec::from_array(&e, [10_0000000, 5_0000000]),
+        &Bytes::new(&e),
+    );
+    assert_eq!(fees, Vec::from_array(&e, [300000, 150000]));
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                liq_pool.address.clone(),
+                (
+                    Symbol::new(&e, "flash_loan"),
+                    setup.token1.address.clone(),
+                    setup.token2.address.clone()
+                )
+                    .into_val(&e),
+                (
+                    receiver.address.clone(),
+                    10_0000000_i128,
+                    300000_i128,
+                    5_0000000_i128,
+                    150000_i128
+                )
+                    .into_val(&e),
+            ),
+        ]
+    );
+
+    // fees are added to the reserves
+    assert_eq!(
+        liq_pool.get_reserves(),
+        Vec::from_array(&e, [100_0300000, 100_0150000])
+    );
+    assert_eq!(setup.token1.balance(&liq_pool.address), 100_0300000);
+    assert_eq!(setup.token2.balance(&liq_pool.address), 100_0150000);
+    assert_eq!(setup.token1.balance(&receiver.address), 1_0000000 - 300000);
+    assert_eq!(setup.token2.balance(&receiver.address), 1_0000000 - 150000);
+
+    // loan can't exceed the reserves
+    assert_eq!(
+        liq_pool
+            .try_flash_loan(
+                &receiver.address,
+                &Vec::from_array(&e, [100_0300001, 0]),
+                &Bytes::new(&e),
+            )
+            .unwrap_err(),
+        Ok(Error::from_contract_error(2019))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2021)")]
+fn test_flash_loan_not_repaid() {
+    let setup = Setup::new_with_config(&TestConfig {
+        mint_to_user: 1000000_0000000,
+        ..TestConfig::default()
+    });
+    let e = setup.env;
+    let liq_pool = setup.liq_pool;
+    let user = setup.users[1].clone();
+    liq_pool.deposit(&user, &Vec::from_array(&e, [100_0000000, 100_0000000]), &0);
+
+    let receiver = create_flash_loan_receiver(&e, &liq_pool.address);
+    // receiver returns borrowed amount without the fee
+    liq_pool.flash_loan(
+        &receiver.address,
+        &Vec::from_array(&e, [10_0000000, 0]),
+        &Bytes::from_array(&e, &[1]),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #206)")]
+fn test_flash_loan_swap_killed() {
+    let setup = Setup::new_with_config(&TestConfig {
+        mint_to_user: 1000000_0000000,
+        ..TestConfig::default()
+    });
+    let e = setup.env;
+    let liq_pool = setup.liq_pool;
+    let user = setup.users[1].clone();
+    liq_pool.deposit(&user, &Vec::from_array(&e, [100_0000000, 100_0000000]), &0);
+
+    let receiver = create_flash_loan_receiver(&e, &liq_pool.address);
+    liq_pool.kill_swap(&setup.admin);
+    liq_pool.flash_loan(
+        &receiver.address,
+        &Vec::from_array(&e, [10_0000000, 0]),
+        &Bytes::new(&e),
+    );
+}
+
+#[test]
+fn test_deposit_single() {
+    let setup = Setup::new_with_config(&TestConfig {
+        mint_to_user: 1000000_0000000,
+        ..TestConfig::default()
+    });
+    let e = setup.env;
+    let liq_pool = setup.liq_pool;
+    let user = setup.users[1].clone();
+    liq_pool.deposit(&user, &Vec::from_array(&e, [100_0000000, 100_0000000]), &0);
+    let (balance_a, balance_b) = (setup.token1.balance(&user), setup.token2.balance(&user));
+
+    assert_eq!(
+        liq_pool.deposit_single(&user, &0, &10_0000000, &0),
+        (Vec::from_array(&e, [51114247, 46467497]), 48731948)
+    );
+    // swapped amount is deposited with the rest of the token, nothing is returned
+    assert_eq!(setup.token1.balance(&user), balance_a - 10_0000000);
+    assert_eq!(setup.token2.balance(&user), balance_b);
+    assert_eq!(
+        liq_pool.get_reserves(),
+        Vec::from_array(&e, [110_0000000, 100_0000000])
+    );
+    assert_eq!(
+        setup.token_share.balance(&user) as u128,
+        100_0000000 - MINIMUM_LIQUIDITY + 48731948
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2006)")]
+fn test_deposit_single_min_shares() {
+    let setup = Setup::new_with_config(&TestConfig {
+        mint_to_user: 1000000_0000000,
+        ..TestConfig::default()
+    });
+    let e = setup.env;
+    let liq_pool = setup.liq_pool;
+    let user = setup.users[1].clone();
+    liq_pool.deposit(&user, &Vec::from_array(&e, [100_0000000, 100_0000000]), &0);
+    liq_pool.deposit_single(&user, &1, &10_0000000, &48731949);
+}
+
+#[test]
+fn test_withdraw_one_coin() {
+    let setup = Setup::new_with_config(&TestConfig {
+        mint_to_user: 1000000_0000000,
+        ..TestConfig::default()
+    });
+    let e = setup.env;
+    let liq_pool = setup.liq_pool;
+    let user = setup.users[1].clone();
+    liq_pool.deposit(&user, &Vec::from_array(&e, [100_0000000, 100_0000000]), &0);
+    let (balance_a, balance_b) = (setup.token1.balance(&user), setup.token2.balance(&user));
+
+    assert_eq!(liq_pool.calc_withdraw_one_coin(&10_0000000, &0), 189730000);
+    assert_eq!(
+        liq_pool
+            .try_withdraw_one_coin(&user, &10_0000000, &0, &189730001)
+            .unwrap_err(),
+        Ok(Error::from_contract_error(2006))
+    );
+    assert_eq!(
+        liq_pool.withdraw_one_coin(&user, &10_0000000, &0, &189730000),
+        Vec::from_array(&e, [189730000, 0])
+    );
+    assert_eq!(setup.token1.balance(&user), balance_a + 189730000);
+    assert_eq!(setup.token2.balance(&user), balance_b);
+    assert_eq!(
+        setup.token_share.balance(&user) as u128,
+        90_0000000 - MINIMUM_LIQUIDITY
+    );
+    // share of the other token is swapped back into the pool
+    assert_eq!(
+        liq_pool.get_reserves(),
+        Vec::from_array(&e, [81_0270000, 100_0000000])
+    );
+
+    // pool can't be emptied with a single token withdrawal
+    assert_eq!(
+        liq_pool
+            .try_calc_withdraw_one_coin(&90_0000000, &1)
+            .unwrap_err(),
+        Ok(Error::from_contract_error(2010))
+    );
+
+    assert_eq!(
+        liq_pool
+            .try_withdraw_one_coin(&user, &0, &0, &0)
+            .unwrap_err(),
+        Ok(Error::from_contract_error(2018))
+    );
+
+    // withdrawal swaps the other token, so it's disabled along with swaps
+    liq_pool.kill_swap(&setup.admin);
+    assert_eq!(
+        liq_pool
+            .try_withdraw_one_coin(&user, &10_0000000, &0, &0)
+            .unwrap_err(),
+        Ok(Error::from_contract_error(206))
+    );
+}
+
+#[test]
+fn test_skim_sync() {
+    let setup = Setup::new_with_config(&TestConfig {
+        mint_to_user: 1000000_0000000,
+        ..TestConfig::default()
+    });
+    let e = setup.env;
+    let liq_pool = setup.liq_pool;
+    let user = setup.users[1].clone();
+    liq_pool.deposit(&user, &Vec::from_array(&e, [100_0000000, 100_0000000]), &0);
+
+    // nothing to skim yet
+    let to = Address::generate(&e);
+    assert_eq!(
+        liq_pool.skim(&setup.admin, &to),
+        Vec::from_array(&e, [0, 0])
+    );
+
+    // tokens sent directly to the pool are not accounted in the reserves
+    setup.token1.transfer(&user, &liq_pool.address, &1_0000000);
+    setup.token2.transfer(&user, &liq_pool.address, &2_0000000);
+    assert_eq!(
+        liq_pool.skim(&setup.admin, &to),
+        Vec::from_array(&e, [1_0000000, 2_0000000])
+    );
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                liq_pool.address.clone(),
+                (Symbol::new(&e, "skim"), to.clone()).into_val(&e),
+                (Vec::from_array(&e, [1_0000000_u128, 2_0000000_u128]),).into_val(&e),
+            ),
+        ]
+    );
+    assert_eq!(setup.token1.balance(&to), 1_0000000);
+    assert_eq!(setup.token2.balance(&to), 2_0000000);
+    assert_eq!(setup.token1.balance(&liq_pool.address), 100_0000000);
+    assert_eq!(setup.token2.balance(&liq_pool.address), 100_0000000);
+    assert_eq!(
+        liq_pool.get_reserves(),
+        Vec::from_array(&e, [100_0000000, 100_0000000])
+    );
+
+    // sync adds the surplus to the reserves
+    setup.token1.transfer(&user, &liq_pool.address, &3_0000000);
+    assert_eq!(
+        liq_pool.sync(),
+        Vec::from_array(&e, [103_0000000, 100_0000000])
+    );
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                liq_pool.address.clone(),
+                (Symbol::new(&e, "sync"),).into_val(&e),
+                (Vec::from_array(&e, [103_0000000_u128, 100_0000000_u128]),).into_val(&e),
+            ),
+        ]
+    );
+    assert_eq!(
+        liq_pool.get_reserves(),
+        Vec::from_array(&e, [103_0000000, 100_0000000])
+    );
+    assert_eq!(
+        liq_pool.skim(&setup.admin, &to),
+        Vec::from_array(&e, [0, 0])
+    );
+}
+
+#[test]
+fn test_skim_reward_token_in_pool() {
+    let setup = Setup::new_with_config(&TestConfig {
+        reward_tps: 1_0000000,
+        reward_token_in_pool: true,
+        mint_to_user: 1000000_0000000,
+        rewards_count: 60_0000000,
+        ..TestConfig::default()
+    });
+    assert_eq!(setup.token1.address, setup.token_reward.address);
+    let e = setup.env;
+    let liq_pool = setup.liq_pool;
+    let user = setup.users[1].clone();
+    liq_pool.deposit(&user, &Vec::from_array(&e, [100_0000000, 100_0000000]), &0);
+
+    // only reward token balance above the configured rewards is skimmed
+    SorobanTokenAdminClient::new(&e, &setup.token_reward.address)
+        .mint(&liq_pool.address, &10_0000000);
+    setup.token2.transfer(&user, &liq_pool.address, &1_0000000);
+    assert_eq!(liq_pool.get_unused_reward(), 10_0000000);
+    let to = Address::generate(&e);
+    assert_eq!(
+        liq_pool.skim(&setup.admin, &to),
+        Vec::from_array(&e, [10_0000000, 1_0000000])
+    );
+    assert_eq!(setup.token_reward.balance(&to), 10_0000000);
+    assert_eq!(
+        liq_pool.sync(),
+        Vec::from_array(&e, [100_0000000, 100_0000000])
+    );
+    assert_eq!(liq_pool.get_unused_reward(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2010)")]
+fn test_sync_empty_pool() {
+    let setup = Setup::default();
+    SorobanTokenAdminClient::new(&setup.env, &setup.token1.address)
+        .mint(&setup.liq_pool.address, &1_0000000);
+    setup.liq_pool.sync();
+}
@@ -1,9 +1,10 @@
 #![cfg(test)]
+extern crate std;
 
 use crate::testutils::Setup;
 use access_control::constants::ADMIN_ACTIONS_DELAY;
-use soroban_sdk::testutils::Address as _;
-use soroban_sdk::{symbol_short, Address, Symbol, Vec};
+use soroban_sdk::testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Ledger};
+use soroban_sdk::{symbol_short, Address, IntoVal, Symbol, Vec};
 use token_share::Client as ShareTokenClient;
 use utils::test_utils::{install_dummy_wasm, jump};
 
@@ -84,6 +85,78 @@ fn test_admin_transfer_ownership() {
     pool.commit_transfer_ownership(&admin_new, &symbol_short!("Admin"), &admin_new);
 }
 
+#[test]
+#[should_panic(expected = "Error(Contract, #2908)")]
+fn test_admin_accept_ownership_too_early() {
+    let setup = Setup::default();
+    let pool = setup.liq_pool;
+    let admin_original = setup.users[0].clone();
+    let admin_new = Address::generate(&setup.env);
+
+    pool.commit_transfer_ownership(&admin_original, &symbol_short!("Admin"), &admin_new);
+    jump(&setup.env, ADMIN_ACTIONS_DELAY - 1);
+    pool.accept_ownership(&admin_new, &symbol_short!("Admin"));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2909)")]
+fn test_admin_accept_ownership_wrong_address() {
+    let setup = Setup::default();
+    let pool = setup.liq_pool;
+    let admin_original = setup.users[0].clone();
+    let admin_new = Address::generate(&setup.env);
+
+    pool.commit_transfer_ownership(&admin_original, &symbol_short!("Admin"), &admin_new);
+    jump(&setup.env, ADMIN_ACTIONS_DELAY + 1);
+    pool.accept_ownership(&admin_original, &symbol_short!("Admin"));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2907)")]
+fn test_admin_accept_ownership_reverted() {
+    let setup = Setup::default();
+    let pool = setup.liq_pool;
+    let admin_original = setup.users[0].clone();
+    let admin_new = Address::generate(&setup.env);
+
+    pool.commit_transfer_ownership(&admin_original, &symbol_short!("Admin"), &admin_new);
+    jump(&setup.env, ADMIN_ACTIONS_DELAY + 1);
+    pool.revert_transfer_ownership(&admin_original, &symbol_short!("Admin"));
+    pool.accept_ownership(&admin_new, &symbol_short!("Admin"));
+}
+
+#[test]
+fn test_admin_accept_ownership() {
+    let setup = Setup::default();
+    let pool = setup.liq_pool;
+    let admin_original = setup.users[0].clone();
+    let admin_new = Address::generate(&setup.env);
+
+    pool.commit_transfer_ownership(&admin_original, &symbol_short!("Admin"), &admin_new);
+    jump(&setup.env, ADMIN_ACTIONS_DELAY + 1);
+    pool.accept_ownership(&admin_new, &symbol_short!("Admin"));
+    assert_eq!(
+        setup.env.auths()[0],
+        (
+            admin_new.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    pool.address.clone(),
+                    Symbol::new(&setup.env, "accept_ownership"),
+                    (admin_new.clone(), symbol_short!("Admin")).into_val(&setup.env),
+                )),
+                sub_invocations: std::vec![],
+            }
+        )
+    );
+
+    // new admin is capable to call protected methods, original one is not
+    assert!(pool
+        .try_commit_transfer_ownership(&admin_original, &symbol_short!("Admin"), &admin_original)
+        .is_err());
+    pool.commit_transfer_ownership(&admin_new, &symbol_short!("Admin"), &admin_original);
+}
+
 // test emergency admin transfer ownership
 #[test]
 #[should_panic(expected = "Error(Contract, #2908)")]
@@ -396,6 +469,182 @@ fn test_kill_swap() {
     }
 }
 
+#[test]
+fn test_renounce_pause_admin() {
+    let setup = Setup::default();
+    let liq_pool = setup.liq_pool;
+    let role_name = Symbol::new(&setup.env, "PauseAdmin");
+
+    liq_pool.renounce_role(&setup.pause_admin, &role_name);
+    assert_eq!(
+        setup.env.auths()[0],
+        (
+            setup.pause_admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    liq_pool.address.clone(),
+                    Symbol::new(&setup.env, "renounce_role"),
+                    (setup.pause_admin.clone(), role_name.clone()).into_val(&setup.env),
+                )),
+                sub_invocations: std::vec![],
+            }
+        )
+    );
+
+    assert!(liq_pool.try_kill_swap(&setup.pause_admin).is_err());
+    assert_eq!(
+        liq_pool.get_privileged_addrs().get(role_name).unwrap(),
+        Vec::new(&setup.env)
+    );
+}
+
+#[test]
+fn test_renounce_emergency_pause_admin() {
+    let setup = Setup::default();
+    let liq_pool = setup.liq_pool;
+    let role_name = Symbol::new(&setup.env, "EmergencyPauseAdmin");
+    let emergency_pause_admin_2 = Address::generate(&setup.env);
+
+    liq_pool.set_privileged_addrs(
+        &setup.admin,
+        &setup.rewards_admin,
+        &setup.operations_admin,
+        &setup.pause_admin,
+        &Vec::from_array(
+            &setup.env,
+            [
+                setup.emergency_pause_admin.clone(),
+                emergency_pause_admin_2.clone(),
+            ],
+        ),
+    );
+    liq_pool.renounce_role(&setup.emergency_pause_admin, &role_name);
+
+    assert!(liq_pool
+        .try_kill_swap(&setup.emergency_pause_admin)
+        .is_err());
+    assert!(liq_pool.try_kill_swap(&emergency_pause_admin_2).is_ok());
+    assert_eq!(
+        liq_pool.get_privileged_addrs().get(role_name).unwrap(),
+        Vec::from_array(&setup.env, [emergency_pause_admin_2])
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_renounce_role_not_holder() {
+    let setup = Setup::default();
+    let liq_pool = setup.liq_pool;
+
+    liq_pool.renounce_role(&setup.rewards_admin, &Symbol::new(&setup.env, "PauseAdmin"));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #104)")]
+fn test_renounce_admin() {
+    let setup = Setup::default();
+    let liq_pool = setup.liq_pool;
+
+    liq_pool.renounce_role(&setup.admin, &symbol_short!("Admin"));
+}
+
+#[test]
+fn test_emergency_pause_admin_expiry() {
+    let setup = Setup::default();
+    let liq_pool = setup.liq_pool;
+    let role_name = Symbol::new(&setup.env, "EmergencyPauseAdmin");
+    let expires_at = setup.env.ledger().timestamp() + ADMIN_ACTIONS_DELAY + 3600;
+
+    for (addr, is_ok) in [
+        (setup.rewards_admin.clone(), false),
+        (setup.operations_admin.clone(), false),
+        (setup.pause_admin.clone(), false),
+        (setup.emergency_pause_admin.clone(), false),
+        (setup.admin.clone(), true),
+    ] {
+        assert_eq!(
+            liq_pool
+                .try_set_role_expiry(&addr, &role_name, &setup.emergency_pause_admin, &expires_at)
+                .is_ok(),
+            is_ok
+        );
+    }
+    assert_eq!(
+        liq_pool.get_role_expiry(&role_name, &setup.emergency_pause_admin),
+        expires_at
+    );
+
+    jump(&setup.env, ADMIN_ACTIONS_DELAY + 3599);
+    liq_pool.kill_swap(&setup.emergency_pause_admin);
+    jump(&setup.env, 1);
+    assert!(liq_pool
+        .try_kill_swap(&setup.emergency_pause_admin)
+        .is_err());
+
+    // expired grant can be extended by admin
+    liq_pool.set_role_expiry(&setup.admin, &role_name, &setup.emergency_pause_admin, &0);
+    liq_pool.kill_swap(&setup.emergency_pause_admin);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #116)")]
+fn test_role_expiry_before_transfer_delay() {
+    let setup = Setup::default();
+    let liq_pool = setup.liq_pool;
+
+    // grant can't be revoked faster than the role could be transferred
+    liq_pool.set_role_expiry(
+        &setup.admin,
+        &Symbol::new(&setup.env, "EmergencyPauseAdmin"),
+        &setup.emergency_pause_admin,
+        &(setup.env.ledger().timestamp() + ADMIN_ACTIONS_DELAY - 1),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #104)")]
+fn test_emergency_admin_expiry() {
+    let setup = Setup::default();
+    let liq_pool = setup.liq_pool;
+
+    liq_pool.set_role_expiry(
+        &setup.admin,
+        &Symbol::new(&setup.env, "EmergencyAdmin"),
+        &setup.emergency_admin,
+        &(setup.env.ledger().timestamp() + ADMIN_ACTIONS_DELAY + 3600),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #104)")]
+fn test_delayed_role_expiry() {
+    let setup = Setup::default();
+    let liq_pool = setup.liq_pool;
+    let role_name = Symbol::new(&setup.env, "PauseAdmin");
+
+    liq_pool.enable_transfer_delay(&setup.admin, &role_name);
+    liq_pool.set_role_expiry(
+        &setup.admin,
+        &role_name,
+        &setup.pause_admin,
+        &(setup.env.ledger().timestamp() + ADMIN_ACTIONS_DELAY + 3600),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #104)")]
+fn test_admin_expiry() {
+    let setup = Setup::default();
+    let liq_pool = setup.liq_pool;
+
+    liq_pool.set_role_expiry(
+        &setup.admin,
+        &symbol_short!("Admin"),
+        &setup.admin,
+        &(setup.env.ledger().timestamp() + 3600),
+    );
+}
+
 #[test]
 fn test_kill_claim() {
     let setup = Setup::default();
@@ -541,3 +790,72 @@ fn test_set_rewards_config() {
         jump(&setup.env, 10);
     }
 }
+
+#[test]
+fn test_protocol_fee_config() {
+    let setup = Setup::default();
+    let pool = setup.liq_pool;
+    let user = Address::generate(&setup.env);
+
+    for (addr, is_ok) in [
+        (user, false),
+        (setup.admin, true),
+        (setup.router, true),
+        (setup.rewards_admin, false),
+        (setup.operations_admin, false),
+        (setup.pause_admin, false),
+        (setup.emergency_pause_admin, false),
+    ] {
+        assert_eq!(pool.try_commit_protocol_fee(&addr, &100).is_ok(), is_ok);
+        assert_eq!(pool.try_revert_protocol_fee(&addr).is_ok(), is_ok);
+        assert_eq!(
+            pool.try_set_fees_collector(&addr, &Address::generate(&setup.env))
+                .is_ok(),
+            is_ok
+        );
+    }
+}
+
+#[test]
+fn test_update_fee() {
+    let setup = Setup::default();
+    let pool = setup.liq_pool;
+    let user = Address::generate(&setup.env);
+
+    for (addr, is_ok) in [
+        (user, false),
+        (setup.admin, false),
+        (setup.router, true),
+        (setup.rewards_admin, false),
+        (setup.operations_admin, false),
+        (setup.pause_admin, false),
+        (setup.emergency_pause_admin, false),
+    ] {
+        assert_eq!(pool.try_revert_new_parameters(&addr).is_ok(), is_ok);
+        assert_eq!(pool.try_commit_new_fee(&addr, &100).is_ok(), is_ok);
+        jump(&setup.env, ADMIN_ACTIONS_DELAY + 1);
+        assert_eq!(pool.try_apply_new_fee(&addr).is_ok(), is_ok);
+    }
+}
+
+#[test]
+fn test_skim() {
+    let setup = Setup::default();
+    let pool = setup.liq_pool;
+    let user = Address::generate(&setup.env);
+
+    for (addr, is_ok) in [
+        (user, false),
+        (setup.admin, true),
+        (setup.router, false),
+        (setup.rewards_admin, false),
+        (setup.operations_admin, false),
+        (setup.pause_admin, false),
+        (setup.emergency_pause_admin, false),
+    ] {
+        assert_eq!(
+            pool.try_skim(&addr, &Address::generate(&setup.env)).is_ok(),
+            is_ok
+        );
+    }
+}
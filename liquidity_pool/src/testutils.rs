@@ -6,7 +6,10 @@ use access_control::constants::ADMIN_ACTIONS_DELAY;
 use soroban_sdk::token::{
     StellarAssetClient as SorobanTokenAdminClient, TokenClient as SorobanTokenClient,
 };
-use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, Symbol, Vec};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, testutils::Address as _, Address, Bytes, BytesN, Env,
+    Symbol, Vec,
+};
 use std::vec;
 use token_share::token_contract::{Client as ShareTokenClient, WASM};
 use utils::test_utils::jump;
@@ -24,7 +27,7 @@ impl Default for TestConfig {
     fn default() -> Self {
         TestConfig {
             users_count: 2,
-            mint_to_user: 1000,
+            mint_to_user: 10000,
             rewards_count: 1_000_000_0000000,
             liq_pool_fee: 30,
             reward_tps: 10_5000000_u128,
@@ -277,6 +280,47 @@ pub fn create_liqpool_contract<'a>(
     liqpool
 }
 
+#[contract]
+pub struct FlashLoanReceiver;
+
+#[contractimpl]
+impl FlashLoanReceiver {
+    pub fn __constructor(e: Env, pool: Address) {
+        e.storage().instance().set(&symbol_short!("pool"), &pool);
+    }
+
+    // returns borrowed amounts with fees. non-empty data makes receiver keep the fees
+    pub fn on_flash_loan(
+        e: Env,
+        tokens: Vec<Address>,
+        amounts: Vec<u128>,
+        fees: Vec<u128>,
+        data: Bytes,
+    ) {
+        let pool: Address = e.storage().instance().get(&symbol_short!("pool")).unwrap();
+        for i in 0..tokens.len() {
+            let mut amount = amounts.get(i).unwrap();
+            if data.is_empty() {
+                amount += fees.get(i).unwrap();
+            }
+            if amount > 0 {
+                SorobanTokenClient::new(&e, &tokens.get(i).unwrap()).transfer(
+                    &e.current_contract_address(),
+                    &pool,
+                    &(amount as i128),
+                );
+            }
+        }
+    }
+}
+
+pub(crate) fn create_flash_loan_receiver<'a>(
+    e: &Env,
+    pool: &Address,
+) -> FlashLoanReceiverClient<'a> {
+    FlashLoanReceiverClient::new(e, &e.register(FlashLoanReceiver {}, (pool,)))
+}
+
 pub fn install_token_wasm(e: &Env) -> BytesN<32> {
     e.deployer().upload_contract_wasm(WASM)
 }
@@ -285,7 +329,7 @@ pub fn install_token_wasm(e: &Env) -> BytesN<32> {
 fn test() {
     let config = TestConfig {
         users_count: 2,
-        mint_to_user: 1000,
+        mint_to_user: 10000,
         rewards_count: 1_000_000_0000000,
         liq_pool_fee: 30,
         reward_tps: 10_5000000_u128,
@@ -36,6 +36,14 @@ pub trait LiquidityPoolEvents {
         fee_amount: u128,
     );
 
+    fn flash_loan(
+        &self,
+        receiver: Address,
+        tokens: Vec<Address>,
+        amounts: Vec<u128>,
+        fees: Vec<u128>,
+    );
+
     fn kill_deposit(&self);
 
     fn unkill_deposit(&self);
@@ -137,6 +145,43 @@ impl LiquidityPoolEvents for Events {
         );
     }
 
+    fn flash_loan(
+        &self,
+        receiver: Address,
+        tokens: Vec<Address>,
+        amounts: Vec<u128>,
+        fees: Vec<u128>,
+    ) {
+        // topics
+        // [
+        //   "flash_loan": Symbol, // event identifier
+        //   assetA: Address,      // contract addresses identifying asset lent by the pool
+        //   assetB: Address,      // contract addresses identifying asset lent by the pool
+        //   assetC: Address       // contract addresses identifying asset lent by the pool (optional)
+        // ]
+        //
+        // body
+        // [
+        //   receiver: Address, // address of contract that received the loan
+        //   amountA: i128,     // amount of tokens lent for assetA
+        //   feeA: i128,        // flash fee paid for assetA
+        //   amountB: i128,     // amount of tokens lent for assetB
+        //   feeB: i128,        // flash fee paid for assetB
+        //   amountC: i128,     // amount of tokens lent for assetC (optional)
+        //   feeC: i128         // flash fee paid for assetC (optional)
+        // ]
+        let e = self.env();
+        let fn_name = Symbol::new(e, "flash_loan");
+        let mut topics: Vec<Val> = Vec::from_array(e, [fn_name.to_val()]);
+        let mut body: Vec<Val> = Vec::from_array(e, [receiver.into_val(e)]);
+        for i in 0..tokens.len() {
+            topics.push_back(tokens.get(i).unwrap().into_val(e));
+            body.push_back((amounts.get(i).unwrap() as i128).into_val(e));
+            body.push_back((fees.get(i).unwrap() as i128).into_val(e));
+        }
+        e.events().publish(topics, body);
+    }
+
     fn kill_deposit(&self) {
         self.env()
             .events()
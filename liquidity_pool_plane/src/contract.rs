@@ -1,14 +1,25 @@
 use crate::interface::PlaneInterface;
-use crate::storage::{get, update, PoolPlane};
+use crate::storage::{
+    get, get_price_cumulative, update, update_price_cumulative, PoolPlane, PoolPriceCumulative,
+};
 use access_control::access::{AccessControl, AccessControlTrait};
-use access_control::emergency::{get_emergency_mode, set_emergency_mode};
+use access_control::audit::{get_audit_log, get_audit_log_len, AuditRecord};
+use access_control::delays::{apply_delay, commit_delay, get_delay, revert_delay, DelayTarget};
+use access_control::emergency::{
+    get_emergency_mode, get_emergency_mode_expiration, get_emergency_quorum, set_emergency_mode,
+    set_emergency_quorum, vote_emergency_mode,
+};
 use access_control::errors::AccessControlError;
 use access_control::events::Events as AccessControlEvents;
-use access_control::interface::TransferableContract;
+use access_control::interface::{
+    AuditLogContract, ConfigurableDelaysContract, EmergencyQuorumContract, TransferableContract,
+};
 use access_control::management::SingleAddressManagementTrait;
 use access_control::role::{Role, SymbolRepresentation};
 use access_control::transfer::TransferOwnershipTrait;
-use soroban_sdk::{contract, contractimpl, panic_with_error, Address, BytesN, Env, Symbol, Vec};
+use soroban_sdk::{
+    contract, contractimpl, panic_with_error, Address, BytesN, Env, Symbol, Vec, U256,
+};
 use upgrade::events::Events as UpgradeEvents;
 use upgrade::interface::UpgradeableContract;
 use upgrade::{apply_upgrade, commit_upgrade, revert_upgrade};
@@ -70,6 +81,44 @@ impl PlaneInterface for LiquidityPoolPlane {
         }
         result
     }
+
+    // Updates the pool cumulative prices. Any pool can use it to publish its price accumulators.
+    //
+    // # Arguments
+    //
+    // * `pool` - The address of the pool.
+    // * `price_cumulative` - The cumulative prices of the pool.
+    // * `timestamp` - The timestamp of the last accumulators update.
+    fn update_price_cumulative(e: Env, pool: Address, price_cumulative: Vec<U256>, timestamp: u64) {
+        pool.require_auth();
+        update_price_cumulative(
+            &e,
+            pool,
+            &PoolPriceCumulative {
+                price_cumulative,
+                timestamp,
+            },
+        );
+    }
+
+    // Gets cumulative prices for many pools.
+    //
+    // # Arguments
+    //
+    // * `pools` - A vector of addresses representing the pools.
+    //
+    // # Returns
+    //
+    // * A vector of tuples, each containing the cumulative prices of the pool and the timestamp of their last update.
+    fn get_price_cumulative(e: Env, pools: Vec<Address>) -> Vec<(Vec<U256>, u64)> {
+        let mut result = Vec::new(&e);
+        for i in 0..pools.len() {
+            let pool = pools.get(i).unwrap();
+            let data = get_price_cumulative(&e, pool);
+            result.push_back((data.price_cumulative, data.timestamp));
+        }
+        result
+    }
 }
 
 // The `UpgradeableContract` trait provides the interface for upgrading the contract.
@@ -150,6 +199,43 @@ impl UpgradeableContract for LiquidityPoolPlane {
     }
 }
 
+// The `EmergencyQuorumContract` trait provides the interface for emergency mode quorum.
+#[contractimpl]
+impl EmergencyQuorumContract for LiquidityPoolPlane {
+    // Configures the number of emergency admins votes required to enable the emergency mode.
+    // Once quorum is configured, the emergency admin is no longer able to enable the emergency mode alone.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `threshold` - The number of votes required. Zero disables the quorum.
+    fn set_emergency_quorum(e: Env, admin: Address, threshold: u32) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        set_emergency_quorum(&e, threshold);
+    }
+
+    // Returns the emergency mode voters (emergency admin and `EmergencyVoter` role holders) and votes threshold.
+    fn get_emergency_quorum(e: Env) -> (Vec<Address>, u32) {
+        get_emergency_quorum(&e)
+    }
+
+    // Votes for the emergency mode. The emergency mode is enabled once the threshold is reached.
+    //
+    // # Arguments
+    //
+    // * `voter` - The address of the voter.
+    fn vote_emergency_mode(e: Env, voter: Address) {
+        voter.require_auth();
+        vote_emergency_mode(&e, &voter);
+    }
+
+    // Returns the timestamp at which the emergency mode expires. Zero if it's not active.
+    fn get_emergency_mode_expiration(e: Env) -> u64 {
+        get_emergency_mode_expiration(&e)
+    }
+}
+
 // The `TransferableContract` trait provides the interface for transferring ownership of the contract.
 #[contractimpl]
 impl TransferableContract for LiquidityPoolPlane {
@@ -208,6 +294,23 @@ impl TransferableContract for LiquidityPoolPlane {
         AccessControlEvents::new(&e).revert_transfer_ownership(role);
     }
 
+    // Accepts the committed ownership transfer.
+    // Alternative to `apply_transfer_ownership` which has to be signed by the future address,
+    // proving it controls its key before taking over the role.
+    //
+    // # Arguments
+    //
+    // * `new_address` - The future address of the role.
+    // * `role_name` - The name of the role to transfer ownership of.
+    fn accept_ownership(e: Env, new_address: Address, role_name: Symbol) {
+        new_address.require_auth();
+        let access_control = AccessControl::new(&e);
+
+        let role = Role::from_symbol(&e, role_name);
+        access_control.accept_transfer_ownership(&role, &new_address);
+        AccessControlEvents::new(&e).accept_transfer_ownership(role, new_address);
+    }
+
     // Returns the future address for the role.
     // The future address is the address that the ownership of the role will be transferred to.
     // The future address is set using the `commit_transfer_ownership` function.
@@ -230,3 +333,76 @@ impl TransferableContract for LiquidityPoolPlane {
         }
     }
 }
+
+// The `AuditLogContract` trait provides the interface for querying history of privileged actions.
+#[contractimpl]
+impl AuditLogContract for LiquidityPoolPlane {
+    // Returns the total number of privileged actions recorded.
+    // Only the last `AUDIT_LOG_SIZE` records are kept.
+    fn get_audit_log_len(e: Env) -> u32 {
+        get_audit_log_len(&e)
+    }
+
+    // Returns the recorded privileged actions, oldest first.
+    //
+    // # Arguments
+    //
+    // * `start` - The sequence number of the first record.
+    // * `limit` - The maximum number of records to return.
+    fn get_audit_log(e: Env, start: u32, limit: u32) -> Vec<AuditRecord> {
+        get_audit_log(&e, start, limit)
+    }
+}
+
+// The `ConfigurableDelaysContract` trait provides the interface for configuring admin actions delays.
+#[contractimpl]
+impl ConfigurableDelaysContract for LiquidityPoolPlane {
+    // Returns the current delay for the target.
+    //
+    // # Arguments
+    //
+    // * `target` - The delay target: admin actions, upgrade or specific role transfer.
+    fn get_delay(e: Env, target: DelayTarget) -> u64 {
+        get_delay(&e, &target)
+    }
+
+    // Commits the delay change. The change may be applied once the current delay passes.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `target` - The delay target: admin actions, upgrade or specific role transfer.
+    // * `value` - The new delay in seconds.
+    fn commit_delay(e: Env, admin: Address, target: DelayTarget, value: u64) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        commit_delay(&e, &target, value);
+        AccessControlEvents::new(&e).commit_delay(target, value);
+    }
+
+    // Applies the committed delay change.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `target` - The delay target.
+    fn apply_delay(e: Env, admin: Address, target: DelayTarget) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        let value = apply_delay(&e, &target);
+        AccessControlEvents::new(&e).apply_delay(target, value);
+    }
+
+    // Reverts the committed delay change.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `target` - The delay target.
+    fn revert_delay(e: Env, admin: Address, target: DelayTarget) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        revert_delay(&e, &target);
+        AccessControlEvents::new(&e).revert_delay(target);
+    }
+}
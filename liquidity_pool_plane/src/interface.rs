@@ -1,4 +1,4 @@
-use soroban_sdk::{Address, Env, Symbol, Vec};
+use soroban_sdk::{Address, Env, Symbol, Vec, U256};
 
 pub trait PlaneInterface {
     // Initializes the admin user.
@@ -9,4 +9,10 @@ pub trait PlaneInterface {
 
     // get details for many pools: type string representation, pool parameters and reserves amount
     fn get(e: Env, pools: Vec<Address>) -> Vec<(Symbol, Vec<u128>, Vec<u128>)>;
+
+    // update pool cumulative prices and the timestamp of their last update
+    fn update_price_cumulative(e: Env, pool: Address, price_cumulative: Vec<U256>, timestamp: u64);
+
+    // get cumulative prices and the timestamp of their last update for many pools
+    fn get_price_cumulative(e: Env, pools: Vec<Address>) -> Vec<(Vec<U256>, u64)>;
 }
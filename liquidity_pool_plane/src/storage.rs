@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec, U256};
 
 const DAY_IN_LEDGERS: u32 = 17280;
 pub const MONTH_IN_LEDGERS: u32 = DAY_IN_LEDGERS * 30;
@@ -11,6 +11,7 @@ pub const PERSISTENT_TTL_THRESHOLD: u32 = MAX_PERSISTENT_TTL - MONTH_IN_LEDGERS;
 #[contracttype]
 enum DataKey {
     PoolData(Address),
+    PoolPriceCumulative(Address),
 }
 
 #[contracttype]
@@ -21,6 +22,13 @@ pub struct PoolPlane {
     pub reserves: Vec<u128>,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolPriceCumulative {
+    pub price_cumulative: Vec<U256>,
+    pub timestamp: u64,
+}
+
 fn bump_persistent(e: &Env, key: &DataKey) {
     e.storage()
         .persistent()
@@ -51,3 +59,23 @@ pub(crate) fn get(e: &Env, contract: Address) -> PoolPlane {
     bump_persistent(e, &key);
     e.storage().persistent().get(&key).unwrap()
 }
+
+pub(crate) fn update_price_cumulative(e: &Env, contract: Address, value: &PoolPriceCumulative) {
+    let key = DataKey::PoolPriceCumulative(contract);
+    e.storage().persistent().set(&key, value);
+    bump_persistent(e, &key);
+}
+
+pub(crate) fn get_price_cumulative(e: &Env, contract: Address) -> PoolPriceCumulative {
+    let key = DataKey::PoolPriceCumulative(contract);
+
+    // return empty accumulators if data not provided
+    if !e.storage().persistent().has(&key) {
+        return PoolPriceCumulative {
+            price_cumulative: Vec::new(e),
+            timestamp: 0,
+        };
+    }
+    bump_persistent(e, &key);
+    e.storage().persistent().get(&key).unwrap()
+}
@@ -5,7 +5,7 @@ use crate::testutils::{install_dummy_wasm, jump, Setup};
 use crate::{contract::LiquidityPoolPlane, LiquidityPoolPlaneClient};
 use access_control::constants::ADMIN_ACTIONS_DELAY;
 use soroban_sdk::testutils::{Address as _, Events};
-use soroban_sdk::{symbol_short, vec, Address, Env, IntoVal, Symbol, Vec};
+use soroban_sdk::{symbol_short, vec, Address, Env, IntoVal, Symbol, Vec, U256};
 
 fn create_plane_contract<'a>(e: &Env) -> LiquidityPoolPlaneClient<'a> {
     let client = LiquidityPoolPlaneClient::new(e, &e.register(LiquidityPoolPlane {}, ()));
@@ -235,3 +235,31 @@ fn test_regular_upgrade() {
 
     assert_eq!(contract.version(), 130)
 }
+
+#[test]
+fn test_price_cumulative() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.cost_estimate().budget().reset_unlimited();
+
+    let address1 = Address::generate(&e);
+    let address2 = Address::generate(&e);
+
+    let plane = create_plane_contract(&e);
+    plane.init_admin(&Address::generate(&e));
+    plane.update_price_cumulative(
+        &address1,
+        &Vec::from_array(&e, [U256::from_u32(&e, 100), U256::from_u32(&e, 200)]),
+        &1000,
+    );
+    let data = plane.get_price_cumulative(&Vec::from_array(&e, [address1, address2]));
+
+    assert_eq!(
+        data.get(0).unwrap(),
+        (
+            Vec::from_array(&e, [U256::from_u32(&e, 100), U256::from_u32(&e, 200)]),
+            1000
+        )
+    );
+    assert_eq!(data.get(1).unwrap(), (Vec::new(&e), 0));
+}
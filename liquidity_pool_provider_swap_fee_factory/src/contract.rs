@@ -4,10 +4,17 @@ use crate::storage::{
     set_fee_contract_wasm, set_router,
 };
 use access_control::access::{AccessControl, AccessControlTrait};
-use access_control::emergency::{get_emergency_mode, set_emergency_mode};
+use access_control::audit::{get_audit_log, get_audit_log_len, AuditRecord};
+use access_control::delays::{apply_delay, commit_delay, get_delay, revert_delay, DelayTarget};
+use access_control::emergency::{
+    get_emergency_mode, get_emergency_mode_expiration, get_emergency_quorum, set_emergency_mode,
+    set_emergency_quorum, vote_emergency_mode,
+};
 use access_control::errors::AccessControlError;
 use access_control::events::Events as AccessControlEvents;
-use access_control::interface::TransferableContract;
+use access_control::interface::{
+    AuditLogContract, ConfigurableDelaysContract, EmergencyQuorumContract, TransferableContract,
+};
 use access_control::management::SingleAddressManagementTrait;
 use access_control::role::{Role, SymbolRepresentation};
 use access_control::transfer::TransferOwnershipTrait;
@@ -191,6 +198,57 @@ impl UpgradeableContract for ProviderSwapFeeFactory {
     }
 }
 
+#[contractimpl]
+impl EmergencyQuorumContract for ProviderSwapFeeFactory {
+    // set_emergency_quorum
+    // Configures the number of emergency admins votes required to enable emergency mode.
+    //
+    // Arguments:
+    //   - e: The Soroban environment.
+    //   - admin: The admin address (must be authorized).
+    //   - threshold: Number of votes required. Zero disables the quorum.
+    fn set_emergency_quorum(e: Env, admin: Address, threshold: u32) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        set_emergency_quorum(&e, threshold);
+    }
+
+    // get_emergency_quorum
+    // Returns the emergency mode voters (emergency admin and `EmergencyVoter` role holders) and votes threshold.
+    //
+    // Arguments:
+    //   - e: The Soroban environment.
+    //
+    // Returns:
+    //   - A tuple of voters and threshold.
+    fn get_emergency_quorum(e: Env) -> (Vec<Address>, u32) {
+        get_emergency_quorum(&e)
+    }
+
+    // vote_emergency_mode
+    // Votes for emergency mode. Emergency mode is enabled once the threshold is reached.
+    //
+    // Arguments:
+    //   - e: The Soroban environment.
+    //   - voter: The voter address (must be authorized).
+    fn vote_emergency_mode(e: Env, voter: Address) {
+        voter.require_auth();
+        vote_emergency_mode(&e, &voter);
+    }
+
+    // get_emergency_mode_expiration
+    // Returns the timestamp at which emergency mode expires.
+    //
+    // Arguments:
+    //   - e: The Soroban environment.
+    //
+    // Returns:
+    //   - The expiration timestamp. Zero if emergency mode is not active.
+    fn get_emergency_mode_expiration(e: Env) -> u64 {
+        get_emergency_mode_expiration(&e)
+    }
+}
+
 #[contractimpl]
 impl TransferableContract for ProviderSwapFeeFactory {
     // commit_transfer_ownership
@@ -245,6 +303,22 @@ impl TransferableContract for ProviderSwapFeeFactory {
         AccessControlEvents::new(&e).revert_transfer_ownership(role);
     }
 
+    // accept_ownership
+    // Completes a pending ownership transfer on behalf of the future address.
+    //
+    // Arguments:
+    //   - e: The Soroban environment.
+    //   - new_address: The future role address (must be authorized).
+    //   - role_name: The symbol representing the role.
+    fn accept_ownership(e: Env, new_address: Address, role_name: Symbol) {
+        new_address.require_auth();
+        let access_control = AccessControl::new(&e);
+
+        let role = Role::from_symbol(&e, role_name);
+        access_control.accept_transfer_ownership(&role, &new_address);
+        AccessControlEvents::new(&e).accept_transfer_ownership(role, new_address);
+    }
+
     // get_future_address
     // Returns the pending future address for a role if an ownership transfer is committed;
     // otherwise, returns the current role address.
@@ -267,3 +341,91 @@ impl TransferableContract for ProviderSwapFeeFactory {
         }
     }
 }
+
+#[contractimpl]
+impl AuditLogContract for ProviderSwapFeeFactory {
+    // get_audit_log_len
+    // Returns the total number of privileged actions recorded.
+    //
+    // Arguments:
+    //   - e: The Soroban environment.
+    //
+    // Returns:
+    //   - The number of records. Only the last `AUDIT_LOG_SIZE` of them are kept.
+    fn get_audit_log_len(e: Env) -> u32 {
+        get_audit_log_len(&e)
+    }
+
+    // get_audit_log
+    // Returns the recorded privileged actions, oldest first.
+    //
+    // Arguments:
+    //   - e: The Soroban environment.
+    //   - start: The sequence number of the first record.
+    //   - limit: The maximum number of records to return.
+    //
+    // Returns:
+    //   - A vector of audit records.
+    fn get_audit_log(e: Env, start: u32, limit: u32) -> Vec<AuditRecord> {
+        get_audit_log(&e, start, limit)
+    }
+}
+
+#[contractimpl]
+impl ConfigurableDelaysContract for ProviderSwapFeeFactory {
+    // get_delay
+    // Returns the current delay for the target.
+    //
+    // Arguments:
+    //   - e: The Soroban environment.
+    //   - target: The delay target: admin actions, upgrade or specific role transfer.
+    //
+    // Returns:
+    //   - The delay in seconds.
+    fn get_delay(e: Env, target: DelayTarget) -> u64 {
+        get_delay(&e, &target)
+    }
+
+    // commit_delay
+    // Commits the delay change. The change may be applied once the current delay passes.
+    //
+    // Arguments:
+    //   - e: The Soroban environment.
+    //   - admin: The admin address (must be authorized).
+    //   - target: The delay target.
+    //   - value: The new delay in seconds.
+    fn commit_delay(e: Env, admin: Address, target: DelayTarget, value: u64) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        commit_delay(&e, &target, value);
+        AccessControlEvents::new(&e).commit_delay(target, value);
+    }
+
+    // apply_delay
+    // Applies the committed delay change.
+    //
+    // Arguments:
+    //   - e: The Soroban environment.
+    //   - admin: The admin address (must be authorized).
+    //   - target: The delay target.
+    fn apply_delay(e: Env, admin: Address, target: DelayTarget) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        let value = apply_delay(&e, &target);
+        AccessControlEvents::new(&e).apply_delay(target, value);
+    }
+
+    // revert_delay
+    // Reverts the committed delay change.
+    //
+    // Arguments:
+    //   - e: The Soroban environment.
+    //   - admin: The admin address (must be authorized).
+    //   - target: The delay target.
+    fn revert_delay(e: Env, admin: Address, target: DelayTarget) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        revert_delay(&e, &target);
+        AccessControlEvents::new(&e).revert_delay(target);
+    }
+}
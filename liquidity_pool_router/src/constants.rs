@@ -3,3 +3,7 @@ pub(crate) const CONSTANT_PRODUCT_FEE_AVAILABLE: [u32; 3] = [10, 30, 100];
 pub(crate) const STABLESWAP_MAX_POOLS: u32 = 3;
 pub(crate) const STABLESWAP_MAX_FEE: u32 = 100; // 1%
 pub(crate) const STABLESWAP_DEFAULT_A: u128 = 750;
+pub(crate) const SWAP_SPLIT_PARTS: u128 = 10; // granularity of the swap split optimization
+pub(crate) const MAX_PATH_HOPS: u32 = 4; // max swaps in the chain searched by `find_best_path`
+pub(crate) const FEE_MULTIPLIER: u128 = 10_000;
+pub(crate) const PRICE_PRECISION: u128 = 1_000_000_000_000_000_000; // 1e18, precision of the quoted prices
@@ -3,32 +3,45 @@ use crate::errors::LiquidityPoolRouterError;
 use crate::events::{Events, LiquidityPoolRouterEvents};
 use crate::liquidity_calculator::LiquidityCalculatorClient;
 use crate::pool_interface::{
-    CombinedSwapInterface, LiquidityPoolInterfaceTrait, PoolPlaneInterface, PoolsManagementTrait,
-    RewardsInterfaceTrait,
+    CombinedSwapInterface, LiquidityPoolInterfaceTrait, MulticallInterface, PoolPlaneInterface,
+    PoolProtocolFeeInterface, PoolsManagementTrait, RewardsInterfaceTrait,
 };
 use crate::pool_utils::{
-    assert_tokens_sorted, deploy_stableswap_pool, deploy_standard_pool, get_stableswap_pool_salt,
-    get_standard_pool_salt, get_tokens_salt, get_total_liquidity, validate_tokens_contracts,
+    assert_deadline, assert_tokens_sorted, deploy_stableswap_pool, deploy_standard_pool,
+    find_best_path, find_best_split, get_stableswap_pool_salt, get_standard_pool_salt,
+    get_swap_indexes, get_tokens_salt, get_total_liquidity, quote_chained,
+    validate_tokens_contracts,
 };
 use crate::rewards::get_rewards_manager;
 use crate::router_interface::AdminInterface;
 use crate::storage::{
     get_init_pool_payment_address, get_init_pool_payment_token,
     get_init_stable_pool_payment_amount, get_init_standard_pool_payment_amount,
-    get_liquidity_calculator, get_pool, get_pool_plane, get_pools_plain, get_reward_tokens,
-    get_reward_tokens_detailed, get_rewards_config, get_tokens_set, get_tokens_set_count, has_pool,
-    remove_pool, set_constant_product_pool_hash, set_init_pool_payment_address,
-    set_init_pool_payment_token, set_init_stable_pool_payment_amount,
-    set_init_standard_pool_payment_amount, set_liquidity_calculator, set_pool_plane,
-    set_reward_tokens, set_reward_tokens_detailed, set_rewards_config, set_stableswap_pool_hash,
-    set_token_hash, GlobalRewardsConfig, LiquidityPoolRewardInfo,
+    get_liquidity_calculator, get_pool, get_pool_plane, get_pool_type, get_pools_plain,
+    get_reward_tokens, get_reward_tokens_detailed, get_rewards_config, get_tokens_set,
+    get_tokens_set_count, has_pool, move_pool, remove_pool, set_constant_product_pool_hash,
+    set_init_pool_payment_address, set_init_pool_payment_token,
+    set_init_stable_pool_payment_amount, set_init_standard_pool_payment_amount,
+    set_liquidity_calculator, set_pool_plane, set_reward_tokens, set_reward_tokens_detailed,
+    set_rewards_config, set_stableswap_pool_hash, set_token_hash, GlobalRewardsConfig,
+    LiquidityPoolRewardInfo, LiquidityPoolType, PoolError, RouterCall, RouterCallResult, SwapQuote,
 };
 use access_control::access::{AccessControl, AccessControlTrait};
-use access_control::emergency::{get_emergency_mode, set_emergency_mode};
+use access_control::audit::{get_audit_log, get_audit_log_len, AuditRecord};
+use access_control::delays::{apply_delay, commit_delay, get_delay, revert_delay, DelayTarget};
+use access_control::emergency::{
+    get_emergency_mode, get_emergency_mode_expiration, get_emergency_quorum, set_emergency_mode,
+    set_emergency_quorum, vote_emergency_mode,
+};
 use access_control::errors::AccessControlError;
 use access_control::events::Events as AccessControlEvents;
-use access_control::interface::TransferableContract;
+use access_control::grant::RoleGrantTrait;
+use access_control::interface::{
+    AuditLogContract, ConfigurableDelaysContract, DelayedRolesContract, EmergencyQuorumContract,
+    RoleGrantsContract, RolesRegistryContract, TransferableContract,
+};
 use access_control::management::{MultipleAddressesManagementTrait, SingleAddressManagementTrait};
+use access_control::registry::RoleRegistryTrait;
 use access_control::role::Role;
 use access_control::role::SymbolRepresentation;
 use access_control::transfer::TransferOwnershipTrait;
@@ -175,9 +188,69 @@ impl LiquidityPoolInterfaceTrait for LiquidityPoolRouter {
         min_shares: u128,
     ) -> (Vec<u128>, u128) {
         user.require_auth();
+        Self::_deposit(&e, user, tokens, pool_index, desired_amounts, min_shares)
+    }
+
+    // Deposits tokens into the pool minting the pool tokens to another account.
+    //
+    // # Arguments
+    //
+    // * `e` - The environment.
+    // * `user` - The address of the user depositing the tokens.
+    // * `to` - The address receiving the pool tokens.
+    // * `tokens` - A vector of token addresses.
+    // * `pool_index` - The pool index hash.
+    // * `desired_amounts` - A vector of u128s representing the amounts of each token to deposit.
+    // * `min_shares` - The minimum amount of pool tokens to mint.
+    // * `deadline` - The ledger timestamp after which the request is rejected.
+    //
+    // # Returns
+    //
+    // A tuple containing a vector of u128s representing the amounts of each token deposited and a u128 representing the amount of pool tokens minted.
+    fn deposit_to(
+        e: Env,
+        user: Address,
+        to: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        desired_amounts: Vec<u128>,
+        min_shares: u128,
+        deadline: u64,
+    ) -> (Vec<u128>, u128) {
+        user.require_auth();
+        assert_deadline(&e, deadline);
         assert_tokens_sorted(&e, &tokens);
+        if desired_amounts.len() != tokens.len() {
+            panic_with_error!(&e, LiquidityPoolRouterError::WrongInputVecSize);
+        }
 
-        let pool_id = get_pool(&e, &tokens, pool_index);
+        let pool_id = get_pool(&e, &tokens, pool_index.clone());
+
+        // deposit on behalf of the router, so the pool tokens can be passed to the recipient
+        let mut auth_entries = Vec::new(&e);
+        for i in 0..tokens.len() {
+            let token = tokens.get(i).unwrap();
+            let amount = desired_amounts.get(i).unwrap();
+            SorobanTokenClient::new(&e, &token).transfer(
+                &user,
+                &e.current_contract_address(),
+                &(amount as i128),
+            );
+            auth_entries.push_back(InvokerContractAuthEntry::Contract(SubContractInvocation {
+                context: ContractContext {
+                    contract: token,
+                    fn_name: Symbol::new(&e, "transfer"),
+                    args: (
+                        e.current_contract_address(),
+                        pool_id.clone(),
+                        amount as i128,
+                    )
+                        .into_val(&e),
+                },
+                sub_invocations: vec![&e],
+            }));
+        }
+        e.authorize_as_current_contract(auth_entries);
 
         let (amounts, share_amount): (Vec<u128>, u128) = e.invoke_contract(
             &pool_id,
@@ -185,13 +258,33 @@ impl LiquidityPoolInterfaceTrait for LiquidityPoolRouter {
             Vec::from_array(
                 &e,
                 [
-                    user.clone().into_val(&e),
-                    desired_amounts.into_val(&e),
+                    e.current_contract_address().into_val(&e),
+                    desired_amounts.clone().into_val(&e),
                     min_shares.into_val(&e),
                 ],
             ),
         );
-        Events::new(&e).deposit(tokens, user, pool_id, amounts.clone(), share_amount);
+
+        // return back the unused amounts
+        for i in 0..tokens.len() {
+            let unused = desired_amounts.get(i).unwrap() - amounts.get(i).unwrap();
+            if unused > 0 {
+                SorobanTokenClient::new(&e, &tokens.get(i).unwrap()).transfer(
+                    &e.current_contract_address(),
+                    &user,
+                    &(unused as i128),
+                );
+            }
+        }
+
+        let share_token = Self::share_id(e.clone(), tokens.clone(), pool_index);
+        SorobanTokenClient::new(&e, &share_token).transfer(
+            &e.current_contract_address(),
+            &to,
+            &(share_amount as i128),
+        );
+
+        Events::new(&e).deposit_to(tokens, user, to, pool_id, amounts.clone(), share_amount);
         (amounts, share_amount)
     }
 
@@ -222,34 +315,48 @@ impl LiquidityPoolInterfaceTrait for LiquidityPoolRouter {
         out_min: u128,
     ) -> u128 {
         user.require_auth();
-        assert_tokens_sorted(&e, &tokens);
-        let pool_id = get_pool(&e, &tokens, pool_index);
+        Self::_swap(
+            &e, user, tokens, token_in, token_out, pool_index, in_amount, out_min,
+        )
+    }
 
-        let out_amt = e.invoke_contract(
-            &pool_id,
-            &symbol_short!("swap"),
-            Vec::from_array(
-                &e,
-                [
-                    user.clone().into_val(&e),
-                    tokens
-                        .first_index_of(token_in.clone())
-                        .unwrap()
-                        .into_val(&e),
-                    tokens
-                        .first_index_of(token_out.clone())
-                        .unwrap()
-                        .into_val(&e),
-                    in_amount.into_val(&e),
-                    out_min.into_val(&e),
-                ],
-            ),
-        );
+    // Swaps tokens in the pool sending the output token to another account.
+    //
+    // # Arguments
+    //
+    // * `e` - The environment.
+    // * `user` - The address of the user swapping the tokens.
+    // * `to` - The address receiving the output token.
+    // * `tokens` - A vector of token addresses.
+    // * `token_in` - The address of the input token to be swapped.
+    // * `token_out` - The address of the output token to be received.
+    // * `pool_index` - The pool index hash.
+    // * `in_amount` - The amount of the input token to be swapped.
+    // * `out_min` - The minimum amount of the output token to be received.
+    // * `deadline` - The ledger timestamp after which the request is rejected.
+    //
+    // # Returns
+    //
+    // The amount of the output token received.
+    fn swap_to(
+        e: Env,
+        user: Address,
+        to: Address,
+        tokens: Vec<Address>,
+        token_in: Address,
+        token_out: Address,
+        pool_index: BytesN<32>,
+        in_amount: u128,
+        out_min: u128,
+        deadline: u64,
+    ) -> u128 {
+        user.require_auth();
+        assert_deadline(&e, deadline);
+        assert_tokens_sorted(&e, &tokens);
 
-        Events::new(&e).swap(
-            tokens, user, pool_id, token_in, token_out, in_amount, out_amt,
-        );
-        out_amt
+        // single swap is a chain of one hop
+        let swaps_chain = vec![&e, (tokens, pool_index, token_out)];
+        Self::_swap_chained(e, user, to, swaps_chain, token_in, in_amount, out_min)
     }
 
     // Estimates the result of a swap operation.
@@ -320,9 +427,48 @@ impl LiquidityPoolInterfaceTrait for LiquidityPoolRouter {
         min_amounts: Vec<u128>,
     ) -> Vec<u128> {
         user.require_auth();
+        Self::_withdraw(&e, user, tokens, pool_index, share_amount, min_amounts)
+    }
+
+    // Withdraws tokens from the pool sending them to another account.
+    //
+    // # Arguments
+    //
+    // * `e` - The environment.
+    // * `user` - The address of the user withdrawing the tokens.
+    // * `to` - The address receiving the withdrawn tokens.
+    // * `tokens` - A vector of token addresses.
+    // * `pool_index` - The pool index hash.
+    // * `share_amount` - The amount of pool tokens to burn.
+    // * `min_amounts` - A vector of u128s representing the minimum amounts of each token to be received.
+    // * `deadline` - The ledger timestamp after which the request is rejected.
+    //
+    // # Returns
+    //
+    // A vector of u128s representing the amounts of each token withdrawn.
+    fn withdraw_to(
+        e: Env,
+        user: Address,
+        to: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        share_amount: u128,
+        min_amounts: Vec<u128>,
+        deadline: u64,
+    ) -> Vec<u128> {
+        user.require_auth();
+        assert_deadline(&e, deadline);
         assert_tokens_sorted(&e, &tokens);
 
-        let pool_id = get_pool(&e, &tokens, pool_index);
+        let pool_id = get_pool(&e, &tokens, pool_index.clone());
+
+        // withdraw on behalf of the router, so the tokens can be passed to the recipient
+        let share_token = Self::share_id(e.clone(), tokens.clone(), pool_index);
+        SorobanTokenClient::new(&e, &share_token).transfer(
+            &user,
+            &e.current_contract_address(),
+            &(share_amount as i128),
+        );
 
         let amounts: Vec<u128> = e.invoke_contract(
             &pool_id,
@@ -330,13 +476,107 @@ impl LiquidityPoolInterfaceTrait for LiquidityPoolRouter {
             Vec::from_array(
                 &e,
                 [
-                    user.clone().into_val(&e),
+                    e.current_contract_address().into_val(&e),
                     share_amount.into_val(&e),
                     min_amounts.into_val(&e),
                 ],
             ),
         );
 
+        for i in 0..tokens.len() {
+            let amount = amounts.get(i).unwrap();
+            if amount > 0 {
+                SorobanTokenClient::new(&e, &tokens.get(i).unwrap()).transfer(
+                    &e.current_contract_address(),
+                    &to,
+                    &(amount as i128),
+                );
+            }
+        }
+
+        Events::new(&e).withdraw(tokens, user, pool_id, amounts.clone(), share_amount);
+        amounts
+    }
+
+    // Calculates the amount of token received when withdrawing a single token from the pool.
+    //
+    // # Arguments
+    //
+    // * `e` - The environment.
+    // * `tokens` - A vector of token addresses.
+    // * `pool_index` - The pool index hash.
+    // * `share_amount` - The amount of pool tokens to burn.
+    // * `token_out` - The address of the token to be received.
+    //
+    // # Returns
+    //
+    // The estimated amount of the token that would be received.
+    fn calc_withdraw_one_coin(
+        e: Env,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        share_amount: u128,
+        token_out: Address,
+    ) -> u128 {
+        assert_tokens_sorted(&e, &tokens);
+        let pool_id = get_pool(&e, &tokens, pool_index);
+
+        e.invoke_contract(
+            &pool_id,
+            &Symbol::new(&e, "calc_withdraw_one_coin"),
+            Vec::from_array(
+                &e,
+                [
+                    share_amount.into_val(&e),
+                    tokens.first_index_of(token_out).unwrap().into_val(&e),
+                ],
+            ),
+        )
+    }
+
+    // Withdraws a single token from the pool.
+    //
+    // # Arguments
+    //
+    // * `e` - The environment.
+    // * `user` - The address of the user withdrawing the token.
+    // * `tokens` - A vector of token addresses.
+    // * `pool_index` - The pool index hash.
+    // * `share_amount` - The amount of pool tokens to burn.
+    // * `token_out` - The address of the token to be received.
+    // * `min_amount` - The minimum amount of the token to be received.
+    //
+    // # Returns
+    //
+    // A vector of u128s representing the amounts of each token withdrawn.
+    fn withdraw_one_coin(
+        e: Env,
+        user: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        share_amount: u128,
+        token_out: Address,
+        min_amount: u128,
+    ) -> Vec<u128> {
+        user.require_auth();
+        assert_tokens_sorted(&e, &tokens);
+
+        let pool_id = get_pool(&e, &tokens, pool_index);
+
+        let amounts: Vec<u128> = e.invoke_contract(
+            &pool_id,
+            &Symbol::new(&e, "withdraw_one_coin"),
+            Vec::from_array(
+                &e,
+                [
+                    user.clone().into_val(&e),
+                    share_amount.into_val(&e),
+                    tokens.first_index_of(token_out).unwrap().into_val(&e),
+                    min_amount.into_val(&e),
+                ],
+            ),
+        );
+
         Events::new(&e).withdraw(tokens, user, pool_id, amounts.clone(), share_amount);
         amounts
     }
@@ -472,6 +712,43 @@ impl UpgradeableContract for LiquidityPoolRouter {
     }
 }
 
+// The `EmergencyQuorumContract` trait provides the interface for emergency mode quorum.
+#[contractimpl]
+impl EmergencyQuorumContract for LiquidityPoolRouter {
+    // Configures the number of emergency admins votes required to enable the emergency mode.
+    // Once quorum is configured, the emergency admin is no longer able to enable the emergency mode alone.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `threshold` - The number of votes required. Zero disables the quorum.
+    fn set_emergency_quorum(e: Env, admin: Address, threshold: u32) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        set_emergency_quorum(&e, threshold);
+    }
+
+    // Returns the emergency mode voters (emergency admin and `EmergencyVoter` role holders) and votes threshold.
+    fn get_emergency_quorum(e: Env) -> (Vec<Address>, u32) {
+        get_emergency_quorum(&e)
+    }
+
+    // Votes for the emergency mode. The emergency mode is enabled once the threshold is reached.
+    //
+    // # Arguments
+    //
+    // * `voter` - The address of the voter.
+    fn vote_emergency_mode(e: Env, voter: Address) {
+        voter.require_auth();
+        vote_emergency_mode(&e, &voter);
+    }
+
+    // Returns the timestamp at which the emergency mode expires. Zero if it's not active.
+    fn get_emergency_mode_expiration(e: Env) -> u64 {
+        get_emergency_mode_expiration(&e)
+    }
+}
+
 // The `AdminInterface` trait provides the interface for administrative actions.
 #[contractimpl]
 impl AdminInterface for LiquidityPoolRouter {
@@ -550,6 +827,11 @@ impl AdminInterface for LiquidityPoolRouter {
             access_control.get_role_addresses(&Role::EmergencyPauseAdmin),
         );
 
+        for role_name in access_control.get_declared_roles() {
+            let role = Role::from_symbol(&e, role_name.clone());
+            result.set(role_name, access_control.get_role_addrs(&role));
+        }
+
         result
     }
 
@@ -1105,25 +1387,7 @@ impl RewardsInterfaceTrait for LiquidityPoolRouter {
     // The amount of tokens rewarded to the user as a u128.
     fn claim(e: Env, user: Address, tokens: Vec<Address>, pool_index: BytesN<32>) -> u128 {
         user.require_auth();
-        assert_tokens_sorted(&e, &tokens);
-
-        let pool_id = get_pool(&e, &tokens, pool_index);
-
-        let amount = e.invoke_contract(
-            &pool_id,
-            &symbol_short!("claim"),
-            Vec::from_array(&e, [user.clone().into_val(&e)]),
-        );
-
-        Events::new(&e).claim(
-            tokens,
-            user,
-            pool_id,
-            get_rewards_manager(&e).storage().get_reward_token(),
-            amount,
-        );
-
-        amount
+        Self::_claim(&e, user, tokens, pool_index)
     }
 }
 
@@ -1271,33 +1535,143 @@ impl PoolsManagementTrait for LiquidityPoolRouter {
         }
     }
 
-    // Returns the number of unique token sets.
-    //
-    // # Returns
-    //
-    // The number of unique token sets.
-    fn get_tokens_sets_count(e: Env) -> u128 {
-        get_tokens_set_count(&e)
-    }
-
-    // Retrieves tokens at a specified index.
-    //
-    // # Arguments
-    //
-    // * `index` - The index of the token set to retrieve.
-    //
-    // # Returns
-    //
-    // A vector of token addresses at the specified index.
-    fn get_tokens(e: Env, index: u128) -> Vec<Address> {
-        get_tokens_set(&e, index)
-    }
-
-    // Retrieves a list of pools in batch based on half-open `[..)` range of tokens indexes.
+    // Commits a new fee for the standard pool.
+    // The fee is applied by the pool after its admin actions delay.
     //
     // # Arguments
     //
-    // * `start` - The start index of the range.
+    // * `admin` - The address of the admin user.
+    // * `tokens` - A vector of token addresses that the pool consists of.
+    // * `pool_index` - The pool index hash.
+    // * `new_fee` - The new fee fraction. Should be one of the available fee tiers.
+    fn commit_pool_fee(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        new_fee: u32,
+    ) {
+        admin.require_auth();
+        require_operations_admin_or_owner(&e, &admin);
+        assert_tokens_sorted(&e, &tokens);
+
+        if !CONSTANT_PRODUCT_FEE_AVAILABLE.contains(&new_fee) {
+            panic_with_error!(&e, LiquidityPoolRouterError::BadFee);
+        }
+
+        let salt = get_tokens_salt(&e, &tokens);
+        if get_pool_type(&e, salt.clone(), pool_index.clone()) != LiquidityPoolType::ConstantProduct
+        {
+            panic_with_error!(&e, LiquidityPoolRouterError::InvalidPoolType);
+        }
+        // pool index is derived from the fee, so the fee tier should be free for the pair
+        if has_pool(&e, salt, get_standard_pool_salt(&e, &new_fee)) {
+            panic_with_error!(&e, PoolError::PoolAlreadyExists);
+        }
+
+        let pool_id = get_pool(&e, &tokens, pool_index);
+        e.invoke_contract::<Val>(
+            &pool_id,
+            &Symbol::new(&e, "commit_new_fee"),
+            Vec::from_array(
+                &e,
+                [e.current_contract_address().to_val(), new_fee.into_val(&e)],
+            ),
+        );
+    }
+
+    // Applies the committed fee for the standard pool and moves the pool
+    // under the index matching its new fee tier.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin user.
+    // * `tokens` - A vector of token addresses that the pool consists of.
+    // * `pool_index` - The pool index hash.
+    //
+    // # Returns
+    //
+    // The new pool index hash.
+    fn apply_pool_fee(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+    ) -> BytesN<32> {
+        admin.require_auth();
+        require_operations_admin_or_owner(&e, &admin);
+        assert_tokens_sorted(&e, &tokens);
+
+        let pool_id = get_pool(&e, &tokens, pool_index.clone());
+        e.invoke_contract::<Val>(
+            &pool_id,
+            &Symbol::new(&e, "apply_new_fee"),
+            Vec::from_array(&e, [e.current_contract_address().to_val()]),
+        );
+        let new_fee: u32 =
+            e.invoke_contract(&pool_id, &Symbol::new(&e, "get_fee_fraction"), Vec::new(&e));
+
+        let new_pool_index = get_standard_pool_salt(&e, &new_fee);
+        if new_pool_index != pool_index {
+            move_pool(
+                &e,
+                get_tokens_salt(&e, &tokens),
+                pool_index.clone(),
+                new_pool_index.clone(),
+            );
+            Events::new(&e).update_pool_index(tokens, pool_id, pool_index, new_pool_index.clone());
+        }
+
+        new_pool_index
+    }
+
+    // Reverts the committed fee for the standard pool.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin user.
+    // * `tokens` - A vector of token addresses that the pool consists of.
+    // * `pool_index` - The pool index hash.
+    fn revert_pool_fee(e: Env, admin: Address, tokens: Vec<Address>, pool_index: BytesN<32>) {
+        admin.require_auth();
+        require_operations_admin_or_owner(&e, &admin);
+        assert_tokens_sorted(&e, &tokens);
+
+        let pool_id = get_pool(&e, &tokens, pool_index);
+        e.invoke_contract::<Val>(
+            &pool_id,
+            &Symbol::new(&e, "revert_new_parameters"),
+            Vec::from_array(&e, [e.current_contract_address().to_val()]),
+        );
+    }
+
+    // Returns the number of unique token sets.
+    //
+    // # Returns
+    //
+    // The number of unique token sets.
+    fn get_tokens_sets_count(e: Env) -> u128 {
+        get_tokens_set_count(&e)
+    }
+
+    // Retrieves tokens at a specified index.
+    //
+    // # Arguments
+    //
+    // * `index` - The index of the token set to retrieve.
+    //
+    // # Returns
+    //
+    // A vector of token addresses at the specified index.
+    fn get_tokens(e: Env, index: u128) -> Vec<Address> {
+        get_tokens_set(&e, index)
+    }
+
+    // Retrieves a list of pools in batch based on half-open `[..)` range of tokens indexes.
+    //
+    // # Arguments
+    //
+    // * `start` - The start index of the range.
     // * `end` - The end index of the range.
     //
     // # Returns
@@ -1319,6 +1693,127 @@ impl PoolsManagementTrait for LiquidityPoolRouter {
     }
 }
 
+// The `PoolProtocolFeeInterface` trait provides the interface for managing pools protocol fees.
+#[contractimpl]
+impl PoolProtocolFeeInterface for LiquidityPoolRouter {
+    // Commits the protocol share of the swap fee for the standard pool.
+    // The pool applies it after its admin actions delay.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin user.
+    // * `tokens` - A vector of token addresses.
+    // * `pool_index` - The pool index hash.
+    // * `new_fraction` - The share of the swap fee taken by the protocol. 1 = 0.01%
+    fn commit_pool_protocol_fee(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        new_fraction: u32,
+    ) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        assert_tokens_sorted(&e, &tokens);
+
+        let pool_id = get_pool(&e, &tokens, pool_index);
+        e.invoke_contract::<Val>(
+            &pool_id,
+            &Symbol::new(&e, "commit_protocol_fee"),
+            Vec::from_array(
+                &e,
+                [
+                    e.current_contract_address().to_val(),
+                    new_fraction.into_val(&e),
+                ],
+            ),
+        );
+    }
+
+    // Applies the committed protocol fee share for the standard pool.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin user.
+    // * `tokens` - A vector of token addresses.
+    // * `pool_index` - The pool index hash.
+    fn apply_pool_protocol_fee(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+    ) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        assert_tokens_sorted(&e, &tokens);
+
+        let pool_id = get_pool(&e, &tokens, pool_index);
+        e.invoke_contract::<Val>(
+            &pool_id,
+            &Symbol::new(&e, "apply_protocol_fee"),
+            Vec::from_array(&e, [e.current_contract_address().to_val()]),
+        );
+    }
+
+    // Reverts the committed protocol fee share for the standard pool.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin user.
+    // * `tokens` - A vector of token addresses.
+    // * `pool_index` - The pool index hash.
+    fn revert_pool_protocol_fee(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+    ) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        assert_tokens_sorted(&e, &tokens);
+
+        let pool_id = get_pool(&e, &tokens, pool_index);
+        e.invoke_contract::<Val>(
+            &pool_id,
+            &Symbol::new(&e, "revert_protocol_fee"),
+            Vec::from_array(&e, [e.current_contract_address().to_val()]),
+        );
+    }
+
+    // Sets the address receiving protocol fees claimed from the standard pool.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin user.
+    // * `tokens` - A vector of token addresses.
+    // * `pool_index` - The pool index hash.
+    // * `fees_collector` - The address of the fees collector.
+    fn set_pool_fees_collector(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        fees_collector: Address,
+    ) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        assert_tokens_sorted(&e, &tokens);
+
+        let pool_id = get_pool(&e, &tokens, pool_index);
+        e.invoke_contract::<Val>(
+            &pool_id,
+            &Symbol::new(&e, "set_fees_collector"),
+            Vec::from_array(
+                &e,
+                [
+                    e.current_contract_address().to_val(),
+                    fees_collector.to_val(),
+                ],
+            ),
+        );
+    }
+}
+
 // The `PoolPlaneInterface` trait provides the interface for interacting with a pool plane.
 #[contractimpl]
 impl PoolPlaneInterface for LiquidityPoolRouter {
@@ -1334,42 +1829,151 @@ impl PoolPlaneInterface for LiquidityPoolRouter {
         admin.require_auth();
         AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
 
-        set_pool_plane(&e, &plane);
-    }
+        set_pool_plane(&e, &plane);
+    }
+
+    // Returns the address of the pool plane.
+    fn get_plane(e: Env) -> Address {
+        get_pool_plane(&e)
+    }
+}
+
+impl LiquidityPoolRouter {
+    // Deposits tokens of `user` into the pool.
+    // Authorization is expected to be checked by the caller.
+    fn _deposit(
+        e: &Env,
+        user: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        desired_amounts: Vec<u128>,
+        min_shares: u128,
+    ) -> (Vec<u128>, u128) {
+        assert_tokens_sorted(e, &tokens);
+
+        let pool_id = get_pool(e, &tokens, pool_index);
+
+        let (amounts, share_amount): (Vec<u128>, u128) = e.invoke_contract(
+            &pool_id,
+            &symbol_short!("deposit"),
+            Vec::from_array(
+                e,
+                [
+                    user.clone().into_val(e),
+                    desired_amounts.into_val(e),
+                    min_shares.into_val(e),
+                ],
+            ),
+        );
+        Events::new(e).deposit(tokens, user, pool_id, amounts.clone(), share_amount);
+        (amounts, share_amount)
+    }
+
+    // Swaps tokens of `user` in the pool.
+    // Authorization is expected to be checked by the caller.
+    fn _swap(
+        e: &Env,
+        user: Address,
+        tokens: Vec<Address>,
+        token_in: Address,
+        token_out: Address,
+        pool_index: BytesN<32>,
+        in_amount: u128,
+        out_min: u128,
+    ) -> u128 {
+        assert_tokens_sorted(e, &tokens);
+        let pool_id = get_pool(e, &tokens, pool_index);
+
+        let out_amt = e.invoke_contract(
+            &pool_id,
+            &symbol_short!("swap"),
+            Vec::from_array(
+                e,
+                [
+                    user.clone().into_val(e),
+                    tokens.first_index_of(token_in.clone()).unwrap().into_val(e),
+                    tokens
+                        .first_index_of(token_out.clone())
+                        .unwrap()
+                        .into_val(e),
+                    in_amount.into_val(e),
+                    out_min.into_val(e),
+                ],
+            ),
+        );
+
+        Events::new(e).swap(
+            tokens, user, pool_id, token_in, token_out, in_amount, out_amt,
+        );
+        out_amt
+    }
+
+    // Withdraws tokens of `user` from the pool.
+    // Authorization is expected to be checked by the caller.
+    fn _withdraw(
+        e: &Env,
+        user: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        share_amount: u128,
+        min_amounts: Vec<u128>,
+    ) -> Vec<u128> {
+        assert_tokens_sorted(e, &tokens);
+
+        let pool_id = get_pool(e, &tokens, pool_index);
+
+        let amounts: Vec<u128> = e.invoke_contract(
+            &pool_id,
+            &symbol_short!("withdraw"),
+            Vec::from_array(
+                e,
+                [
+                    user.clone().into_val(e),
+                    share_amount.into_val(e),
+                    min_amounts.into_val(e),
+                ],
+            ),
+        );
+
+        Events::new(e).withdraw(tokens, user, pool_id, amounts.clone(), share_amount);
+        amounts
+    }
+
+    // Claims rewards of `user` from the pool.
+    // Authorization is expected to be checked by the caller.
+    fn _claim(e: &Env, user: Address, tokens: Vec<Address>, pool_index: BytesN<32>) -> u128 {
+        assert_tokens_sorted(e, &tokens);
+
+        let pool_id = get_pool(e, &tokens, pool_index);
+
+        let amount = e.invoke_contract(
+            &pool_id,
+            &symbol_short!("claim"),
+            Vec::from_array(e, [user.clone().into_val(e)]),
+        );
+
+        Events::new(e).claim(
+            tokens,
+            user,
+            pool_id,
+            get_rewards_manager(e).storage().get_reward_token(),
+            amount,
+        );
 
-    // Returns the address of the pool plane.
-    fn get_plane(e: Env) -> Address {
-        get_pool_plane(&e)
+        amount
     }
-}
 
-#[contractimpl]
-impl CombinedSwapInterface for LiquidityPoolRouter {
-    // Executes a chain of token swaps to exchange an input token for an output token.
-    //
-    // # Arguments
-    //
-    // * `user` - The address of the user executing the swaps.
-    // * `swaps_chain` - The series of swaps to be executed. Each swap is represented by a tuple containing:
-    //   - A vector of token addresses liquidity pool belongs to
-    //   - Pool index hash
-    //   - The token to obtain
-    // * `token_in` - The address of the input token to be swapped.
-    // * `in_amount` - The amount of the input token to be swapped.
-    // * `out_min` - The minimum amount of the output token to be received.
-    //
-    // # Returns
-    //
-    // The amount of the output token received after all swaps have been executed.
-    fn swap_chained(
+    // Executes a chain of swaps paid by `user`, sending the output token to `to`.
+    // Authorization is expected to be checked by the caller.
+    fn _swap_chained(
         e: Env,
         user: Address,
+        to: Address,
         swaps_chain: Vec<(Vec<Address>, BytesN<32>, Address)>,
         token_in: Address,
         in_amount: u128,
         out_min: u128,
     ) -> u128 {
-        user.require_auth();
         let mut last_token_out: Option<Address> = None;
         let mut last_swap_result = 0;
 
@@ -1464,40 +2068,24 @@ impl CombinedSwapInterface for LiquidityPoolRouter {
         };
         SorobanTokenClient::new(&e, &token_out_address).transfer(
             &e.current_contract_address(),
-            &user,
+            &to,
             &(last_swap_result as i128),
         );
 
         last_swap_result
     }
 
-    // Executes a chain of token swaps to exchange an input token for an output token.
-    //
-    // # Arguments
-    //
-    // * `user` - The address of the user executing the swaps.
-    // * `swaps_chain` - The series of swaps to be executed. Each swap is represented by a tuple containing:
-    //   - A vector of token addresses liquidity pool belongs to
-    //   - Pool index hash
-    //   - The token to obtain
-    // * `token_in` - The address of the input token to be swapped.
-    // * `out_amount` - The amount of the output token to be received.
-    // * `in_max` - The max amount of the input token to spend.
-    //
-    // # Returns
-    //
-    // The amount of the input token spent after all swaps have been executed.
-    // Executes a chain of token swaps with strict receive functionality.
-    fn swap_chained_strict_receive(
+    // Executes a chain of swaps with strict output paid by `user`, sending the output token to `to`.
+    // Authorization is expected to be checked by the caller.
+    fn _swap_chained_strict_receive(
         e: Env,
         user: Address,
+        to: Address,
         swaps_chain: Vec<(Vec<Address>, BytesN<32>, Address)>,
         token_in: Address,
-        out_amount: u128, // fixed amount of output token to receive
-        max_in: u128,     // maximum input token amount allowed
+        out_amount: u128,
+        max_in: u128,
     ) -> u128 {
-        user.require_auth();
-
         if swaps_chain.len() == 0 {
             panic_with_error!(&e, LiquidityPoolRouterError::PathIsEmpty);
         }
@@ -1593,17 +2181,294 @@ impl CombinedSwapInterface for LiquidityPoolRouter {
                 required_amounts.get_unchecked(i + 1)
             };
 
-            // Authorize and perform the swap.
+            // Authorize and perform the swap.
+            e.authorize_as_current_contract(vec![
+                &e,
+                InvokerContractAuthEntry::Contract(SubContractInvocation {
+                    context: ContractContext {
+                        contract: token_in_local.clone(),
+                        fn_name: Symbol::new(&e, "transfer"),
+                        args: (
+                            e.current_contract_address(),
+                            pool_id.clone(),
+                            current_in as i128,
+                        )
+                            .into_val(&e),
+                    },
+                    sub_invocations: vec![&e],
+                }),
+            ]);
+
+            let in_local: u128 = e.invoke_contract(
+                &pool_id,
+                &swap_fn,
+                Vec::from_array(
+                    &e,
+                    [
+                        e.current_contract_address().into_val(&e),
+                        tokens
+                            .first_index_of(token_in_local.clone())
+                            .unwrap()
+                            .into_val(&e),
+                        tokens
+                            .first_index_of(token_out.clone())
+                            .unwrap()
+                            .into_val(&e),
+                        out_local.into_val(&e),
+                        current_in.into_val(&e),
+                    ],
+                ),
+            );
+
+            // Emit an event for the swap.
+            Events::new(&e).swap(
+                tokens,
+                user.clone(),
+                pool_id,
+                token_in_local.clone(),
+                token_out.clone(),
+                in_local,
+                out_local,
+            );
+
+            current_in = out_local;
+            last_token_out = Some(token_out);
+        }
+
+        // Finally, transfer the received output tokens to the recipient.
+        let final_token = last_token_out.unwrap();
+        SorobanTokenClient::new(&e, &final_token).transfer(
+            &e.current_contract_address(),
+            &to,
+            &(current_in as i128),
+        );
+
+        total_required_input
+    }
+}
+
+#[contractimpl]
+impl CombinedSwapInterface for LiquidityPoolRouter {
+    // Executes a chain of token swaps to exchange an input token for an output token.
+    //
+    // # Arguments
+    //
+    // * `user` - The address of the user executing the swaps.
+    // * `swaps_chain` - The series of swaps to be executed. Each swap is represented by a tuple containing:
+    //   - A vector of token addresses liquidity pool belongs to
+    //   - Pool index hash
+    //   - The token to obtain
+    // * `token_in` - The address of the input token to be swapped.
+    // * `in_amount` - The amount of the input token to be swapped.
+    // * `out_min` - The minimum amount of the output token to be received.
+    //
+    // # Returns
+    //
+    // The amount of the output token received after all swaps have been executed.
+    fn swap_chained(
+        e: Env,
+        user: Address,
+        swaps_chain: Vec<(Vec<Address>, BytesN<32>, Address)>,
+        token_in: Address,
+        in_amount: u128,
+        out_min: u128,
+    ) -> u128 {
+        user.require_auth();
+        Self::_swap_chained(
+            e,
+            user.clone(),
+            user,
+            swaps_chain,
+            token_in,
+            in_amount,
+            out_min,
+        )
+    }
+
+    // Executes a chain of token swaps sending the output token to another account.
+    //
+    // # Arguments
+    //
+    // * `user` - The address of the user executing the swaps.
+    // * `to` - The address receiving the output token.
+    // * `swaps_chain` - The series of swaps to be executed, same as for `swap_chained`.
+    // * `token_in` - The address of the input token to be swapped.
+    // * `in_amount` - The amount of the input token to be swapped.
+    // * `out_min` - The minimum amount of the output token to be received.
+    // * `deadline` - The ledger timestamp after which the request is rejected.
+    //
+    // # Returns
+    //
+    // The amount of the output token received after all swaps have been executed.
+    fn swap_chained_to(
+        e: Env,
+        user: Address,
+        to: Address,
+        swaps_chain: Vec<(Vec<Address>, BytesN<32>, Address)>,
+        token_in: Address,
+        in_amount: u128,
+        out_min: u128,
+        deadline: u64,
+    ) -> u128 {
+        user.require_auth();
+        assert_deadline(&e, deadline);
+        Self::_swap_chained(e, user, to, swaps_chain, token_in, in_amount, out_min)
+    }
+
+    // Executes a chain of token swaps to exchange an input token for an output token.
+    //
+    // # Arguments
+    //
+    // * `user` - The address of the user executing the swaps.
+    // * `swaps_chain` - The series of swaps to be executed. Each swap is represented by a tuple containing:
+    //   - A vector of token addresses liquidity pool belongs to
+    //   - Pool index hash
+    //   - The token to obtain
+    // * `token_in` - The address of the input token to be swapped.
+    // * `out_amount` - The amount of the output token to be received.
+    // * `in_max` - The max amount of the input token to spend.
+    //
+    // # Returns
+    //
+    // The amount of the input token spent after all swaps have been executed.
+    // Executes a chain of token swaps with strict receive functionality.
+    fn swap_chained_strict_receive(
+        e: Env,
+        user: Address,
+        swaps_chain: Vec<(Vec<Address>, BytesN<32>, Address)>,
+        token_in: Address,
+        out_amount: u128, // fixed amount of output token to receive
+        max_in: u128,     // maximum input token amount allowed
+    ) -> u128 {
+        user.require_auth();
+        Self::_swap_chained_strict_receive(
+            e,
+            user.clone(),
+            user,
+            swaps_chain,
+            token_in,
+            out_amount,
+            max_in,
+        )
+    }
+
+    // Executes a chain of token swaps with strict output sending it to another account.
+    //
+    // # Arguments
+    //
+    // * `user` - The address of the user executing the swaps.
+    // * `to` - The address receiving the output token.
+    // * `swaps_chain` - The series of swaps to be executed, same as for `swap_chained`.
+    // * `token_in` - The address of the input token to be swapped.
+    // * `out_amount` - The amount of the output token to be received.
+    // * `in_max` - The max amount of the input token to spend.
+    // * `deadline` - The ledger timestamp after which the request is rejected.
+    //
+    // # Returns
+    //
+    // The amount of the input token spent after all swaps have been executed.
+    fn swap_chained_strict_receive_to(
+        e: Env,
+        user: Address,
+        to: Address,
+        swaps_chain: Vec<(Vec<Address>, BytesN<32>, Address)>,
+        token_in: Address,
+        out_amount: u128,
+        in_max: u128,
+        deadline: u64,
+    ) -> u128 {
+        user.require_auth();
+        assert_deadline(&e, deadline);
+        Self::_swap_chained_strict_receive(e, user, to, swaps_chain, token_in, out_amount, in_max)
+    }
+
+    // Finds the swaps chain giving the biggest output amount for the given input.
+    // Every registered pool with non-empty reserves is considered as a candidate hop.
+    //
+    // # Arguments
+    //
+    // * `token_in` - The address of the input token to be swapped.
+    // * `token_out` - The address of the token to obtain.
+    // * `in_amount` - The amount of the input token to be swapped.
+    // * `max_hops` - The maximum number of swaps in the chain.
+    //
+    // # Returns
+    //
+    // The series of swaps in the format accepted by `swap_chained`.
+    fn find_best_path(
+        e: Env,
+        token_in: Address,
+        token_out: Address,
+        in_amount: u128,
+        max_hops: u32,
+    ) -> Vec<(Vec<Address>, BytesN<32>, Address)> {
+        match find_best_path(&e, &token_in, &token_out, in_amount, max_hops) {
+            Some((path, _)) => path,
+            None => panic_with_error!(&e, LiquidityPoolRouterError::PathNotFound),
+        }
+    }
+
+    // Swaps an input token for an output token splitting the amount between several pools.
+    //
+    // # Arguments
+    //
+    // * `user` - The address of the user executing the swap.
+    // * `token_in` - The address of the input token to be swapped.
+    // * `token_out` - The address of the token to obtain.
+    // * `legs` - The swaps to be executed. Each swap is represented by a tuple containing:
+    //   - A vector of token addresses liquidity pool belongs to
+    //   - Pool index hash
+    //   - The amount of the input token to be swapped in the pool
+    // * `out_min` - The minimum total amount of the output token to be received.
+    //
+    // # Returns
+    //
+    // The total amount of the output token received.
+    fn swap_split(
+        e: Env,
+        user: Address,
+        token_in: Address,
+        token_out: Address,
+        legs: Vec<(Vec<Address>, BytesN<32>, u128)>,
+        out_min: u128,
+    ) -> u128 {
+        user.require_auth();
+
+        if legs.len() == 0 {
+            panic_with_error!(&e, LiquidityPoolRouterError::PathIsEmpty);
+        }
+
+        // validate all the legs before moving any funds
+        let mut in_amount = 0;
+        let mut swaps = Vec::new(&e);
+        for (tokens, pool_index, leg_amount) in legs.iter() {
+            assert_tokens_sorted(&e, &tokens);
+            if leg_amount == 0 {
+                panic_with_error!(&e, LiquidityPoolRouterError::ZeroAmount);
+            }
+            let (in_idx, out_idx) = get_swap_indexes(&e, &tokens, &token_in, &token_out);
+            let pool_id = get_pool(&e, &tokens, pool_index);
+            in_amount += leg_amount;
+            swaps.push_back((tokens, pool_id, in_idx, out_idx, leg_amount));
+        }
+        SorobanTokenClient::new(&e, &token_in).transfer(
+            &user,
+            &e.current_contract_address(),
+            &(in_amount as i128),
+        );
+
+        let mut out_amount = 0;
+        for (tokens, pool_id, in_idx, out_idx, leg_amount) in swaps.iter() {
             e.authorize_as_current_contract(vec![
                 &e,
                 InvokerContractAuthEntry::Contract(SubContractInvocation {
                     context: ContractContext {
-                        contract: token_in_local.clone(),
+                        contract: token_in.clone(),
                         fn_name: Symbol::new(&e, "transfer"),
                         args: (
                             e.current_contract_address(),
                             pool_id.clone(),
-                            current_in as i128,
+                            leg_amount as i128,
                         )
                             .into_val(&e),
                     },
@@ -1611,51 +2476,164 @@ impl CombinedSwapInterface for LiquidityPoolRouter {
                 }),
             ]);
 
-            let in_local: u128 = e.invoke_contract(
+            let leg_out: u128 = e.invoke_contract(
                 &pool_id,
-                &swap_fn,
+                &symbol_short!("swap"),
                 Vec::from_array(
                     &e,
                     [
                         e.current_contract_address().into_val(&e),
-                        tokens
-                            .first_index_of(token_in_local.clone())
-                            .unwrap()
-                            .into_val(&e),
-                        tokens
-                            .first_index_of(token_out.clone())
-                            .unwrap()
-                            .into_val(&e),
-                        out_local.into_val(&e),
-                        current_in.into_val(&e),
+                        in_idx.into_val(&e),
+                        out_idx.into_val(&e),
+                        leg_amount.into_val(&e),
+                        0_u128.into_val(&e),
                     ],
                 ),
             );
 
-            // Emit an event for the swap.
             Events::new(&e).swap(
                 tokens,
                 user.clone(),
                 pool_id,
-                token_in_local.clone(),
+                token_in.clone(),
                 token_out.clone(),
-                in_local,
-                out_local,
+                leg_amount,
+                leg_out,
             );
 
-            current_in = out_local;
-            last_token_out = Some(token_out);
+            out_amount += leg_out;
         }
 
-        // Finally, transfer the received output tokens to the user.
-        let final_token = last_token_out.unwrap();
-        SorobanTokenClient::new(&e, &final_token).transfer(
+        if out_amount < out_min {
+            panic_with_error!(&e, LiquidityPoolRouterError::OutMinNotSatisfied);
+        }
+
+        SorobanTokenClient::new(&e, &token_out).transfer(
             &e.current_contract_address(),
             &user,
-            &(current_in as i128),
+            &(out_amount as i128),
         );
 
-        total_required_input
+        out_amount
+    }
+
+    // Proposes the split of the input amount between the pools of the tokens set
+    // minimizing the price impact.
+    //
+    // # Arguments
+    //
+    // * `tokens` - A vector of token addresses the pools belong to.
+    // * `token_in` - The address of the input token to be swapped.
+    // * `token_out` - The address of the token to obtain.
+    // * `in_amount` - The amount of the input token to be swapped.
+    //
+    // # Returns
+    //
+    // The swap legs in the format accepted by `swap_split`.
+    fn find_best_split(
+        e: Env,
+        tokens: Vec<Address>,
+        token_in: Address,
+        token_out: Address,
+        in_amount: u128,
+    ) -> Vec<(Vec<Address>, BytesN<32>, u128)> {
+        assert_tokens_sorted(&e, &tokens);
+        find_best_split(&e, &tokens, &token_in, &token_out, in_amount)
+    }
+
+    // Estimates a chain of token swaps with the breakdown for every hop.
+    //
+    // # Arguments
+    //
+    // * `swaps_chain` - The series of swaps to be estimated, same as for `swap_chained`.
+    // * `token_in` - The address of the input token to be swapped.
+    // * `in_amount` - The amount of the input token to be swapped.
+    //
+    // # Returns
+    //
+    // The quote containing pool, amounts, fee and spot prices before and after the swap for every hop,
+    // the final output amount and the aggregate price impact in basis points.
+    fn quote_chained(
+        e: Env,
+        swaps_chain: Vec<(Vec<Address>, BytesN<32>, Address)>,
+        token_in: Address,
+        in_amount: u128,
+    ) -> SwapQuote {
+        quote_chained(&e, &swaps_chain, &token_in, in_amount)
+    }
+}
+
+// The `MulticallInterface` trait provides the interface for batching pool operations in a single call.
+#[contractimpl]
+impl MulticallInterface for LiquidityPoolRouter {
+    // Executes a batch of pool operations on behalf of the user in a single transaction.
+    // Operations are executed in order, any failure reverts the whole batch.
+    //
+    // # Arguments
+    //
+    // * `user` - The address of the user executing the operations.
+    // * `calls` - The operations to be executed: deposit, withdraw, swap, claim or swap_chained.
+    //
+    // # Returns
+    //
+    // The result of every operation in the same order as the calls.
+    fn multicall(e: Env, user: Address, calls: Vec<RouterCall>) -> Vec<RouterCallResult> {
+        user.require_auth();
+
+        let mut results = Vec::new(&e);
+        for call in calls {
+            let result = match call {
+                RouterCall::Deposit(tokens, pool_index, desired_amounts, min_shares) => {
+                    let (amounts, share_amount) = Self::_deposit(
+                        &e,
+                        user.clone(),
+                        tokens,
+                        pool_index,
+                        desired_amounts,
+                        min_shares,
+                    );
+                    RouterCallResult::Deposit(amounts, share_amount)
+                }
+                RouterCall::Withdraw(tokens, pool_index, share_amount, min_amounts) => {
+                    RouterCallResult::Withdraw(Self::_withdraw(
+                        &e,
+                        user.clone(),
+                        tokens,
+                        pool_index,
+                        share_amount,
+                        min_amounts,
+                    ))
+                }
+                RouterCall::Swap(tokens, token_in, token_out, pool_index, in_amount, out_min) => {
+                    RouterCallResult::Swap(Self::_swap(
+                        &e,
+                        user.clone(),
+                        tokens,
+                        token_in,
+                        token_out,
+                        pool_index,
+                        in_amount,
+                        out_min,
+                    ))
+                }
+                RouterCall::Claim(tokens, pool_index) => {
+                    RouterCallResult::Claim(Self::_claim(&e, user.clone(), tokens, pool_index))
+                }
+                RouterCall::SwapChained(swaps_chain, token_in, in_amount, out_min) => {
+                    RouterCallResult::SwapChained(Self::_swap_chained(
+                        e.clone(),
+                        user.clone(),
+                        user.clone(),
+                        swaps_chain,
+                        token_in,
+                        in_amount,
+                        out_min,
+                    ))
+                }
+            };
+            results.push_back(result);
+        }
+        results
     }
 }
 
@@ -1670,6 +2648,9 @@ impl TransferableContract for LiquidityPoolRouter {
     // * `role_name` - The name of the role to transfer ownership of. The role must be one of the following:
     //     * `Admin`
     //     * `EmergencyAdmin`
+    //     * `RewardsAdmin` (if transfer delay is enabled)
+    //     * `OperationsAdmin` (if transfer delay is enabled)
+    //     * `PauseAdmin` (if transfer delay is enabled)
     // * `new_address` - New address for the role
     fn commit_transfer_ownership(e: Env, admin: Address, role_name: Symbol, new_address: Address) {
         admin.require_auth();
@@ -1689,6 +2670,9 @@ impl TransferableContract for LiquidityPoolRouter {
     // * `role_name` - The name of the role to transfer ownership of. The role must be one of the following:
     //     * `Admin`
     //     * `EmergencyAdmin`
+    //     * `RewardsAdmin` (if transfer delay is enabled)
+    //     * `OperationsAdmin` (if transfer delay is enabled)
+    //     * `PauseAdmin` (if transfer delay is enabled)
     fn apply_transfer_ownership(e: Env, admin: Address, role_name: Symbol) {
         admin.require_auth();
         let access_control = AccessControl::new(&e);
@@ -1707,6 +2691,9 @@ impl TransferableContract for LiquidityPoolRouter {
     // * `role_name` - The name of the role to transfer ownership of. The role must be one of the following:
     //     * `Admin`
     //     * `EmergencyAdmin`
+    //     * `RewardsAdmin` (if transfer delay is enabled)
+    //     * `OperationsAdmin` (if transfer delay is enabled)
+    //     * `PauseAdmin` (if transfer delay is enabled)
     fn revert_transfer_ownership(e: Env, admin: Address, role_name: Symbol) {
         admin.require_auth();
         let access_control = AccessControl::new(&e);
@@ -1717,6 +2704,23 @@ impl TransferableContract for LiquidityPoolRouter {
         AccessControlEvents::new(&e).revert_transfer_ownership(role);
     }
 
+    // Accepts the committed ownership transfer.
+    // Alternative to `apply_transfer_ownership` which has to be signed by the future address,
+    // proving it controls its key before taking over the role.
+    //
+    // # Arguments
+    //
+    // * `new_address` - The future address of the role.
+    // * `role_name` - The name of the role to transfer ownership of.
+    fn accept_ownership(e: Env, new_address: Address, role_name: Symbol) {
+        new_address.require_auth();
+        let access_control = AccessControl::new(&e);
+
+        let role = Role::from_symbol(&e, role_name);
+        access_control.accept_transfer_ownership(&role, &new_address);
+        AccessControlEvents::new(&e).accept_transfer_ownership(role, new_address);
+    }
+
     // Returns the future address for the role.
     // The future address is the address that the ownership of the role will be transferred to.
     // The future address is set using the `commit_transfer_ownership` function.
@@ -1727,6 +2731,9 @@ impl TransferableContract for LiquidityPoolRouter {
     // * `role_name` - The name of the role to get the future address for. The role must be one of the following:
     //    * `Admin`
     //    * `EmergencyAdmin`
+    //    * `RewardsAdmin` (if transfer delay is enabled)
+    //    * `OperationsAdmin` (if transfer delay is enabled)
+    //    * `PauseAdmin` (if transfer delay is enabled)
     fn get_future_address(e: Env, role_name: Symbol) -> Address {
         let access_control = AccessControl::new(&e);
         let role = Role::from_symbol(&e, role_name);
@@ -1739,3 +2746,283 @@ impl TransferableContract for LiquidityPoolRouter {
         }
     }
 }
+
+// The `DelayedRolesContract` trait provides the interface for delayed changes of privileged roles.
+#[contractimpl]
+impl DelayedRolesContract for LiquidityPoolRouter {
+    // Enables transfer delay for the privileged role.
+    // Once enabled, the role can only be changed through the commit/apply flow
+    // and the delay cannot be disabled.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `role_name` - The name of the role. The role must be one of the following:
+    //     * `RewardsAdmin`
+    //     * `OperationsAdmin`
+    //     * `PauseAdmin`
+    //     * `EmergencyPauseAdmin`
+    fn enable_transfer_delay(e: Env, admin: Address, role_name: Symbol) {
+        admin.require_auth();
+        let access_control = AccessControl::new(&e);
+        access_control.assert_address_has_role(&admin, &Role::Admin);
+
+        let role = Role::from_symbol(&e, role_name);
+        access_control.enable_transfer_delay(&role);
+        AccessControlEvents::new(&e).enable_transfer_delay(role);
+    }
+
+    // Returns whether the role can be changed only through the commit/apply flow.
+    //
+    // # Arguments
+    //
+    // * `role_name` - The name of the role.
+    fn get_transfer_delay_enabled(e: Env, role_name: Symbol) -> bool {
+        let role = Role::from_symbol(&e, role_name);
+        AccessControl::new(&e).is_transfer_delayed(&role)
+    }
+
+    // Commits a change of the multiple addresses role.
+    // Pending change can be reverted with `revert_transfer_ownership`.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `role_name` - The name of the role. The role must be one of the following:
+    //     * `EmergencyPauseAdmin` (if transfer delay is enabled)
+    // * `new_addresses` - New addresses for the role
+    fn commit_transfer_addresses(
+        e: Env,
+        admin: Address,
+        role_name: Symbol,
+        new_addresses: Vec<Address>,
+    ) {
+        admin.require_auth();
+        let access_control = AccessControl::new(&e);
+        access_control.assert_address_has_role(&admin, &Role::Admin);
+
+        let role = Role::from_symbol(&e, role_name);
+        access_control.commit_transfer_addresses(&role, &new_addresses);
+        AccessControlEvents::new(&e).commit_transfer_addresses(role, new_addresses);
+    }
+
+    // Applies the committed change of the multiple addresses role.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `role_name` - The name of the role. The role must be one of the following:
+    //     * `EmergencyPauseAdmin`
+    fn apply_transfer_addresses(e: Env, admin: Address, role_name: Symbol) {
+        admin.require_auth();
+        let access_control = AccessControl::new(&e);
+        access_control.assert_address_has_role(&admin, &Role::Admin);
+
+        let role = Role::from_symbol(&e, role_name);
+        let new_addresses = access_control.apply_transfer_addresses(&role);
+        AccessControlEvents::new(&e).apply_transfer_addresses(role, new_addresses);
+    }
+
+    // Returns the future addresses for the multiple addresses role.
+    // The addresses will be defaulted to the current ones if the change is not committed.
+    //
+    // # Arguments
+    //
+    // * `role_name` - The name of the role. The role must be one of the following:
+    //     * `EmergencyPauseAdmin`
+    fn get_future_addresses(e: Env, role_name: Symbol) -> Vec<Address> {
+        let access_control = AccessControl::new(&e);
+        let role = Role::from_symbol(&e, role_name);
+        match access_control.get_transfer_ownership_deadline(&role) {
+            0 => access_control.get_role_addresses(&role),
+            _ => access_control.get_future_addresses(&role),
+        }
+    }
+}
+
+// The `RolesRegistryContract` trait provides the interface for managing roles declared at runtime.
+#[contractimpl]
+impl RolesRegistryContract for LiquidityPoolRouter {
+    // Declares a new role. Role flags cannot be changed afterwards.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `role_name` - The name of the role. Must not match any of the built-in roles.
+    // * `has_many_users` - Whether the role is assigned to multiple addresses.
+    // * `is_transfer_delayed` - Whether the role address can be replaced only through
+    //   the commit/apply flow. Not available for multiple addresses roles.
+    fn declare_role(
+        e: Env,
+        admin: Address,
+        role_name: Symbol,
+        has_many_users: bool,
+        is_transfer_delayed: bool,
+    ) {
+        admin.require_auth();
+        let access_control = AccessControl::new(&e);
+        access_control.assert_address_has_role(&admin, &Role::Admin);
+
+        let role = access_control.declare_role(&role_name, has_many_users, is_transfer_delayed);
+        AccessControlEvents::new(&e).declare_role(role, has_many_users, is_transfer_delayed);
+    }
+
+    // Returns the names of the roles declared at runtime.
+    fn get_declared_roles(e: Env) -> Vec<Symbol> {
+        AccessControl::new(&e).get_declared_roles()
+    }
+
+    // Sets the addresses of the role.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `role_name` - The name of the role.
+    // * `addresses` - The addresses of the role. Single address roles expect exactly one address.
+    fn set_role_addrs(e: Env, admin: Address, role_name: Symbol, addresses: Vec<Address>) {
+        admin.require_auth();
+        let access_control = AccessControl::new(&e);
+        access_control.assert_address_has_role(&admin, &Role::Admin);
+
+        let role = Role::from_symbol(&e, role_name);
+        access_control.set_role_addrs(&role, &addresses);
+        AccessControlEvents::new(&e).set_role_addrs(role, addresses);
+    }
+
+    // Returns the addresses of the role.
+    //
+    // # Arguments
+    //
+    // * `role_name` - The name of the role.
+    fn get_role_addrs(e: Env, role_name: Symbol) -> Vec<Address> {
+        let role = Role::from_symbol(&e, role_name);
+        AccessControl::new(&e).get_role_addrs(&role)
+    }
+}
+
+// The `RoleGrantsContract` trait provides the interface for managing role grants lifetime.
+#[contractimpl]
+impl RoleGrantsContract for LiquidityPoolRouter {
+    // Gives up the role. The address won't hold the role anymore.
+    // For multiple addresses roles only the given address is removed.
+    //
+    // # Arguments
+    //
+    // * `address` - The address of the role holder.
+    // * `role_name` - The name of the role. Admin role cannot be renounced.
+    fn renounce_role(e: Env, address: Address, role_name: Symbol) {
+        address.require_auth();
+        let role = Role::from_symbol(&e, role_name);
+        AccessControl::new(&e).renounce_role(&role, &address);
+        AccessControlEvents::new(&e).renounce_role(role, address);
+    }
+
+    // Sets the grant expiration timestamp. Once expired, the address is no longer authorized for the role.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `role_name` - The name of the role. Admin, EmergencyAdmin and delayed roles grants cannot expire.
+    // * `address` - The address of the role holder.
+    // * `expires_at` - The expiration timestamp, not earlier than the role transfer delay from now.
+    //   Zero means grant never expires.
+    fn set_role_expiry(
+        e: Env,
+        admin: Address,
+        role_name: Symbol,
+        address: Address,
+        expires_at: u64,
+    ) {
+        admin.require_auth();
+        let access_control = AccessControl::new(&e);
+        access_control.assert_address_has_role(&admin, &Role::Admin);
+
+        let role = Role::from_symbol(&e, role_name);
+        access_control.set_role_expiry(&role, &address, expires_at);
+        AccessControlEvents::new(&e).set_role_expiry(role, address, expires_at);
+    }
+
+    // Returns the grant expiration timestamp. Zero means grant never expires.
+    //
+    // # Arguments
+    //
+    // * `role_name` - The name of the role.
+    // * `address` - The address of the role holder.
+    fn get_role_expiry(e: Env, role_name: Symbol, address: Address) -> u64 {
+        let role = Role::from_symbol(&e, role_name);
+        AccessControl::new(&e).get_role_expiry(&role, &address)
+    }
+}
+
+// The `AuditLogContract` trait provides the interface for querying history of privileged actions.
+#[contractimpl]
+impl AuditLogContract for LiquidityPoolRouter {
+    // Returns the total number of privileged actions recorded.
+    // Only the last `AUDIT_LOG_SIZE` records are kept.
+    fn get_audit_log_len(e: Env) -> u32 {
+        get_audit_log_len(&e)
+    }
+
+    // Returns the recorded privileged actions, oldest first.
+    //
+    // # Arguments
+    //
+    // * `start` - The sequence number of the first record.
+    // * `limit` - The maximum number of records to return.
+    fn get_audit_log(e: Env, start: u32, limit: u32) -> Vec<AuditRecord> {
+        get_audit_log(&e, start, limit)
+    }
+}
+
+// The `ConfigurableDelaysContract` trait provides the interface for configuring admin actions delays.
+#[contractimpl]
+impl ConfigurableDelaysContract for LiquidityPoolRouter {
+    // Returns the current delay for the target.
+    //
+    // # Arguments
+    //
+    // * `target` - The delay target: admin actions, upgrade or specific role transfer.
+    fn get_delay(e: Env, target: DelayTarget) -> u64 {
+        get_delay(&e, &target)
+    }
+
+    // Commits the delay change. The change may be applied once the current delay passes.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `target` - The delay target: admin actions, upgrade or specific role transfer.
+    // * `value` - The new delay in seconds.
+    fn commit_delay(e: Env, admin: Address, target: DelayTarget, value: u64) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        commit_delay(&e, &target, value);
+        AccessControlEvents::new(&e).commit_delay(target, value);
+    }
+
+    // Applies the committed delay change.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `target` - The delay target.
+    fn apply_delay(e: Env, admin: Address, target: DelayTarget) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        let value = apply_delay(&e, &target);
+        AccessControlEvents::new(&e).apply_delay(target, value);
+    }
+
+    // Reverts the committed delay change.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `target` - The delay target.
+    fn revert_delay(e: Env, admin: Address, target: DelayTarget) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        revert_delay(&e, &target);
+        AccessControlEvents::new(&e).revert_delay(target);
+    }
+}
@@ -19,7 +19,14 @@ pub enum LiquidityPoolRouterError {
     RewardsAlreadyConfigured = 314,
     DuplicatesNotAllowed = 315,
     InvalidPoolType = 316,
+    PathNotFound = 317,
+    DeadlineExpired = 318, // request executed after its deadline
+    TooManyHops = 319,
+    TokenNotInPool = 320,
 
+    WrongInputVecSize = 2001,
     TokensNotSorted = 2002,
+    OutMinNotSatisfied = 2006,
+    ZeroAmount = 2018,
     InMaxNotSatisfied = 2020,
 }
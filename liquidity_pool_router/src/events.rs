@@ -25,6 +25,16 @@ pub(crate) trait LiquidityPoolRouterEvents {
         share_amount: u128,
     );
 
+    fn deposit_to(
+        &self,
+        tokens: Vec<Address>,
+        user: Address,
+        to: Address,
+        pool_id: Address,
+        amounts: Vec<u128>,
+        share_amount: u128,
+    );
+
     fn swap(
         &self,
         tokens: Vec<Address>,
@@ -54,6 +64,14 @@ pub(crate) trait LiquidityPoolRouterEvents {
         init_args: Vec<Val>,
     );
 
+    fn update_pool_index(
+        &self,
+        tokens: Vec<Address>,
+        pool_address: Address,
+        old_pool_index: BytesN<32>,
+        new_pool_index: BytesN<32>,
+    );
+
     fn config_rewards(
         &self,
         tokens: Vec<Address>,
@@ -87,6 +105,21 @@ impl LiquidityPoolRouterEvents for Events {
         );
     }
 
+    fn deposit_to(
+        &self,
+        tokens: Vec<Address>,
+        user: Address,
+        to: Address,
+        pool_id: Address,
+        amounts: Vec<u128>,
+        share_amount: u128,
+    ) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "deposit_to"), tokens, user),
+            (to, pool_id, amounts, share_amount),
+        );
+    }
+
     fn swap(
         &self,
         tokens: Vec<Address>,
@@ -131,6 +164,19 @@ impl LiquidityPoolRouterEvents for Events {
         );
     }
 
+    fn update_pool_index(
+        &self,
+        tokens: Vec<Address>,
+        pool_address: Address,
+        old_pool_index: BytesN<32>,
+        new_pool_index: BytesN<32>,
+    ) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "update_pool_index"), tokens),
+            (pool_address, old_pool_index, new_pool_index),
+        );
+    }
+
     fn config_rewards(
         &self,
         tokens: Vec<Address>,
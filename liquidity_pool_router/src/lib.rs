@@ -6,6 +6,7 @@ pub mod errors;
 mod events;
 mod liquidity_calculator;
 mod pool_interface;
+mod pool_plane;
 mod pool_utils;
 mod rewards;
 mod router_interface;
@@ -1,3 +1,4 @@
+use crate::storage::{RouterCall, RouterCallResult, SwapQuote};
 use soroban_sdk::{Address, BytesN, Env, Map, Symbol, Val, Vec, U256};
 
 pub trait LiquidityPoolInterfaceTrait {
@@ -31,6 +32,21 @@ pub trait LiquidityPoolInterfaceTrait {
         min_shares: u128,
     ) -> (Vec<u128>, u128);
 
+    // Deposit coins into the pool minting LP tokens to another account.
+    // to: Recipient of the LP tokens
+    // deadline: Ledger timestamp after which the request is rejected
+    // Returns amounts deposited and the amount of LP tokens minted to the recipient.
+    fn deposit_to(
+        e: Env,
+        user: Address,
+        to: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        desired_amounts: Vec<u128>,
+        min_shares: u128,
+        deadline: u64,
+    ) -> (Vec<u128>, u128);
+
     // Perform an exchange between two coins.
     // token_in: token to send
     // token_out: token to receive
@@ -48,6 +64,23 @@ pub trait LiquidityPoolInterfaceTrait {
         out_min: u128,
     ) -> u128;
 
+    // Perform an exchange between two coins sending the output to another account.
+    // to: Recipient of token_out
+    // deadline: Ledger timestamp after which the request is rejected
+    // Returns the actual amount of coin out received by the recipient
+    fn swap_to(
+        e: Env,
+        user: Address,
+        to: Address,
+        tokens: Vec<Address>,
+        token_in: Address,
+        token_out: Address,
+        pool_index: BytesN<32>,
+        in_amount: u128,
+        out_min: u128,
+        deadline: u64,
+    ) -> u128;
+
     // Estimate amount of coins to retrieve using swap function
     fn estimate_swap(
         e: Env,
@@ -71,6 +104,47 @@ pub trait LiquidityPoolInterfaceTrait {
         min_amounts: Vec<u128>,
     ) -> Vec<u128>;
 
+    // Withdraw coins from the pool sending them to another account.
+    // to: Recipient of the withdrawn coins
+    // deadline: Ledger timestamp after which the request is rejected
+    // Returns a list of the amounts for each coin that was withdrawn.
+    fn withdraw_to(
+        e: Env,
+        user: Address,
+        to: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        share_amount: u128,
+        min_amounts: Vec<u128>,
+        deadline: u64,
+    ) -> Vec<u128>;
+
+    // Calculate the amount of token received when withdrawing a single token.
+    // share_amount: Quantity of LP tokens to burn in the withdrawal
+    // token_out: token to receive
+    fn calc_withdraw_one_coin(
+        e: Env,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        share_amount: u128,
+        token_out: Address,
+    ) -> u128;
+
+    // Withdraw a single token from the pool.
+    // share_amount: Quantity of LP tokens to burn in the withdrawal
+    // token_out: token to receive
+    // min_amount: Minimum amount of token_out to receive
+    // Returns a list of the amounts for each coin that was withdrawn.
+    fn withdraw_one_coin(
+        e: Env,
+        user: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        share_amount: u128,
+        token_out: Address,
+        min_amount: u128,
+    ) -> Vec<u128>;
+
     fn get_liquidity(e: Env, tokens: Vec<Address>, pool_index: BytesN<32>) -> U256;
 
     // Set liquidity calculator address. it's separate contract optimized to estimate liquidity for multiple pools
@@ -225,6 +299,28 @@ pub trait PoolsManagementTrait {
     // Remove pool from the list
     fn remove_pool(e: Env, user: Address, tokens: Vec<Address>, pool_hash: BytesN<32>);
 
+    // Commit new fee for the standard pool. Fee should be one of the available fee tiers
+    // and the pair should not have a pool with the same fee yet.
+    fn commit_pool_fee(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        new_fee: u32,
+    );
+
+    // Apply committed fee for the standard pool once the pool admin actions delay passed.
+    // Pool is moved under the index matching the new fee tier, which is returned.
+    fn apply_pool_fee(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+    ) -> BytesN<32>;
+
+    // Revert committed fee for the standard pool
+    fn revert_pool_fee(e: Env, admin: Address, tokens: Vec<Address>, pool_index: BytesN<32>);
+
     // Calculates the number of unique token sets.
     fn get_tokens_sets_count(e: Env) -> u128;
 
@@ -252,6 +348,21 @@ pub trait PoolPlaneInterface {
     fn get_plane(e: Env) -> Address;
 }
 
+pub trait MulticallInterface {
+    // Executes a batch of pool operations on behalf of the user in a single transaction.
+    // Operations are executed in order, any failure reverts the whole batch.
+    //
+    // # Arguments
+    //
+    // * `user` - The address of the user executing the operations.
+    // * `calls` - The operations to be executed: deposit, withdraw, swap, claim or swap_chained.
+    //
+    // # Returns
+    //
+    // The result of every operation in the same order as the calls.
+    fn multicall(e: Env, user: Address, calls: Vec<RouterCall>) -> Vec<RouterCallResult>;
+}
+
 pub trait CombinedSwapInterface {
     // Executes a chain of token swaps to exchange an input token for an output token.
     //
@@ -278,6 +389,32 @@ pub trait CombinedSwapInterface {
         out_min: u128,
     ) -> u128;
 
+    // Executes a chain of token swaps sending the output token to another account.
+    //
+    // # Arguments
+    //
+    // * `user` - The address of the user executing the swaps.
+    // * `to` - The address receiving the output token.
+    // * `swaps_chain` - The series of swaps to be executed, same as for `swap_chained`.
+    // * `token_in` - The address of the input token to be swapped.
+    // * `in_amount` - The amount of the input token to be swapped.
+    // * `out_min` - The minimum amount of the output token to be received.
+    // * `deadline` - The ledger timestamp after which the request is rejected.
+    //
+    // # Returns
+    //
+    // The amount of the output token received after all swaps have been executed.
+    fn swap_chained_to(
+        e: Env,
+        user: Address,
+        to: Address,
+        swaps_chain: Vec<(Vec<Address>, BytesN<32>, Address)>,
+        token_in: Address,
+        in_amount: u128,
+        out_min: u128,
+        deadline: u64,
+    ) -> u128;
+
     // Executes a chain of token swaps to exchange an input token for an output token.
     //
     // # Arguments
@@ -302,4 +439,152 @@ pub trait CombinedSwapInterface {
         out_amount: u128,
         in_max: u128,
     ) -> u128;
+
+    // Executes a chain of token swaps with strict output sending it to another account.
+    //
+    // # Arguments
+    //
+    // * `user` - The address of the user executing the swaps.
+    // * `to` - The address receiving the output token.
+    // * `swaps_chain` - The series of swaps to be executed, same as for `swap_chained`.
+    // * `token_in` - The address of the input token to be swapped.
+    // * `out_amount` - The amount of the output token to be received.
+    // * `in_max` - The max amount of the input token to spend.
+    // * `deadline` - The ledger timestamp after which the request is rejected.
+    //
+    // # Returns
+    //
+    // The amount of the input token spent after all swaps have been executed.
+    fn swap_chained_strict_receive_to(
+        e: Env,
+        user: Address,
+        to: Address,
+        swaps_chain: Vec<(Vec<Address>, BytesN<32>, Address)>,
+        token_in: Address,
+        out_amount: u128,
+        in_max: u128,
+        deadline: u64,
+    ) -> u128;
+
+    // Finds the swaps chain giving the biggest output amount for the given input.
+    //
+    // # Arguments
+    //
+    // * `token_in` - The address of the input token to be swapped.
+    // * `token_out` - The address of the token to obtain.
+    // * `in_amount` - The amount of the input token to be swapped.
+    // * `max_hops` - The maximum number of swaps in the chain, up to `MAX_PATH_HOPS`.
+    //
+    // # Returns
+    //
+    // The series of swaps in the format accepted by `swap_chained`.
+    fn find_best_path(
+        e: Env,
+        token_in: Address,
+        token_out: Address,
+        in_amount: u128,
+        max_hops: u32,
+    ) -> Vec<(Vec<Address>, BytesN<32>, Address)>;
+
+    // Swaps an input token for an output token splitting the amount between several pools.
+    //
+    // # Arguments
+    //
+    // * `user` - The address of the user executing the swap.
+    // * `token_in` - The address of the input token to be swapped.
+    // * `token_out` - The address of the token to obtain.
+    // * `legs` - The swaps to be executed. Each swap is represented by a tuple containing:
+    //   - A vector of token addresses liquidity pool belongs to
+    //   - Pool index hash
+    //   - The amount of the input token to be swapped in the pool
+    // * `out_min` - The minimum total amount of the output token to be received.
+    //
+    // # Returns
+    //
+    // The total amount of the output token received.
+    fn swap_split(
+        e: Env,
+        user: Address,
+        token_in: Address,
+        token_out: Address,
+        legs: Vec<(Vec<Address>, BytesN<32>, u128)>,
+        out_min: u128,
+    ) -> u128;
+
+    // Proposes the split of the input amount between the pools of the tokens set
+    // minimizing the price impact.
+    //
+    // # Arguments
+    //
+    // * `tokens` - A vector of token addresses the pools belong to.
+    // * `token_in` - The address of the input token to be swapped.
+    // * `token_out` - The address of the token to obtain.
+    // * `in_amount` - The amount of the input token to be swapped.
+    //
+    // # Returns
+    //
+    // The swap legs in the format accepted by `swap_split`.
+    fn find_best_split(
+        e: Env,
+        tokens: Vec<Address>,
+        token_in: Address,
+        token_out: Address,
+        in_amount: u128,
+    ) -> Vec<(Vec<Address>, BytesN<32>, u128)>;
+
+    // Estimates a chain of token swaps with the breakdown for every hop.
+    //
+    // # Arguments
+    //
+    // * `swaps_chain` - The series of swaps to be estimated, same as for `swap_chained`.
+    // * `token_in` - The address of the input token to be swapped.
+    // * `in_amount` - The amount of the input token to be swapped.
+    //
+    // # Returns
+    //
+    // The quote containing pool, amounts, fee and spot prices before and after the swap for every hop,
+    // the final output amount and the aggregate price impact in basis points.
+    fn quote_chained(
+        e: Env,
+        swaps_chain: Vec<(Vec<Address>, BytesN<32>, Address)>,
+        token_in: Address,
+        in_amount: u128,
+    ) -> SwapQuote;
+}
+
+pub trait PoolProtocolFeeInterface {
+    // Commit protocol share of the swap fee for the standard pool. Applied after the pool admin actions delay.
+    // new_fraction has denominator 10000; 1 = 0.01% of the swap fee
+    fn commit_pool_protocol_fee(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        new_fraction: u32,
+    );
+
+    // Apply committed protocol fee share for the standard pool
+    fn apply_pool_protocol_fee(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+    );
+
+    // Revert committed protocol fee share for the standard pool
+    fn revert_pool_protocol_fee(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+    );
+
+    // Set address receiving protocol fees claimed from the standard pool
+    fn set_pool_fees_collector(
+        e: Env,
+        admin: Address,
+        tokens: Vec<Address>,
+        pool_index: BytesN<32>,
+        fees_collector: Address,
+    );
 }
@@ -0,0 +1,8 @@
+mod pool_plane_client {
+    soroban_sdk::contractimport!(
+        file =
+            "../target/wasm32-unknown-unknown/release/soroban_liquidity_pool_plane_contract.wasm"
+    );
+}
+
+pub use crate::pool_plane::pool_plane_client::Client as PoolPlaneClient;
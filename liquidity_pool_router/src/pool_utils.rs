@@ -1,10 +1,13 @@
+use crate::constants::{FEE_MULTIPLIER, MAX_PATH_HOPS, PRICE_PRECISION, SWAP_SPLIT_PARTS};
 use crate::errors::LiquidityPoolRouterError;
 use crate::events::{Events, LiquidityPoolRouterEvents};
 use crate::liquidity_calculator::LiquidityCalculatorClient;
+use crate::pool_plane::PoolPlaneClient;
 use crate::rewards::get_rewards_manager;
 use crate::storage::{
-    add_pool, add_tokens_set, get_constant_product_pool_hash, get_pool_next_counter,
-    get_pool_plane, get_pools_plain, get_stableswap_pool_hash, get_token_hash, LiquidityPoolType,
+    add_pool, add_tokens_set, get_constant_product_pool_hash, get_pool, get_pool_next_counter,
+    get_pool_plane, get_pools_plain, get_stableswap_pool_hash, get_token_hash, get_token_sets,
+    get_tokens_set, LiquidityPoolType, SwapQuote, SwapQuoteHop,
 };
 use access_control::access::AccessControl;
 use access_control::management::{MultipleAddressesManagementTrait, SingleAddressManagementTrait};
@@ -266,6 +269,12 @@ pub fn assert_tokens_sorted(e: &Env, tokens: &Vec<Address>) {
     }
 }
 
+pub fn assert_deadline(e: &Env, deadline: u64) {
+    if e.ledger().timestamp() > deadline {
+        panic_with_error!(e, LiquidityPoolRouterError::DeadlineExpired);
+    }
+}
+
 pub fn get_tokens_salt(e: &Env, tokens: &Vec<Address>) -> BytesN<32> {
     let mut salt = Bytes::new(e);
     for token in tokens.iter() {
@@ -307,3 +316,388 @@ pub fn get_total_liquidity(
     }
     (pools_map, result)
 }
+
+fn estimate_pool_swap(
+    e: &Env,
+    pool_id: &Address,
+    in_idx: u32,
+    out_idx: u32,
+    in_amount: u128,
+) -> u128 {
+    e.invoke_contract(
+        pool_id,
+        &Symbol::new(e, "estimate_swap"),
+        Vec::from_array(
+            e,
+            [
+                in_idx.into_val(e),
+                out_idx.into_val(e),
+                in_amount.into_val(e),
+            ],
+        ),
+    )
+}
+
+fn is_pool_swap_killed(e: &Env, pool_id: &Address) -> bool {
+    e.invoke_contract(pool_id, &Symbol::new(e, "get_is_killed_swap"), Vec::new(e))
+}
+
+// Pools containing the token which may be used for swaps: not empty (according to the pools plane)
+// and not killed.
+fn get_token_swap_pools(
+    e: &Env,
+    plane: &PoolPlaneClient,
+    token: &Address,
+) -> Vec<(Vec<Address>, BytesN<32>, Address)> {
+    let mut pools: Vec<(Vec<Address>, BytesN<32>, Address)> = Vec::new(e);
+    let mut pools_addresses: Vec<Address> = Vec::new(e);
+    for index in get_token_sets(e, token) {
+        let tokens = get_tokens_set(e, index);
+        for (pool_index, pool_id) in get_pools_plain(e, get_tokens_salt(e, &tokens)) {
+            // tokens set may be registered again after all its pools were removed
+            if pools_addresses.contains(&pool_id) {
+                continue;
+            }
+            pools.push_back((tokens.clone(), pool_index, pool_id.clone()));
+            pools_addresses.push_back(pool_id);
+        }
+    }
+    if pools_addresses.len() == 0 {
+        return pools;
+    }
+
+    let pools_data = plane.get(&pools_addresses);
+    let mut result = Vec::new(e);
+    for i in 0..pools.len() {
+        let (_, _, reserves) = pools_data.get(i).unwrap();
+        // empty pools are skipped without being called
+        if reserves.len() == 0 || reserves.iter().any(|reserve| reserve == 0) {
+            continue;
+        }
+        let (tokens, pool_index, pool_id) = pools.get(i).unwrap();
+        if is_pool_swap_killed(e, &pool_id) {
+            continue;
+        }
+        result.push_back((tokens, pool_index, pool_id));
+    }
+    result
+}
+
+// Searches the registered pools for the swaps chain giving the biggest output amount.
+// Paths are expanded hop by hop keeping only the best known amount for every reached token,
+// so the number of pool estimates stays linear in `max_hops`.
+// Only the pools containing the reached tokens are considered, see `get_token_swap_pools`.
+pub fn find_best_path(
+    e: &Env,
+    token_in: &Address,
+    token_out: &Address,
+    in_amount: u128,
+    max_hops: u32,
+) -> Option<(Vec<(Vec<Address>, BytesN<32>, Address)>, u128)> {
+    if max_hops == 0 || max_hops > MAX_PATH_HOPS {
+        panic_with_error!(e, LiquidityPoolRouterError::TooManyHops);
+    }
+
+    let plane = PoolPlaneClient::new(e, &get_pool_plane(e));
+    let mut token_pools: Map<Address, Vec<(Vec<Address>, BytesN<32>, Address)>> = Map::new(e);
+
+    let mut best_path: Option<(Vec<(Vec<Address>, BytesN<32>, Address)>, u128)> = None;
+    let mut reached: Map<Address, (Vec<(Vec<Address>, BytesN<32>, Address)>, u128)> = Map::new(e);
+    reached.set(token_in.clone(), (Vec::new(e), in_amount));
+
+    for _hop in 0..max_hops {
+        let mut next_reached = Map::new(e);
+        for (token, (path, amount)) in reached {
+            let pools = match token_pools.get(token.clone()) {
+                Some(v) => v,
+                None => {
+                    let pools = get_token_swap_pools(e, &plane, &token);
+                    token_pools.set(token.clone(), pools.clone());
+                    pools
+                }
+            };
+
+            for (tokens, pool_index, pool_id) in pools {
+                let in_idx = tokens.first_index_of(token.clone()).unwrap();
+                for out_idx in 0..tokens.len() {
+                    let next_token = tokens.get(out_idx).unwrap();
+                    // don't visit the same token twice
+                    if out_idx == in_idx
+                        || next_token == *token_in
+                        || path.iter().any(|(_, _, hop_out)| hop_out == next_token)
+                    {
+                        continue;
+                    }
+
+                    let out_amount = estimate_pool_swap(e, &pool_id, in_idx, out_idx, amount);
+                    if out_amount == 0 {
+                        continue;
+                    }
+                    if let Some((_, known_amount)) = next_reached.get(next_token.clone()) {
+                        if known_amount >= out_amount {
+                            continue;
+                        }
+                    }
+
+                    let mut next_path = path.clone();
+                    next_path.push_back((tokens.clone(), pool_index.clone(), next_token.clone()));
+                    next_reached.set(next_token, (next_path, out_amount));
+                }
+            }
+        }
+
+        // destination reached, no need to go further from it
+        if let Some((path, amount)) = next_reached.get(token_out.clone()) {
+            let is_better = match &best_path {
+                Some((_, best_amount)) => amount > *best_amount,
+                None => true,
+            };
+            if is_better {
+                best_path = Some((path, amount));
+            }
+            next_reached.remove(token_out.clone());
+        }
+        reached = next_reached;
+    }
+
+    best_path
+}
+
+// Indexes of the swapped tokens in the tokens set. Both tokens must belong to the set and differ.
+pub fn get_swap_indexes(
+    e: &Env,
+    tokens: &Vec<Address>,
+    token_in: &Address,
+    token_out: &Address,
+) -> (u32, u32) {
+    match (
+        tokens.first_index_of(token_in.clone()),
+        tokens.first_index_of(token_out.clone()),
+    ) {
+        (Some(in_idx), Some(out_idx)) if in_idx != out_idx => (in_idx, out_idx),
+        _ => panic_with_error!(e, LiquidityPoolRouterError::TokenNotInPool),
+    }
+}
+
+// Splits the input amount between the active pools of the tokens set to minimize the price impact.
+// Amount is allocated in equal parts, every part goes to the pool giving the biggest output increase,
+// so marginal prices of the used pools end up as close as the parts granularity allows.
+pub fn find_best_split(
+    e: &Env,
+    tokens: &Vec<Address>,
+    token_in: &Address,
+    token_out: &Address,
+    in_amount: u128,
+) -> Vec<(Vec<Address>, BytesN<32>, u128)> {
+    let (in_idx, out_idx) = get_swap_indexes(e, tokens, token_in, token_out);
+
+    let pools = get_pools_plain(e, get_tokens_salt(e, tokens));
+    let pools_data = PoolPlaneClient::new(e, &get_pool_plane(e)).get(&pools.values());
+    let mut pools_indexes: Vec<BytesN<32>> = Vec::new(e);
+    let mut pools_addresses: Vec<Address> = Vec::new(e);
+    for (i, (pool_index, pool_id)) in pools.iter().enumerate() {
+        let (_, _, reserves) = pools_data.get(i as u32).unwrap();
+        // empty pools are skipped without being called
+        if reserves.get(in_idx).unwrap_or(0) == 0 || reserves.get(out_idx).unwrap_or(0) == 0 {
+            continue;
+        }
+        if is_pool_swap_killed(e, &pool_id) {
+            continue;
+        }
+        pools_indexes.push_back(pool_index);
+        pools_addresses.push_back(pool_id);
+    }
+    if pools_addresses.len() == 0 {
+        panic_with_error!(e, LiquidityPoolRouterError::PoolNotFound);
+    }
+
+    let mut allocated: Vec<u128> = Vec::new(e);
+    let mut estimated: Vec<u128> = Vec::new(e);
+    for _ in 0..pools_addresses.len() {
+        allocated.push_back(0);
+        estimated.push_back(0);
+    }
+
+    let part = in_amount / SWAP_SPLIT_PARTS;
+    for step in 0..SWAP_SPLIT_PARTS {
+        // last part takes the rounding remainder
+        let amount = if step == SWAP_SPLIT_PARTS - 1 {
+            in_amount - part * (SWAP_SPLIT_PARTS - 1)
+        } else {
+            part
+        };
+        if amount == 0 {
+            continue;
+        }
+
+        let mut best: Option<(u32, u128, u128)> = None;
+        for i in 0..pools_addresses.len() {
+            let pool_allocated = allocated.get(i).unwrap() + amount;
+            let pool_estimated = estimate_pool_swap(
+                e,
+                &pools_addresses.get(i).unwrap(),
+                in_idx,
+                out_idx,
+                pool_allocated,
+            );
+            let gain = pool_estimated.saturating_sub(estimated.get(i).unwrap());
+            let is_better = match best {
+                Some((_, _, best_gain)) => gain > best_gain,
+                None => true,
+            };
+            if is_better {
+                best = Some((i, pool_estimated, gain));
+            }
+        }
+
+        let (i, pool_estimated, _) = best.unwrap();
+        allocated.set(i, allocated.get(i).unwrap() + amount);
+        estimated.set(i, pool_estimated);
+    }
+
+    let mut legs = Vec::new(e);
+    for i in 0..pools_addresses.len() {
+        let amount = allocated.get(i).unwrap();
+        if amount > 0 {
+            legs.push_back((tokens.clone(), pools_indexes.get(i).unwrap(), amount));
+        }
+    }
+    legs
+}
+
+// Spot price of `token_in` in units of `token_out` based on the pool reserves.
+fn spot_price(e: &Env, reserve_in: u128, reserve_out: u128) -> u128 {
+    if reserve_in == 0 {
+        return 0;
+    }
+    U256::from_u128(e, reserve_out)
+        .mul(&U256::from_u128(e, PRICE_PRECISION))
+        .div(&U256::from_u128(e, reserve_in))
+        .to_u128()
+        .unwrap()
+}
+
+// Spot price of `token_in` in units of `token_out` on the stableswap invariant curve.
+// The pool provides the marginal prices in the normalized units, so they're adjusted by the decimals.
+fn stableswap_spot_price(
+    e: &Env,
+    pool_id: &Address,
+    reserves: &Vec<u128>,
+    decimals: &Vec<u32>,
+    in_idx: u32,
+    out_idx: u32,
+) -> u128 {
+    let prices: Vec<U256> = e.invoke_contract(
+        pool_id,
+        &Symbol::new(e, "calc_prices"),
+        Vec::from_array(e, [reserves.clone().into_val(e)]),
+    );
+    let price_out = prices.get(out_idx).unwrap();
+    if price_out == U256::from_u32(e, 0) {
+        return 0;
+    }
+    prices
+        .get(in_idx)
+        .unwrap()
+        .mul(&U256::from_u128(
+            e,
+            10_u128.pow(decimals.get(out_idx).unwrap()),
+        ))
+        .mul(&U256::from_u128(e, PRICE_PRECISION))
+        .div(&price_out.mul(&U256::from_u128(
+            e,
+            10_u128.pow(decimals.get(in_idx).unwrap()),
+        )))
+        .to_u128()
+        .unwrap()
+}
+
+// Estimates the swaps chain hop by hop without executing it.
+// For every hop the pool is asked for the output amount, fee fraction and reserves,
+// the prices after the swap are calculated from reserves moved by the swapped amounts.
+// Constant product pools are priced by the reserves ratio, stableswap pools by their invariant curve.
+pub fn quote_chained(
+    e: &Env,
+    swaps_chain: &Vec<(Vec<Address>, BytesN<32>, Address)>,
+    token_in: &Address,
+    in_amount: u128,
+) -> SwapQuote {
+    if swaps_chain.len() == 0 {
+        panic_with_error!(e, LiquidityPoolRouterError::PathIsEmpty);
+    }
+
+    let precision = U256::from_u128(e, PRICE_PRECISION);
+    let mut hops: Vec<SwapQuoteHop> = Vec::new(e);
+    let mut hop_token_in = token_in.clone();
+    let mut hop_in_amount = in_amount;
+    // output expected at the spot prices, without fees and slippage
+    let mut ideal_out = U256::from_u128(e, in_amount);
+
+    for (tokens, pool_index, token_out) in swaps_chain.iter() {
+        assert_tokens_sorted(e, &tokens);
+        let pool_id = get_pool(e, &tokens, pool_index);
+        let (in_idx, out_idx) = get_swap_indexes(e, &tokens, &hop_token_in, &token_out);
+
+        let out_amount = estimate_pool_swap(e, &pool_id, in_idx, out_idx, hop_in_amount);
+        let fee_fraction: u32 =
+            e.invoke_contract(&pool_id, &Symbol::new(e, "get_fee_fraction"), Vec::new(e));
+        let reserves: Vec<u128> =
+            e.invoke_contract(&pool_id, &Symbol::new(e, "get_reserves"), Vec::new(e));
+        let pool_type: Symbol =
+            e.invoke_contract(&pool_id, &Symbol::new(e, "pool_type"), Vec::new(e));
+
+        let reserve_in = reserves.get(in_idx).unwrap();
+        let reserve_out = reserves.get(out_idx).unwrap();
+        let (price_before, price_after) = if pool_type == symbol_short!("stable") {
+            let decimals: Vec<u32> =
+                e.invoke_contract(&pool_id, &Symbol::new(e, "get_decimals"), Vec::new(e));
+            let mut reserves_after = reserves.clone();
+            reserves_after.set(in_idx, reserve_in + hop_in_amount);
+            reserves_after.set(out_idx, reserve_out - out_amount);
+            (
+                stableswap_spot_price(e, &pool_id, &reserves, &decimals, in_idx, out_idx),
+                stableswap_spot_price(e, &pool_id, &reserves_after, &decimals, in_idx, out_idx),
+            )
+        } else {
+            (
+                spot_price(e, reserve_in, reserve_out),
+                spot_price(e, reserve_in + hop_in_amount, reserve_out - out_amount),
+            )
+        };
+
+        // pools take the fee from the output amount, restore it from the amount left to the user
+        let fee_fraction = fee_fraction as u128;
+        let fee_amount = (out_amount * fee_fraction).div_ceil(FEE_MULTIPLIER - fee_fraction);
+
+        ideal_out = ideal_out
+            .mul(&U256::from_u128(e, price_before))
+            .div(&precision);
+
+        hops.push_back(SwapQuoteHop {
+            pool: pool_id,
+            pool_type,
+            token_in: hop_token_in,
+            token_out: token_out.clone(),
+            in_amount: hop_in_amount,
+            out_amount,
+            fee_amount,
+            price_before,
+            price_after,
+        });
+        hop_token_in = token_out;
+        hop_in_amount = out_amount;
+    }
+
+    let ideal_out = ideal_out.to_u128().unwrap();
+    let price_impact = if ideal_out > hop_in_amount {
+        (ideal_out - hop_in_amount) * FEE_MULTIPLIER / ideal_out
+    } else {
+        0
+    };
+
+    SwapQuote {
+        hops,
+        out_amount: hop_in_amount,
+        price_impact,
+    }
+}
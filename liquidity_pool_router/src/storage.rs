@@ -3,7 +3,7 @@ use crate::errors::LiquidityPoolRouterError;
 use crate::pool_utils::get_tokens_salt;
 use paste::paste;
 use soroban_sdk::{
-    contracterror, contracttype, panic_with_error, Address, BytesN, Env, Map, Vec, U256,
+    contracterror, contracttype, panic_with_error, Address, BytesN, Env, Map, Symbol, Vec, U256,
 };
 use utils::bump::{bump_instance, bump_persistent, bump_temporary};
 use utils::storage_errors::StorageError;
@@ -45,12 +45,69 @@ pub struct LiquidityPoolRewardInfo {
     pub total_liquidity: U256,
 }
 
+// Breakdown of the single swap in the quoted chain.
+// Prices are amounts of `token_out` per unit of `token_in` multiplied by 1e18.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SwapQuoteHop {
+    pub pool: Address,
+    pub pool_type: Symbol,
+    pub token_in: Address,
+    pub token_out: Address,
+    pub in_amount: u128,
+    pub out_amount: u128,
+    pub fee_amount: u128, // fee charged by the pool, in `token_out`
+    pub price_before: u128,
+    pub price_after: u128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SwapQuote {
+    pub hops: Vec<SwapQuoteHop>,
+    pub out_amount: u128,
+    pub price_impact: u128, // in basis points, compared to the spot prices before the swap
+}
+
+// Single operation executed as part of the router multicall.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RouterCall {
+    // tokens, pool_index, desired_amounts, min_shares
+    Deposit(Vec<Address>, BytesN<32>, Vec<u128>, u128),
+    // tokens, pool_index, share_amount, min_amounts
+    Withdraw(Vec<Address>, BytesN<32>, u128, Vec<u128>),
+    // tokens, token_in, token_out, pool_index, in_amount, out_min
+    Swap(Vec<Address>, Address, Address, BytesN<32>, u128, u128),
+    // tokens, pool_index
+    Claim(Vec<Address>, BytesN<32>),
+    // swaps_chain, token_in, in_amount, out_min
+    SwapChained(
+        Vec<(Vec<Address>, BytesN<32>, Address)>,
+        Address,
+        u128,
+        u128,
+    ),
+}
+
+// Result of the single multicall operation, matching the standalone method output.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RouterCallResult {
+    Deposit(Vec<u128>, u128),
+    Withdraw(Vec<u128>),
+    Swap(u128),
+    Claim(u128),
+    SwapChained(u128),
+}
+
 #[derive(Clone)]
 #[contracttype]
 enum DataKey {
     TokensSet(u128),
     TokensSetCounter,
     TokensSetPools(BytesN<32>),
+    TokenSets(Address), // indexes of the tokens sets containing the token
     TokenHash,
     InitPoolPaymentToken,
     InitStandardPoolPaymentAmount,
@@ -279,6 +336,45 @@ pub fn add_tokens_set(e: &Env, tokens: &Vec<Address>) {
     let tokens_set_count = get_tokens_set_count(e);
     put_tokens_set(e, tokens_set_count, &tokens);
     set_tokens_set_count(e, &(tokens_set_count + 1));
+
+    for token in tokens.iter() {
+        let mut token_sets = get_token_sets(e, &token);
+        token_sets.push_back(tokens_set_count);
+        put_token_sets(e, &token, &token_sets);
+    }
+}
+
+pub fn get_pool_type(e: &Env, salt: BytesN<32>, pool_index: BytesN<32>) -> LiquidityPoolType {
+    match get_pools(e, salt).get(pool_index) {
+        Some(data) => data.pool_type,
+        None => LiquidityPoolType::MissingPool,
+    }
+}
+
+// move pool under the new index keeping its data
+pub fn move_pool(e: &Env, salt: BytesN<32>, pool_index: BytesN<32>, new_pool_index: BytesN<32>) {
+    let mut pools = get_pools(e, salt.clone());
+    if pools.contains_key(new_pool_index.clone()) {
+        panic_with_error!(&e, PoolError::PoolAlreadyExists);
+    }
+    let pool_data = match pools.get(pool_index.clone()) {
+        Some(data) => data,
+        None => panic_with_error!(&e, PoolError::PoolNotFound),
+    };
+    pools.remove(pool_index.clone());
+    pools.set(new_pool_index.clone(), pool_data);
+    put_pools(e, salt.clone(), &pools);
+
+    // pool liquidity filled for the current rewards config is keyed by the pool index as well
+    let key = DataKey::RewardTokensPoolsLiquidity(salt.clone());
+    let tokens_detailed: Option<Map<BytesN<32>, (U256, bool)>> = e.storage().temporary().get(&key);
+    if let Some(mut tokens_detailed) = tokens_detailed {
+        if let Some(pool_liquidity) = tokens_detailed.get(pool_index.clone()) {
+            tokens_detailed.remove(pool_index);
+            tokens_detailed.set(new_pool_index, pool_liquidity);
+            set_reward_tokens_detailed(e, salt, &tokens_detailed);
+        }
+    }
 }
 
 pub fn remove_pool(e: &Env, salt: BytesN<32>, pool_index: BytesN<32>) {
@@ -309,3 +405,20 @@ pub fn put_tokens_set(e: &Env, index: u128, tokens: &Vec<Address>) {
     e.storage().persistent().set(&key, tokens);
     bump_persistent(e, &key);
 }
+
+pub fn get_token_sets(e: &Env, token: &Address) -> Vec<u128> {
+    let key = DataKey::TokenSets(token.clone());
+    match e.storage().persistent().get(&key) {
+        Some(v) => {
+            bump_persistent(e, &key);
+            v
+        }
+        None => Vec::new(e),
+    }
+}
+
+fn put_token_sets(e: &Env, token: &Address, value: &Vec<u128>) {
+    let key = DataKey::TokenSets(token.clone());
+    e.storage().persistent().set(&key, value);
+    bump_persistent(e, &key);
+}
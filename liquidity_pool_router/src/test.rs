@@ -2,6 +2,7 @@
 extern crate std;
 
 use crate::constants::{CONSTANT_PRODUCT_FEE_AVAILABLE, STABLESWAP_MAX_POOLS};
+use crate::storage::{RouterCall, RouterCallResult, SwapQuote, SwapQuoteHop};
 use crate::testutils;
 use crate::testutils::{create_plane_contract, test_token, Setup};
 use access_control::constants::ADMIN_ACTIONS_DELAY;
@@ -112,34 +113,35 @@ fn test_constant_product_pool() {
 
     let token_share = test_token::Client::new(&e, &router.share_id(&tokens, &pool_hash));
 
-    token1.mint(&user1, &1000);
-    assert_eq!(token1.balance(&user1), 1000);
+    token1.mint(&user1, &10000);
+    assert_eq!(token1.balance(&user1), 10000);
 
-    token2.mint(&user1, &1000);
-    assert_eq!(token2.balance(&user1), 1000);
+    token2.mint(&user1, &10000);
+    assert_eq!(token2.balance(&user1), 10000);
 
     assert_eq!(token_share.balance(&user1), 0);
 
-    let desired_amounts = Vec::from_array(&e, [100, 100]);
+    let desired_amounts = Vec::from_array(&e, [2000, 2000]);
     router.deposit(&user1, &tokens, &pool_hash, &desired_amounts, &0);
-    assert_eq!(router.get_total_liquidity(&tokens), U256::from_u32(&e, 2));
+    assert_eq!(router.get_total_liquidity(&tokens), U256::from_u32(&e, 70));
 
-    assert_eq!(token_share.balance(&user1), 100);
-    assert_eq!(router.get_total_shares(&tokens, &pool_hash), 100);
-    assert_eq!(token_share.balance(&pool_address), 0);
-    assert_eq!(token1.balance(&user1), 900);
-    assert_eq!(token1.balance(&pool_address), 100);
-    assert_eq!(token2.balance(&user1), 900);
-    assert_eq!(token2.balance(&pool_address), 100);
+    // minimum liquidity is locked on the pool balance
+    assert_eq!(token_share.balance(&user1), 1000);
+    assert_eq!(router.get_total_shares(&tokens, &pool_hash), 2000);
+    assert_eq!(token_share.balance(&pool_address), 1000);
+    assert_eq!(token1.balance(&user1), 8000);
+    assert_eq!(token1.balance(&pool_address), 2000);
+    assert_eq!(token2.balance(&user1), 8000);
+    assert_eq!(token2.balance(&pool_address), 2000);
 
     assert_eq!(
         router.get_reserves(&tokens, &pool_hash),
-        Vec::from_array(&e, [100, 100])
+        Vec::from_array(&e, [2000, 2000])
     );
 
     assert_eq!(
-        router.estimate_swap(&tokens, &token1.address, &token2.address, &pool_hash, &97),
-        48
+        router.estimate_swap(&tokens, &token1.address, &token2.address, &pool_hash, &1940),
+        981
     );
     assert_eq!(
         router.swap(
@@ -148,35 +150,35 @@ fn test_constant_product_pool() {
             &token1.address,
             &token2.address,
             &pool_hash,
-            &97_u128,
-            &48_u128,
+            &1940_u128,
+            &981_u128,
         ),
-        48
+        981
     );
 
-    assert_eq!(token1.balance(&user1), 803);
-    assert_eq!(token1.balance(&pool_address), 197);
-    assert_eq!(token2.balance(&user1), 948);
-    assert_eq!(token2.balance(&pool_address), 52);
+    assert_eq!(token1.balance(&user1), 6060);
+    assert_eq!(token1.balance(&pool_address), 3940);
+    assert_eq!(token2.balance(&user1), 8981);
+    assert_eq!(token2.balance(&pool_address), 1019);
     assert_eq!(
         router.get_reserves(&tokens, &pool_hash),
-        Vec::from_array(&e, [197, 52])
+        Vec::from_array(&e, [3940, 1019])
     );
 
     router.withdraw(
         &user1,
         &tokens,
         &pool_hash,
-        &100_u128,
-        &Vec::from_array(&e, [197_u128, 52_u128]),
+        &1000_u128,
+        &Vec::from_array(&e, [1970_u128, 509_u128]),
     );
 
-    assert_eq!(token1.balance(&user1), 1000);
-    assert_eq!(token2.balance(&user1), 1000);
+    assert_eq!(token1.balance(&user1), 8030);
+    assert_eq!(token2.balance(&user1), 9490);
     assert_eq!(token_share.balance(&user1), 0);
-    assert_eq!(token1.balance(&pool_address), 0);
-    assert_eq!(token2.balance(&pool_address), 0);
-    assert_eq!(token_share.balance(&pool_address), 0);
+    assert_eq!(token1.balance(&pool_address), 1970);
+    assert_eq!(token2.balance(&pool_address), 510);
+    assert_eq!(token_share.balance(&pool_address), 1000);
 }
 
 #[test]
@@ -367,9 +369,9 @@ fn test_stableswap_pool() {
         U256::from_u32(&e, 337515788)
     );
 
-    assert_eq!(token_share.balance(&user1), 200_0000000);
+    assert_eq!(token_share.balance(&user1), 200_0000000 - 1000);
     assert_eq!(router.get_total_shares(&tokens, &pool_hash), 200_0000000);
-    assert_eq!(token_share.balance(&pool_address), 0);
+    assert_eq!(token_share.balance(&pool_address), 1000);
     assert_eq!(token1.balance(&user1), 900_0000000);
     assert_eq!(token1.balance(&pool_address), 100_0000000);
     assert_eq!(token2.balance(&user1), 900_0000000);
@@ -416,16 +418,16 @@ fn test_stableswap_pool() {
         &user1,
         &tokens,
         &pool_hash,
-        &200_0000000_u128,
-        &Vec::from_array(&e, [197_0000000_u128, 4_1079105_u128]),
+        &(200_0000000_u128 - 1000),
+        &Vec::from_array(&e, [196_9999015_u128, 4_1079084_u128]),
     );
 
-    assert_eq!(token1.balance(&user1), 1000_0000000);
-    assert_eq!(token2.balance(&user1), 1000_0000000);
+    assert_eq!(token1.balance(&user1), 999_9999015);
+    assert_eq!(token2.balance(&user1), 999_9999979);
     assert_eq!(token_share.balance(&user1), 0);
-    assert_eq!(token1.balance(&pool_address), 0);
-    assert_eq!(token2.balance(&pool_address), 0);
-    assert_eq!(token_share.balance(&pool_address), 0);
+    assert_eq!(token1.balance(&pool_address), 985);
+    assert_eq!(token2.balance(&pool_address), 21);
+    assert_eq!(token_share.balance(&pool_address), 1000);
 }
 
 #[test]
@@ -486,8 +488,8 @@ fn test_stableswap_3_pool() {
         U256::from_u32(&e, 1038936246)
     );
 
-    assert_eq!(token_share.balance(&user1), 300_0000000);
-    assert_eq!(token_share.balance(&pool_address), 0);
+    assert_eq!(token_share.balance(&user1), 300_0000000 - 1000);
+    assert_eq!(token_share.balance(&pool_address), 1000);
 
     assert_eq!(token1.balance(&user1), 900_0000000);
     assert_eq!(token1.balance(&pool_address), 100_0000000);
@@ -541,17 +543,17 @@ fn test_stableswap_3_pool() {
         &user1,
         &tokens,
         &pool_hash,
-        &300_0000000_u128,
-        &Vec::from_array(&e, [197_0000000, 23_5091615, 79_8855775]),
+        &(300_0000000_u128 - 1000),
+        &Vec::from_array(&e, [196_9999343, 23_5091536, 79_8855508]),
     );
 
-    assert_eq!(token1.balance(&user1), 1000_0000000);
-    assert_eq!(token2.balance(&user1), 1000_0000000);
-    assert_eq!(token3.balance(&user1), 1000_0000000);
+    assert_eq!(token1.balance(&user1), 999_9999343);
+    assert_eq!(token2.balance(&user1), 999_9999921);
+    assert_eq!(token3.balance(&user1), 999_9999733);
     assert_eq!(token_share.balance(&user1), 0);
-    assert_eq!(token1.balance(&pool_address), 0);
-    assert_eq!(token2.balance(&pool_address), 0);
-    assert_eq!(token_share.balance(&pool_address), 0);
+    assert_eq!(token1.balance(&pool_address), 657);
+    assert_eq!(token2.balance(&pool_address), 79);
+    assert_eq!(token_share.balance(&pool_address), 1000);
 }
 
 #[test]
@@ -685,11 +687,11 @@ fn test_simple_ongoing_reward() {
     let reward_1_tps = 10_5000000_u128;
     let total_reward_1 = reward_1_tps * 60;
 
-    token1.mint(&user1, &2000);
-    assert_eq!(token1.balance(&user1), 2000);
+    token1.mint(&user1, &3000);
+    assert_eq!(token1.balance(&user1), 3000);
 
-    token2.mint(&user1, &2000);
-    assert_eq!(token2.balance(&user1), 2000);
+    token2.mint(&user1, &3000);
+    assert_eq!(token2.balance(&user1), 3000);
 
     assert_eq!(
         router.get_total_accumulated_reward(&tokens, &standard_pool_hash),
@@ -730,11 +732,11 @@ fn test_simple_ongoing_reward() {
         &user1,
         &tokens,
         &standard_pool_hash,
-        &Vec::from_array(&e, [1000, 1000]),
+        &Vec::from_array(&e, [2000, 2000]),
         &0,
     );
     let standard_liquidity = router.get_total_liquidity(&tokens);
-    assert_eq!(standard_liquidity, U256::from_u32(&e, 34));
+    assert_eq!(standard_liquidity, U256::from_u32(&e, 70));
     router.deposit(
         &user1,
         &tokens,
@@ -745,7 +747,7 @@ fn test_simple_ongoing_reward() {
     let stable_liquidity = router.get_total_liquidity(&tokens).sub(&standard_liquidity);
     assert_eq!(
         standard_liquidity.add(&stable_liquidity),
-        U256::from_u32(&e, 370)
+        U256::from_u32(&e, 406)
     );
 
     assert_eq!(
@@ -987,9 +989,9 @@ fn test_rewards_distribution() {
 
     let reward_tps = 10_5000000_u128;
 
-    token1.mint(&user1, &4000);
-    token2.mint(&user1, &2000);
-    reward_token.mint(&user1, &2000);
+    token1.mint(&user1, &6000);
+    token2.mint(&user1, &3000);
+    reward_token.mint(&user1, &3000);
 
     assert_eq!(
         router.get_total_outstanding_reward(&tokens1, &standard_pool_hash1),
@@ -1014,20 +1016,20 @@ fn test_rewards_distribution() {
         &user1,
         &tokens1,
         &standard_pool_hash1,
-        &Vec::from_array(&e, [1000, 1000]),
+        &Vec::from_array(&e, [2000, 2000]),
         &0,
     );
     router.deposit(
         &user1,
         &tokens2,
         &standard_pool_hash2,
-        &Vec::from_array(&e, [1000, 1000]),
+        &Vec::from_array(&e, [2000, 2000]),
         &0,
     );
     let standard_liquidity1 = router.get_total_liquidity(&tokens1);
     let standard_liquidity2 = router.get_total_liquidity(&tokens2);
-    assert_eq!(standard_liquidity1, U256::from_u32(&e, 34));
-    assert_eq!(standard_liquidity2, U256::from_u32(&e, 34));
+    assert_eq!(standard_liquidity1, U256::from_u32(&e, 70));
+    assert_eq!(standard_liquidity2, U256::from_u32(&e, 70));
     router.deposit(
         &user1,
         &tokens1,
@@ -1050,11 +1052,11 @@ fn test_rewards_distribution() {
         .sub(&standard_liquidity2);
     assert_eq!(
         standard_liquidity1.add(&stable_liquidity1),
-        U256::from_u32(&e, 370)
+        U256::from_u32(&e, 406)
     );
     assert_eq!(
         standard_liquidity2.add(&stable_liquidity2),
-        U256::from_u32(&e, 370)
+        U256::from_u32(&e, 406)
     );
 
     let rewards = Vec::from_array(
@@ -1133,7 +1135,7 @@ fn test_rewards_distribution() {
 
     assert_eq!(reward_token.balance(&standard_pool_address1), 0);
     assert_eq!(reward_token.balance(&stable_pool_address1), 0);
-    assert_eq!(reward_token.balance(&standard_pool_address2), 1000);
+    assert_eq!(reward_token.balance(&standard_pool_address2), 2000);
     assert_eq!(reward_token.balance(&stable_pool_address2), 1000);
     assert_eq!(
         router.distribute_outstanding_reward(
@@ -1240,7 +1242,7 @@ fn test_rewards_distribution() {
     );
     assert_eq!(
         reward_token.balance(&standard_pool_address2) as u128,
-        standard_pool_tps * 60 + 2000
+        standard_pool_tps * 60 + 3000
     );
     assert_eq!(
         reward_token.balance(&stable_pool_address2) as u128,
@@ -1250,11 +1252,11 @@ fn test_rewards_distribution() {
     // reserves don't include rewards
     assert_eq!(
         router.get_reserves(&tokens1, &standard_pool_hash1),
-        Vec::from_array(&e, [2000, 2000])
+        Vec::from_array(&e, [3000, 3000])
     );
     assert_eq!(
         router.get_reserves(&tokens2, &standard_pool_hash2),
-        Vec::from_array(&e, [2000, 2000])
+        Vec::from_array(&e, [3000, 3000])
     );
     assert_eq!(
         router.get_reserves(&tokens1, &stable_pool_hash1),
@@ -1297,8 +1299,8 @@ fn test_rewards_distribution_as_operator() {
 
     let reward_1_tps = 10_5000000_u128;
 
-    token1.mint(&user1, &2000);
-    token2.mint(&user1, &2000);
+    token1.mint(&user1, &3000);
+    token2.mint(&user1, &3000);
 
     // 10 seconds passed since config, user depositing
     jump(&e, 10);
@@ -1306,7 +1308,7 @@ fn test_rewards_distribution_as_operator() {
         &user1,
         &tokens,
         &standard_pool_hash,
-        &Vec::from_array(&e, [1000, 1000]),
+        &Vec::from_array(&e, [2000, 2000]),
         &0,
     );
     router.deposit(
@@ -1401,8 +1403,8 @@ fn test_rewards_distribution_override() {
 
     let reward_1_tps = 10_5000000_u128;
 
-    token1.mint(&user1, &2000);
-    token2.mint(&user1, &2000);
+    token1.mint(&user1, &3000);
+    token2.mint(&user1, &3000);
 
     // 10 seconds passed since config, user depositing
     jump(&e, 10);
@@ -1410,7 +1412,7 @@ fn test_rewards_distribution_override() {
         &user1,
         &tokens,
         &standard_pool_hash,
-        &Vec::from_array(&e, [1000, 1000]),
+        &Vec::from_array(&e, [2000, 2000]),
         &0,
     );
     router.deposit(
@@ -1561,7 +1563,7 @@ fn test_liqidity_not_filled() {
         &user1,
         &tokens,
         &standard_pool_hash,
-        &Vec::from_array(&e, [1000, 1000]),
+        &Vec::from_array(&e, [2000, 2000]),
         &0,
     );
     let rewards = Vec::from_array(&e, [(tokens.clone(), 1_0000000)]);
@@ -1605,7 +1607,7 @@ fn test_fill_liqidity_reentrancy() {
         &user1,
         &tokens,
         &standard_pool_hash,
-        &Vec::from_array(&e, [1000, 1000]),
+        &Vec::from_array(&e, [2000, 2000]),
         &0,
     );
     let rewards = Vec::from_array(&e, [(tokens.clone(), 1_0000000)]);
@@ -1650,7 +1652,7 @@ fn test_config_pool_rewards_reentrancy() {
         &user1,
         &tokens,
         &standard_pool_hash,
-        &Vec::from_array(&e, [1000, 1000]),
+        &Vec::from_array(&e, [2000, 2000]),
         &0,
     );
     let rewards = Vec::from_array(&e, [(tokens.clone(), 1_0000000)]);
@@ -1695,7 +1697,7 @@ fn test_config_pool_rewards_after_new_global_config() {
         &user1,
         &tokens,
         &standard_pool_hash,
-        &Vec::from_array(&e, [1000, 1000]),
+        &Vec::from_array(&e, [2000, 2000]),
         &0,
     );
     let rewards = Vec::from_array(&e, [(tokens.clone(), 1_0000000)]);
@@ -1742,8 +1744,8 @@ fn test_config_pool_after_liquidity_fill() {
         &router.address,
     );
 
-    token1.mint(&user1, &2000);
-    token2.mint(&user1, &2000);
+    token1.mint(&user1, &4000);
+    token2.mint(&user1, &4000);
 
     let (standard_pool_1_hash, _standard_pool_1_address) =
         router.init_standard_pool(&user1, &tokens, &30);
@@ -1751,7 +1753,7 @@ fn test_config_pool_after_liquidity_fill() {
         &user1,
         &tokens,
         &standard_pool_1_hash,
-        &Vec::from_array(&e, [1000, 1000]),
+        &Vec::from_array(&e, [2000, 2000]),
         &0,
     );
 
@@ -1774,7 +1776,7 @@ fn test_config_pool_after_liquidity_fill() {
         &user1,
         &tokens,
         &standard_pool_2_hash,
-        &Vec::from_array(&e, [1000, 1000]),
+        &Vec::from_array(&e, [2000, 2000]),
         &0,
     );
     assert_eq!(
@@ -1814,7 +1816,7 @@ fn test_fill_liquidity_no_config() {
         &user1,
         &tokens,
         &standard_pool_hash,
-        &Vec::from_array(&e, [1000, 1000]),
+        &Vec::from_array(&e, [2000, 2000]),
         &0,
     );
     router.fill_liquidity(&tokens);
@@ -2083,20 +2085,20 @@ fn test_event_correct() {
     router.fill_liquidity(&tokens);
     router.config_pool_rewards(&tokens, &pool_hash);
 
-    token1.mint(&user1, &1000);
-    assert_eq!(token1.balance(&user1), 1000);
+    token1.mint(&user1, &3000);
+    assert_eq!(token1.balance(&user1), 3000);
 
-    token2.mint(&user1, &1000);
-    assert_eq!(token2.balance(&user1), 1000);
+    token2.mint(&user1, &3000);
+    assert_eq!(token2.balance(&user1), 3000);
 
     // 10 seconds passed since config, user depositing
     jump(&e, 10);
 
-    let desired_amounts = Vec::from_array(&e, [100, 100]);
+    let desired_amounts = Vec::from_array(&e, [2000, 2000]);
 
     let (amounts, share_amount) = router.deposit(&user1, &tokens, &pool_hash, &desired_amounts, &0);
     let deposit_event = e.events().all().last().unwrap();
-    assert_eq!(router.get_total_liquidity(&tokens), U256::from_u32(&e, 2));
+    assert_eq!(router.get_total_liquidity(&tokens), U256::from_u32(&e, 70));
 
     let pool_id = router.get_pool(&tokens, &pool_hash);
 
@@ -2146,8 +2148,8 @@ fn test_event_correct() {
         &user1,
         &tokens,
         &pool_hash,
-        &100_u128,
-        &Vec::from_array(&e, [197_u128, 51_u128]),
+        &1000_u128,
+        &Vec::from_array(&e, [1048_u128, 954_u128]),
     );
     let withdraw_event = e.events().all().last().unwrap();
 
@@ -2158,7 +2160,7 @@ fn test_event_correct() {
             (
                 router.address.clone(),
                 (Symbol::new(&e, "withdraw"), tokens.clone(), user1.clone()).into_val(&e),
-                (pool_id.clone(), 100_u128, amounts).into_val(&e)
+                (pool_id.clone(), 1000_u128, amounts).into_val(&e)
             ),
         ]
     );
@@ -2587,193 +2589,1083 @@ fn test_chained_swap_min_not_met() {
 }
 
 #[test]
-fn test_create_pool_payment() {
+fn test_find_best_path() {
     let setup = Setup::default();
     let e = setup.env;
     let router = setup.router;
     let admin = setup.admin;
-    let [token1, token2, _, _] = setup.tokens;
+    let [token1, token2, token3, _] = setup.tokens;
     let reward_token = setup.reward_token;
 
-    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+    let tokens1 = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+    let tokens2 = Vec::from_array(&e, [token2.address.clone(), token3.address.clone()]);
+    let tokens3 = Vec::from_array(&e, [token1.address.clone(), token3.address.clone()]);
 
-    let user1 = Address::generate(&e);
-    reward_token.mint(&user1, &10_0000000);
+    let swapper = Address::generate(&e);
+    reward_token.mint(&swapper, &10_0000000);
 
-    let payments_destination = router.get_init_pool_payment_address();
-    router.configure_init_pool_payment(
+    let (pool_index1, _pool_address1) = router.init_standard_pool(&swapper, &tokens1, &30);
+    let (pool_index2, _pool_address2) = router.init_standard_pool(&swapper, &tokens2, &30);
+    let (pool_index3, _pool_address3) = router.init_standard_pool(&swapper, &tokens3, &30);
+    // empty pool should be ignored
+    router.init_stableswap_pool(&swapper, &tokens3, &30);
+    token1.mint(&admin, &12000);
+    token2.mint(&admin, &20000);
+    token3.mint(&admin, &12000);
+    router.deposit(
         &admin,
-        &reward_token.address,
-        &100,
-        &1000,
-        &payments_destination,
+        &tokens1,
+        &pool_index1,
+        &Vec::from_array(&e, [10000, 10000]),
+        &0,
+    );
+    router.deposit(
+        &admin,
+        &tokens2,
+        &pool_index2,
+        &Vec::from_array(&e, [10000, 10000]),
+        &0,
+    );
+    // direct pool is too shallow, so the path through token 2 gives more
+    router.deposit(
+        &admin,
+        &tokens3,
+        &pool_index3,
+        &Vec::from_array(&e, [2000, 2000]),
+        &0,
     );
 
-    assert_eq!(reward_token.balance(&payments_destination), 0);
-    router.init_standard_pool(&user1, &tokens, &30);
-    assert_eq!(reward_token.balance(&payments_destination), 100);
-    router.init_stableswap_pool(&user1, &tokens, &30);
-    assert_eq!(reward_token.balance(&payments_destination), 1100);
+    let direct_path = vec![
+        &e,
+        (tokens3.clone(), pool_index3.clone(), token3.address.clone()),
+    ];
+    let chained_path = vec![
+        &e,
+        (tokens1.clone(), pool_index1.clone(), token2.address.clone()),
+        (tokens2.clone(), pool_index2.clone(), token3.address.clone()),
+    ];
+    assert_eq!(
+        router.find_best_path(&token1.address, &token3.address, &100, &1),
+        direct_path
+    );
+    assert_eq!(
+        router.find_best_path(&token1.address, &token3.address, &100, &3),
+        chained_path
+    );
+
+    // found path is accepted by swap_chained as is
+    token1.mint(&swapper, &100);
+    let path = router.find_best_path(&token1.address, &token3.address, &100, &3);
+    assert_eq!(
+        router.swap_chained(&swapper, &path, &token1.address, &100, &0),
+        96
+    );
+    assert_eq!(token3.balance(&swapper), 96);
 }
 
 #[test]
-fn test_rewards_distribution_without_outstanding_rewards() {
+fn test_find_best_path_killed_pool() {
     let setup = Setup::default();
     let e = setup.env;
     let router = setup.router;
     let admin = setup.admin;
-
-    let [token, _, _, _] = setup.tokens;
+    let [token1, token2, token3, _] = setup.tokens;
     let reward_token = setup.reward_token;
 
-    let tokens = Vec::from_array(&e, [token.address.clone(), reward_token.address.clone()]);
-    let user = Address::generate(&e);
-
-    reward_token.mint(&user, &200000_0000000);
-    reward_token.mint(&router.address, &20_000_000_0000000);
-
-    let (standard_pool_hash1, standard_pool_address1) =
-        router.init_standard_pool(&user, &tokens, &30);
-
-    let reward_tps = 1_5000000_u128;
+    let tokens1 = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+    let tokens2 = Vec::from_array(&e, [token2.address.clone(), token3.address.clone()]);
+    let tokens3 = Vec::from_array(&e, [token1.address.clone(), token3.address.clone()]);
 
-    token.mint(&user, &(i128::MAX / 100));
-    reward_token.mint(&user, &(i128::MAX / 100));
+    let swapper = Address::generate(&e);
+    reward_token.mint(&swapper, &10_0000000);
 
-    // 10 seconds passed since config, user depositing
-    jump(&e, 10);
+    let (pool_index1, _pool_address1) = router.init_standard_pool(&swapper, &tokens1, &30);
+    let (pool_index2, pool_address2) = router.init_standard_pool(&swapper, &tokens2, &30);
+    let (pool_index3, _pool_address3) = router.init_standard_pool(&swapper, &tokens3, &30);
+    token1.mint(&admin, &12000);
+    token2.mint(&admin, &20000);
+    token3.mint(&admin, &12000);
     router.deposit(
-        &user,
-        &tokens,
-        &standard_pool_hash1,
-        &Vec::from_array(&e, [30399483, 2420176738]),
+        &admin,
+        &tokens1,
+        &pool_index1,
+        &Vec::from_array(&e, [10000, 10000]),
         &0,
     );
-
-    reward_token.mint(&standard_pool_address1, &(3888205486 - 2420176738));
-    let rewards = Vec::from_array(&e, [(tokens.clone(), 1_0000000)]);
-    router.config_global_rewards(
+    router.deposit(
         &admin,
-        &reward_tps,
-        &e.ledger().timestamp().saturating_add(60),
-        &rewards,
+        &tokens2,
+        &pool_index2,
+        &Vec::from_array(&e, [10000, 10000]),
+        &0,
     );
-
-    router.fill_liquidity(&tokens);
-    router.config_pool_rewards(&tokens, &standard_pool_hash1);
-
-    // check that we don't need to add rewards to pool
-    assert_eq!(
-        router.get_total_outstanding_reward(&tokens, &standard_pool_hash1),
-        0
+    router.deposit(
+        &admin,
+        &tokens3,
+        &pool_index3,
+        &Vec::from_array(&e, [2000, 2000]),
+        &0,
     );
 
-    // check that it works without panicking
+    // chained path is better until its second pool is killed
+    testutils::standard_pool::Client::new(&e, &pool_address2).kill_swap(&admin);
     assert_eq!(
-        router.distribute_outstanding_reward(
-            &admin,
-            &router.address,
-            &tokens,
-            &standard_pool_hash1
-        ),
-        0
+        router.find_best_path(&token1.address, &token3.address, &100, &3),
+        vec![
+            &e,
+            (tokens3.clone(), pool_index3.clone(), token3.address.clone()),
+        ]
     );
 }
 
 #[test]
-fn test_privileged_users() {
+#[should_panic(expected = "Error(Contract, #319)")]
+fn test_find_best_path_too_many_hops() {
     let setup = Setup::default();
     let e = setup.env;
     let router = setup.router;
-
+    let admin = setup.admin;
     let [token1, token2, _, _] = setup.tokens;
     let reward_token = setup.reward_token;
 
     let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
 
-    let user1 = Address::generate(&e);
-    reward_token.mint(&user1, &10_0000000);
+    let swapper = Address::generate(&e);
+    reward_token.mint(&swapper, &10_0000000);
 
-    let (_, standard_address) = router.init_standard_pool(&user1, &tokens, &30);
-    let (_, stable_address) = router.init_stableswap_pool(&user1, &tokens, &30);
-    let privileged_addrs: Map<Symbol, Vec<Address>> = Map::from_array(
-        &e,
-        [
-            (Symbol::new(&e, "Admin"), Vec::from_array(&e, [setup.admin])),
-            (
-                Symbol::new(&e, "EmergencyAdmin"),
-                Vec::from_array(&e, [setup.emergency_admin]),
-            ),
-            (
-                Symbol::new(&e, "RewardsAdmin"),
-                Vec::from_array(&e, [setup.rewards_admin]),
-            ),
-            (
-                Symbol::new(&e, "OperationsAdmin"),
-                Vec::from_array(&e, [setup.operations_admin]),
-            ),
-            (
-                Symbol::new(&e, "PauseAdmin"),
-                Vec::from_array(&e, [setup.pause_admin]),
-            ),
-            (
-                Symbol::new(&e, "EmergencyPauseAdmin"),
-                Vec::from_array(&e, [setup.emergency_pause_admin]),
-            ),
-        ],
-    );
-    assert_eq!(privileged_addrs, router.get_privileged_addrs());
-    // test addresses inheritance
-    assert_eq!(
-        privileged_addrs,
-        testutils::standard_pool::Client::new(&e, &standard_address).get_privileged_addrs()
-    );
-    assert_eq!(
-        privileged_addrs,
-        testutils::stableswap_pool::Client::new(&e, &stable_address).get_privileged_addrs()
+    let (pool_index, _pool_address) = router.init_standard_pool(&swapper, &tokens, &30);
+    token1.mint(&admin, &10000);
+    token2.mint(&admin, &10000);
+    router.deposit(
+        &admin,
+        &tokens,
+        &pool_index,
+        &Vec::from_array(&e, [10000, 10000]),
+        &0,
     );
+
+    router.find_best_path(&token1.address, &token2.address, &100, &5);
 }
 
 #[test]
-fn test_set_privileged_addresses_event() {
+#[should_panic(expected = "Error(Contract, #317)")]
+fn test_find_best_path_not_found() {
     let setup = Setup::default();
+    let e = setup.env;
     let router = setup.router;
+    let admin = setup.admin;
+    let [token1, token2, token3, _] = setup.tokens;
+    let reward_token = setup.reward_token;
 
-    router.set_privileged_addrs(
-        &setup.admin.clone(),
-        &setup.rewards_admin.clone(),
-        &setup.operations_admin.clone(),
-        &setup.pause_admin.clone(),
-        &Vec::from_array(&setup.env, [setup.emergency_pause_admin.clone()]),
-    );
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
 
-    assert_eq!(
-        vec![&setup.env, setup.env.events().all().last().unwrap()],
-        vec![
-            &setup.env,
-            (
-                router.address.clone(),
-                (Symbol::new(&setup.env, "set_privileged_addrs"),).into_val(&setup.env),
-                (
-                    setup.rewards_admin,
-                    setup.operations_admin,
-                    setup.pause_admin,
-                    Vec::from_array(&setup.env, [setup.emergency_pause_admin]),
-                )
-                    .into_val(&setup.env),
-            ),
-        ]
+    let swapper = Address::generate(&e);
+    reward_token.mint(&swapper, &10_0000000);
+
+    let (pool_index, _pool_address) = router.init_standard_pool(&swapper, &tokens, &30);
+    token1.mint(&admin, &10000);
+    token2.mint(&admin, &10000);
+    router.deposit(
+        &admin,
+        &tokens,
+        &pool_index,
+        &Vec::from_array(&e, [10000, 10000]),
+        &0,
     );
+
+    router.find_best_path(&token1.address, &token3.address, &100, &3);
 }
 
 #[test]
-fn test_transfer_ownership_events() {
+fn test_swap_split() {
     let setup = Setup::default();
+    let e = setup.env;
     let router = setup.router;
-    let new_admin = Address::generate(&setup.env);
+    let admin = setup.admin;
+    let [token1, token2, _, _] = setup.tokens;
+    let reward_token = setup.reward_token;
 
-    router.commit_transfer_ownership(&setup.admin, &symbol_short!("Admin"), &new_admin);
-    assert_eq!(
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let swapper = Address::generate(&e);
+    reward_token.mint(&swapper, &10_0000000);
+
+    let (pool_index1, _pool_address1) = router.init_standard_pool(&swapper, &tokens, &10);
+    let (pool_index2, _pool_address2) = router.init_standard_pool(&swapper, &tokens, &30);
+    token1.mint(&admin, &20000);
+    token2.mint(&admin, &20000);
+    for pool_index in [&pool_index1, &pool_index2] {
+        router.deposit(
+            &admin,
+            &tokens,
+            pool_index,
+            &Vec::from_array(&e, [10000, 10000]),
+            &0,
+        );
+    }
+
+    // single pool gives less than the split between two pools
+    assert_eq!(
+        router.estimate_swap(
+            &tokens,
+            &token1.address,
+            &token2.address,
+            &pool_index1,
+            &2000
+        ),
+        1664
+    );
+    let legs = router.find_best_split(&tokens, &token1.address, &token2.address, &2000);
+    assert_eq!(legs.len(), 2);
+    assert!(legs.contains((tokens.clone(), pool_index1.clone(), 1000)));
+    assert!(legs.contains((tokens.clone(), pool_index2.clone(), 1000)));
+
+    token1.mint(&swapper, &2000);
+    assert_eq!(
+        router.swap_split(&swapper, &token1.address, &token2.address, &legs, &1814),
+        1814
+    );
+    assert_eq!(token1.balance(&swapper), 0);
+    assert_eq!(token2.balance(&swapper), 1814);
+    assert_eq!(token1.balance(&router.address), 0);
+    assert_eq!(token2.balance(&router.address), 0);
+    assert_eq!(
+        router.get_reserves(&tokens, &pool_index1),
+        Vec::from_array(&e, [11000, 10000 - 908])
+    );
+    assert_eq!(
+        router.get_reserves(&tokens, &pool_index2),
+        Vec::from_array(&e, [11000, 10000 - 906])
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2006)")]
+fn test_swap_split_min_not_met() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let router = setup.router;
+    let admin = setup.admin;
+    let [token1, token2, _, _] = setup.tokens;
+    let reward_token = setup.reward_token;
+
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let swapper = Address::generate(&e);
+    reward_token.mint(&swapper, &10_0000000);
+
+    let (pool_index1, _pool_address1) = router.init_standard_pool(&swapper, &tokens, &10);
+    let (pool_index2, _pool_address2) = router.init_standard_pool(&swapper, &tokens, &30);
+    token1.mint(&admin, &20000);
+    token2.mint(&admin, &20000);
+    for pool_index in [&pool_index1, &pool_index2] {
+        router.deposit(
+            &admin,
+            &tokens,
+            pool_index,
+            &Vec::from_array(&e, [10000, 10000]),
+            &0,
+        );
+    }
+
+    token1.mint(&swapper, &2000);
+    router.swap_split(
+        &swapper,
+        &token1.address,
+        &token2.address,
+        &vec![
+            &e,
+            (tokens.clone(), pool_index1.clone(), 1000),
+            (tokens.clone(), pool_index2.clone(), 1000),
+        ],
+        &1815,
+    );
+}
+
+#[test]
+fn test_find_best_split_killed_pool() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let router = setup.router;
+    let admin = setup.admin;
+    let [token1, token2, _, _] = setup.tokens;
+    let reward_token = setup.reward_token;
+
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let swapper = Address::generate(&e);
+    reward_token.mint(&swapper, &10_0000000);
+
+    let (pool_index1, _pool_address1) = router.init_standard_pool(&swapper, &tokens, &10);
+    let (pool_index2, pool_address2) = router.init_standard_pool(&swapper, &tokens, &30);
+    token1.mint(&admin, &20000);
+    token2.mint(&admin, &20000);
+    for pool_index in [&pool_index1, &pool_index2] {
+        router.deposit(
+            &admin,
+            &tokens,
+            pool_index,
+            &Vec::from_array(&e, [10000, 10000]),
+            &0,
+        );
+    }
+
+    // whole amount goes to the active pool
+    testutils::standard_pool::Client::new(&e, &pool_address2).kill_swap(&admin);
+    let legs = router.find_best_split(&tokens, &token1.address, &token2.address, &2000);
+    assert_eq!(legs, vec![&e, (tokens.clone(), pool_index1.clone(), 2000)]);
+
+    token1.mint(&swapper, &2000);
+    assert_eq!(
+        router.swap_split(&swapper, &token1.address, &token2.address, &legs, &0),
+        1664
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #320)")]
+fn test_swap_split_token_not_in_pool() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let router = setup.router;
+    let admin = setup.admin;
+    let [token1, token2, token3, _] = setup.tokens;
+    let reward_token = setup.reward_token;
+
+    let tokens1 = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+    let tokens2 = Vec::from_array(&e, [token1.address.clone(), token3.address.clone()]);
+
+    let swapper = Address::generate(&e);
+    reward_token.mint(&swapper, &10_0000000);
+
+    let (pool_index1, _pool_address1) = router.init_standard_pool(&swapper, &tokens1, &30);
+    let (pool_index2, _pool_address2) = router.init_standard_pool(&swapper, &tokens2, &30);
+    token1.mint(&admin, &20000);
+    token2.mint(&admin, &10000);
+    token3.mint(&admin, &10000);
+    router.deposit(
+        &admin,
+        &tokens1,
+        &pool_index1,
+        &Vec::from_array(&e, [10000, 10000]),
+        &0,
+    );
+    router.deposit(
+        &admin,
+        &tokens2,
+        &pool_index2,
+        &Vec::from_array(&e, [10000, 10000]),
+        &0,
+    );
+
+    // second leg pool doesn't contain the output token
+    token1.mint(&swapper, &2000);
+    router.swap_split(
+        &swapper,
+        &token1.address,
+        &token2.address,
+        &vec![
+            &e,
+            (tokens1.clone(), pool_index1.clone(), 1000),
+            (tokens2.clone(), pool_index2.clone(), 1000),
+        ],
+        &0,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2018)")]
+fn test_swap_split_zero_leg() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let router = setup.router;
+    let admin = setup.admin;
+    let [token1, token2, _, _] = setup.tokens;
+    let reward_token = setup.reward_token;
+
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let swapper = Address::generate(&e);
+    reward_token.mint(&swapper, &10_0000000);
+
+    let (pool_index1, _pool_address1) = router.init_standard_pool(&swapper, &tokens, &10);
+    let (pool_index2, _pool_address2) = router.init_standard_pool(&swapper, &tokens, &30);
+    token1.mint(&admin, &20000);
+    token2.mint(&admin, &20000);
+    for pool_index in [&pool_index1, &pool_index2] {
+        router.deposit(
+            &admin,
+            &tokens,
+            pool_index,
+            &Vec::from_array(&e, [10000, 10000]),
+            &0,
+        );
+    }
+
+    token1.mint(&swapper, &1000);
+    router.swap_split(
+        &swapper,
+        &token1.address,
+        &token2.address,
+        &vec![
+            &e,
+            (tokens.clone(), pool_index1.clone(), 1000),
+            (tokens.clone(), pool_index2.clone(), 0),
+        ],
+        &0,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #320)")]
+fn test_find_best_split_token_not_in_pool() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let router = setup.router;
+    let [token1, token2, token3, _] = setup.tokens;
+
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+    router.find_best_split(&tokens, &token1.address, &token3.address, &1000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2001)")]
+fn test_deposit_to_wrong_amounts_size() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let router = setup.router;
+    let [token1, token2, _, _] = setup.tokens;
+    let reward_token = setup.reward_token;
+
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    reward_token.mint(&user1, &10_0000000);
+
+    let (pool_index, _pool_address) = router.init_standard_pool(&user1, &tokens, &30);
+    token1.mint(&user1, &2000);
+    token2.mint(&user1, &2000);
+
+    router.deposit_to(
+        &user1,
+        &user2,
+        &tokens,
+        &pool_index,
+        &Vec::from_array(&e, [2000]),
+        &0,
+        &(e.ledger().timestamp() + 10),
+    );
+}
+
+#[test]
+fn test_operations_to_recipient() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let router = setup.router;
+    let [token1, token2, token3, _] = setup.tokens;
+    let reward_token = setup.reward_token;
+
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+    let tokens2 = Vec::from_array(&e, [token2.address.clone(), token3.address.clone()]);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let user3 = Address::generate(&e);
+    reward_token.mint(&user1, &10_0000000);
+
+    let (pool_index, pool_address) = router.init_standard_pool(&user1, &tokens, &30);
+    let (pool_index2, _pool_address2) = router.init_standard_pool(&user1, &tokens2, &30);
+    let token_share = test_token::Client::new(&e, &router.share_id(&tokens, &pool_index));
+    token1.mint(&user1, &3000);
+    token2.mint(&user1, &4000);
+    token3.mint(&user1, &2000);
+    let deadline = e.ledger().timestamp() + 10;
+
+    // deposit with unbalanced amounts, the rest is returned to the depositor
+    assert_eq!(
+        router.deposit_to(
+            &user1,
+            &user2,
+            &tokens,
+            &pool_index,
+            &Vec::from_array(&e, [2000, 3000]),
+            &0,
+            &deadline,
+        ),
+        (Vec::from_array(&e, [2000, 2000]), 1000)
+    );
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (Symbol::new(&e, "deposit_to"), tokens.clone(), user1.clone()).into_val(&e),
+                (
+                    user2.clone(),
+                    pool_address.clone(),
+                    Vec::from_array(&e, [2000_u128, 2000]),
+                    1000_u128
+                )
+                    .into_val(&e)
+            ),
+        ]
+    );
+    assert_eq!(token_share.balance(&user1), 0);
+    assert_eq!(token_share.balance(&user2), 1000);
+    assert_eq!(token_share.balance(&router.address), 0);
+    assert_eq!(token1.balance(&user1), 1000);
+    assert_eq!(token2.balance(&user1), 2000);
+    assert_eq!(token1.balance(&pool_address), 2000);
+    assert_eq!(token2.balance(&pool_address), 2000);
+    router.deposit(
+        &user1,
+        &tokens2,
+        &pool_index2,
+        &Vec::from_array(&e, [2000, 2000]),
+        &0,
+    );
+
+    assert_eq!(
+        router.swap_to(
+            &user1,
+            &user3,
+            &tokens,
+            &token1.address,
+            &token2.address,
+            &pool_index,
+            &100,
+            &94,
+            &deadline,
+        ),
+        94
+    );
+    assert_eq!(token1.balance(&user1), 900);
+    assert_eq!(token2.balance(&user3), 94);
+
+    assert_eq!(
+        router.swap_chained_to(
+            &user1,
+            &user3,
+            &vec![
+                &e,
+                (tokens.clone(), pool_index.clone(), token2.address.clone()),
+                (tokens2.clone(), pool_index2.clone(), token3.address.clone()),
+            ],
+            &token1.address,
+            &100,
+            &0,
+            &deadline,
+        ),
+        token3.balance(&user3) as u128
+    );
+    assert_eq!(token1.balance(&user1), 800);
+
+    let amounts = router.withdraw_to(
+        &user2,
+        &user3,
+        &tokens,
+        &pool_index,
+        &1000,
+        &Vec::from_array(&e, [0, 0]),
+        &deadline,
+    );
+    assert_eq!(token_share.balance(&user2), 0);
+    assert_eq!(token1.balance(&user2), 0);
+    assert_eq!(token2.balance(&user2), 0);
+    assert_eq!(token1.balance(&user3), amounts.get(0).unwrap() as i128);
+    assert_eq!(token2.balance(&user3), 94 + amounts.get(1).unwrap() as i128);
+    assert_eq!(token1.balance(&router.address), 0);
+    assert_eq!(token2.balance(&router.address), 0);
+    assert_eq!(token3.balance(&router.address), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #318)")]
+fn test_swap_to_deadline_expired() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let router = setup.router;
+    let [token1, token2, _, _] = setup.tokens;
+    let reward_token = setup.reward_token;
+
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+    let user1 = Address::generate(&e);
+    reward_token.mint(&user1, &10_0000000);
+
+    let (pool_index, _pool_address) = router.init_standard_pool(&user1, &tokens, &30);
+    token1.mint(&user1, &3000);
+    token2.mint(&user1, &2000);
+    router.deposit(
+        &user1,
+        &tokens,
+        &pool_index,
+        &Vec::from_array(&e, [2000, 2000]),
+        &0,
+    );
+
+    let deadline = e.ledger().timestamp() + 10;
+    jump(&e, 11);
+    router.swap_to(
+        &user1,
+        &user1,
+        &tokens,
+        &token1.address,
+        &token2.address,
+        &pool_index,
+        &100,
+        &0,
+        &deadline,
+    );
+}
+
+#[test]
+fn test_multicall() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let router = setup.router;
+    let [token1, token2, _, _] = setup.tokens;
+    let reward_token = setup.reward_token;
+
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+    let user1 = Address::generate(&e);
+    reward_token.mint(&user1, &10_0000000);
+
+    let (pool_index, pool_address) = router.init_standard_pool(&user1, &tokens, &30);
+    let token_share = test_token::Client::new(&e, &router.share_id(&tokens, &pool_index));
+    token1.mint(&user1, &3000);
+    token2.mint(&user1, &2000);
+
+    let calls = vec![
+        &e,
+        RouterCall::Deposit(
+            tokens.clone(),
+            pool_index.clone(),
+            Vec::from_array(&e, [2000, 2000]),
+            0,
+        ),
+        RouterCall::Swap(
+            tokens.clone(),
+            token1.address.clone(),
+            token2.address.clone(),
+            pool_index.clone(),
+            100,
+            94,
+        ),
+        RouterCall::Claim(tokens.clone(), pool_index.clone()),
+        RouterCall::Withdraw(
+            tokens.clone(),
+            pool_index.clone(),
+            500,
+            Vec::from_array(&e, [0, 0]),
+        ),
+        RouterCall::SwapChained(
+            vec![
+                &e,
+                (tokens.clone(), pool_index.clone(), token2.address.clone()),
+            ],
+            token1.address.clone(),
+            100,
+            0,
+        ),
+    ];
+    assert_eq!(
+        router.multicall(&user1, &calls),
+        vec![
+            &e,
+            RouterCallResult::Deposit(Vec::from_array(&e, [2000, 2000]), 1000),
+            RouterCallResult::Swap(94),
+            RouterCallResult::Claim(0),
+            RouterCallResult::Withdraw(Vec::from_array(&e, [525, 476])),
+            RouterCallResult::SwapChained(84),
+        ]
+    );
+    // single signature covers the whole batch
+    let auths = e.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, user1);
+    assert_eq!(
+        auths[0].1.function,
+        AuthorizedFunction::Contract((
+            router.address.clone(),
+            Symbol::new(&e, "multicall"),
+            (user1.clone(), calls.clone()).into_val(&e),
+        ))
+    );
+
+    assert_eq!(token_share.balance(&user1), 500);
+    assert_eq!(token1.balance(&user1), 1325);
+    assert_eq!(token2.balance(&user1), 654);
+    assert_eq!(token1.balance(&pool_address), 1675);
+    assert_eq!(token2.balance(&pool_address), 1346);
+}
+
+#[test]
+fn test_quote_chained() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let router = setup.router;
+    let [token1, token2, token3, token4] = setup.tokens;
+    let reward_token = setup.reward_token;
+
+    let tokens1 = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+    let tokens2 = Vec::from_array(&e, [token2.address.clone(), token3.address.clone()]);
+    let tokens3 = Vec::from_array(&e, [token3.address.clone(), token4.address.clone()]);
+    let user1 = Address::generate(&e);
+    reward_token.mint(&user1, &10_0000000);
+
+    let (pool_index1, pool_address1) = router.init_standard_pool(&user1, &tokens1, &30);
+    let (pool_index2, pool_address2) = router.init_standard_pool(&user1, &tokens2, &30);
+    token1.mint(&user1, &11000);
+    token2.mint(&user1, &20000);
+    token3.mint(&user1, &10000);
+    router.deposit(
+        &user1,
+        &tokens1,
+        &pool_index1,
+        &Vec::from_array(&e, [10000, 10000]),
+        &0,
+    );
+    router.deposit(
+        &user1,
+        &tokens2,
+        &pool_index2,
+        &Vec::from_array(&e, [10000, 10000]),
+        &0,
+    );
+
+    let swaps_chain = vec![
+        &e,
+        (tokens1.clone(), pool_index1.clone(), token2.address.clone()),
+        (tokens2.clone(), pool_index2.clone(), token3.address.clone()),
+    ];
+    let quote = router.quote_chained(&swaps_chain, &token1.address, &1000);
+    assert_eq!(
+        quote,
+        SwapQuote {
+            hops: vec![
+                &e,
+                SwapQuoteHop {
+                    pool: pool_address1,
+                    pool_type: Symbol::new(&e, "constant_product"),
+                    token_in: token1.address.clone(),
+                    token_out: token2.address.clone(),
+                    in_amount: 1000,
+                    out_amount: 906,
+                    fee_amount: 3,
+                    price_before: 1_000_000_000_000_000_000,
+                    price_after: 826_727_272_727_272_727,
+                },
+                SwapQuoteHop {
+                    pool: pool_address2,
+                    pool_type: Symbol::new(&e, "constant_product"),
+                    token_in: token2.address.clone(),
+                    token_out: token3.address.clone(),
+                    in_amount: 906,
+                    out_amount: 827,
+                    fee_amount: 3,
+                    price_before: 1_000_000_000_000_000_000,
+                    price_after: 841_096_644_049_147_258,
+                },
+            ],
+            out_amount: 827,
+            price_impact: 1730,
+        }
+    );
+
+    // quote matches the actual swap
+    assert_eq!(
+        router.swap_chained(&user1, &swaps_chain, &token1.address, &1000, &827),
+        827
+    );
+    assert_eq!(
+        router.get_reserves(&tokens1, &pool_index1),
+        Vec::from_array(&e, [11000, 9094])
+    );
+    assert_eq!(
+        router.get_reserves(&tokens2, &pool_index2),
+        Vec::from_array(&e, [10906, 9173])
+    );
+
+    // stableswap hop is priced by the invariant curve rather than by the reserves ratio
+    let (pool_index3, pool_address3) = router.init_stableswap_pool(&user1, &tokens3, &30);
+    token3.mint(&user1, &10000);
+    token4.mint(&user1, &10000);
+    router.deposit(
+        &user1,
+        &tokens3,
+        &pool_index3,
+        &Vec::from_array(&e, [10000, 10000]),
+        &0,
+    );
+
+    let swaps_chain = vec![
+        &e,
+        (tokens2.clone(), pool_index2.clone(), token3.address.clone()),
+        (tokens3.clone(), pool_index3.clone(), token4.address.clone()),
+    ];
+    let quote = router.quote_chained(&swaps_chain, &token2.address, &1000);
+    let stable_hop = quote.hops.get(1).unwrap();
+    assert_eq!(stable_hop.pool, pool_address3);
+    assert_eq!(stable_hop.pool_type, Symbol::new(&e, "stable"));
+    assert_eq!(stable_hop.token_in, token3.address);
+    assert_eq!(stable_hop.token_out, token4.address);
+    assert_eq!(stable_hop.in_amount, quote.hops.get(0).unwrap().out_amount);
+    assert_eq!(stable_hop.out_amount, quote.out_amount);
+    // balanced pool
+    assert_eq!(stable_hop.price_before, 1_000_000_000_000_000_000);
+    // the curve is flat around the balance, so the price moves much less than the reserves ratio
+    let reserves_ratio_after = (10000 - stable_hop.out_amount) * 1_000_000_000_000_000_000
+        / (10000 + stable_hop.in_amount);
+    assert!(stable_hop.price_after < stable_hop.price_before);
+    assert!(stable_hop.price_after > reserves_ratio_after);
+
+    // quote matches the actual swap
+    token2.mint(&user1, &1000);
+    assert_eq!(
+        router.swap_chained(
+            &user1,
+            &swaps_chain,
+            &token2.address,
+            &1000,
+            &quote.out_amount
+        ),
+        quote.out_amount
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #320)")]
+fn test_quote_chained_token_not_in_pool() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let router = setup.router;
+    let [token1, token2, token3, _] = setup.tokens;
+    let reward_token = setup.reward_token;
+
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+    let user1 = Address::generate(&e);
+    reward_token.mint(&user1, &10_0000000);
+
+    let (pool_index, _pool_address) = router.init_standard_pool(&user1, &tokens, &30);
+    token1.mint(&user1, &10000);
+    token2.mint(&user1, &10000);
+    router.deposit(
+        &user1,
+        &tokens,
+        &pool_index,
+        &Vec::from_array(&e, [10000, 10000]),
+        &0,
+    );
+
+    router.quote_chained(
+        &vec![
+            &e,
+            (tokens.clone(), pool_index.clone(), token3.address.clone()),
+        ],
+        &token1.address,
+        &1000,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #307)")]
+fn test_quote_chained_empty_path() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let router = setup.router;
+    let [token1, _, _, _] = setup.tokens;
+
+    router.quote_chained(&Vec::new(&e), &token1.address, &1000);
+}
+
+#[test]
+fn test_create_pool_payment() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let router = setup.router;
+    let admin = setup.admin;
+    let [token1, token2, _, _] = setup.tokens;
+    let reward_token = setup.reward_token;
+
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let user1 = Address::generate(&e);
+    reward_token.mint(&user1, &10_0000000);
+
+    let payments_destination = router.get_init_pool_payment_address();
+    router.configure_init_pool_payment(
+        &admin,
+        &reward_token.address,
+        &100,
+        &1000,
+        &payments_destination,
+    );
+
+    assert_eq!(reward_token.balance(&payments_destination), 0);
+    router.init_standard_pool(&user1, &tokens, &30);
+    assert_eq!(reward_token.balance(&payments_destination), 100);
+    router.init_stableswap_pool(&user1, &tokens, &30);
+    assert_eq!(reward_token.balance(&payments_destination), 1100);
+}
+
+#[test]
+fn test_rewards_distribution_without_outstanding_rewards() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let router = setup.router;
+    let admin = setup.admin;
+
+    let [token, _, _, _] = setup.tokens;
+    let reward_token = setup.reward_token;
+
+    let tokens = Vec::from_array(&e, [token.address.clone(), reward_token.address.clone()]);
+    let user = Address::generate(&e);
+
+    reward_token.mint(&user, &200000_0000000);
+    reward_token.mint(&router.address, &20_000_000_0000000);
+
+    let (standard_pool_hash1, standard_pool_address1) =
+        router.init_standard_pool(&user, &tokens, &30);
+
+    let reward_tps = 1_5000000_u128;
+
+    token.mint(&user, &(i128::MAX / 100));
+    reward_token.mint(&user, &(i128::MAX / 100));
+
+    // 10 seconds passed since config, user depositing
+    jump(&e, 10);
+    router.deposit(
+        &user,
+        &tokens,
+        &standard_pool_hash1,
+        &Vec::from_array(&e, [30399483, 2420176738]),
+        &0,
+    );
+
+    reward_token.mint(&standard_pool_address1, &(3888205486 - 2420176738));
+    let rewards = Vec::from_array(&e, [(tokens.clone(), 1_0000000)]);
+    router.config_global_rewards(
+        &admin,
+        &reward_tps,
+        &e.ledger().timestamp().saturating_add(60),
+        &rewards,
+    );
+
+    router.fill_liquidity(&tokens);
+    router.config_pool_rewards(&tokens, &standard_pool_hash1);
+
+    // check that we don't need to add rewards to pool
+    assert_eq!(
+        router.get_total_outstanding_reward(&tokens, &standard_pool_hash1),
+        0
+    );
+
+    // check that it works without panicking
+    assert_eq!(
+        router.distribute_outstanding_reward(
+            &admin,
+            &router.address,
+            &tokens,
+            &standard_pool_hash1
+        ),
+        0
+    );
+}
+
+#[test]
+fn test_privileged_users() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let router = setup.router;
+
+    let [token1, token2, _, _] = setup.tokens;
+    let reward_token = setup.reward_token;
+
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+
+    let user1 = Address::generate(&e);
+    reward_token.mint(&user1, &10_0000000);
+
+    let (_, standard_address) = router.init_standard_pool(&user1, &tokens, &30);
+    let (_, stable_address) = router.init_stableswap_pool(&user1, &tokens, &30);
+    let privileged_addrs: Map<Symbol, Vec<Address>> = Map::from_array(
+        &e,
+        [
+            (Symbol::new(&e, "Admin"), Vec::from_array(&e, [setup.admin])),
+            (
+                Symbol::new(&e, "EmergencyAdmin"),
+                Vec::from_array(&e, [setup.emergency_admin]),
+            ),
+            (
+                Symbol::new(&e, "RewardsAdmin"),
+                Vec::from_array(&e, [setup.rewards_admin]),
+            ),
+            (
+                Symbol::new(&e, "OperationsAdmin"),
+                Vec::from_array(&e, [setup.operations_admin]),
+            ),
+            (
+                Symbol::new(&e, "PauseAdmin"),
+                Vec::from_array(&e, [setup.pause_admin]),
+            ),
+            (
+                Symbol::new(&e, "EmergencyPauseAdmin"),
+                Vec::from_array(&e, [setup.emergency_pause_admin]),
+            ),
+        ],
+    );
+    assert_eq!(privileged_addrs, router.get_privileged_addrs());
+    // test addresses inheritance
+    assert_eq!(
+        privileged_addrs,
+        testutils::standard_pool::Client::new(&e, &standard_address).get_privileged_addrs()
+    );
+    assert_eq!(
+        privileged_addrs,
+        testutils::stableswap_pool::Client::new(&e, &stable_address).get_privileged_addrs()
+    );
+}
+
+#[test]
+fn test_set_privileged_addresses_event() {
+    let setup = Setup::default();
+    let router = setup.router;
+
+    router.set_privileged_addrs(
+        &setup.admin.clone(),
+        &setup.rewards_admin.clone(),
+        &setup.operations_admin.clone(),
+        &setup.pause_admin.clone(),
+        &Vec::from_array(&setup.env, [setup.emergency_pause_admin.clone()]),
+    );
+
+    assert_eq!(
+        vec![&setup.env, setup.env.events().all().last().unwrap()],
+        vec![
+            &setup.env,
+            (
+                router.address.clone(),
+                (Symbol::new(&setup.env, "set_privileged_addrs"),).into_val(&setup.env),
+                (
+                    setup.rewards_admin,
+                    setup.operations_admin,
+                    setup.pause_admin,
+                    Vec::from_array(&setup.env, [setup.emergency_pause_admin]),
+                )
+                    .into_val(&setup.env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_transfer_ownership_events() {
+    let setup = Setup::default();
+    let router = setup.router;
+    let new_admin = Address::generate(&setup.env);
+
+    router.commit_transfer_ownership(&setup.admin, &symbol_short!("Admin"), &new_admin);
+    assert_eq!(
         vec![&setup.env, setup.env.events().all().last().unwrap()],
         vec![
             &setup.env,
@@ -2937,3 +3829,240 @@ fn test_regular_upgrade() {
 
     assert_eq!(contract.version(), 130)
 }
+
+#[test]
+fn test_pool_protocol_fee() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let router = setup.router;
+    let [token1, token2, _, _] = setup.tokens;
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+    let user1 = Address::generate(&e);
+    let fees_collector = Address::generate(&e);
+    setup.reward_token.mint(&user1, &10_0000000);
+
+    let (pool_hash, pool_address) = router.init_standard_pool(&user1, &tokens, &30);
+    let pool = testutils::standard_pool::Client::new(&e, &pool_address);
+
+    router.commit_pool_protocol_fee(&setup.admin, &tokens, &pool_hash, &5000);
+    jump(&e, ADMIN_ACTIONS_DELAY + 1);
+    router.apply_pool_protocol_fee(&setup.admin, &tokens, &pool_hash);
+    router.set_pool_fees_collector(&setup.admin, &tokens, &pool_hash, &fees_collector);
+    assert_eq!(pool.get_protocol_fee_fraction(), 5000);
+    assert_eq!(pool.get_fees_collector(), fees_collector);
+
+    token1.mint(&user1, &200_0000000);
+    token2.mint(&user1, &200_0000000);
+    router.deposit(
+        &user1,
+        &tokens,
+        &pool_hash,
+        &Vec::from_array(&e, [100_0000000, 100_0000000]),
+        &0,
+    );
+    assert_eq!(
+        router.swap(
+            &user1,
+            &tokens,
+            &token2.address,
+            &token1.address,
+            &pool_hash,
+            &1_0000000,
+            &0,
+        ),
+        9871287
+    );
+
+    // protocol share is excluded from the reserves
+    assert_eq!(
+        router.get_reserves(&tokens, &pool_hash),
+        Vec::from_array(&e, [100_0000000 - 9871287 - 14851, 101_0000000])
+    );
+    assert_eq!(pool.claim_protocol_fees(), Vec::from_array(&e, [14851, 0]));
+    assert_eq!(token1.balance(&fees_collector), 14851);
+}
+
+#[test]
+fn test_update_pool_fee() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let router = setup.router;
+    let [token1, token2, _, _] = setup.tokens;
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+    let user1 = Address::generate(&e);
+    setup.reward_token.mint(&user1, &10_0000000);
+
+    let (pool_hash, pool_address) = router.init_standard_pool(&user1, &tokens, &30);
+    let (pool_hash_10, _) = router.init_standard_pool(&user1, &tokens, &10);
+
+    // fee tier already taken by another pool
+    assert!(router
+        .try_commit_pool_fee(&setup.admin, &tokens, &pool_hash, &10)
+        .is_err());
+    // fee tier not available
+    assert!(router
+        .try_commit_pool_fee(&setup.admin, &tokens, &pool_hash, &50)
+        .is_err());
+
+    router.commit_pool_fee(&setup.admin, &tokens, &pool_hash, &100);
+    jump(&e, ADMIN_ACTIONS_DELAY + 1);
+    let new_pool_hash = router.apply_pool_fee(&setup.admin, &tokens, &pool_hash);
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (Symbol::new(&e, "update_pool_index"), tokens.clone()).into_val(&e),
+                (
+                    pool_address.clone(),
+                    pool_hash.clone(),
+                    new_pool_hash.clone()
+                )
+                    .into_val(&e),
+            ),
+        ]
+    );
+
+    // pool is available under the index of the new fee tier
+    let pools = router.get_pools(&tokens);
+    assert_eq!(pools.len(), 2);
+    assert!(!pools.contains_key(pool_hash.clone()));
+    assert!(pools.contains_key(pool_hash_10));
+    assert_eq!(pools.get(new_pool_hash.clone()).unwrap(), pool_address);
+    assert_eq!(
+        router.init_standard_pool(&user1, &tokens, &100),
+        (new_pool_hash.clone(), pool_address.clone())
+    );
+    let pool_info = router.get_info(&tokens, &new_pool_hash);
+    assert_eq!(
+        u32::from_val(&e, &pool_info.get(Symbol::new(&e, "fee")).unwrap()),
+        100
+    );
+
+    // old fee tier is free again
+    let (pool_hash_30, pool_address_30) = router.init_standard_pool(&user1, &tokens, &30);
+    assert_eq!(pool_hash_30, pool_hash);
+    assert_ne!(pool_address_30, pool_address);
+}
+
+#[test]
+fn test_update_pool_fee_rewards() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let router = setup.router;
+    let [token1, token2, _, _] = setup.tokens;
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+    let user1 = Address::generate(&e);
+    setup.reward_token.mint(&user1, &10_0000000);
+    setup.reward_token.mint(&router.address, &2_000_000_0000000);
+
+    let (pool_hash, _) = router.init_standard_pool(&user1, &tokens, &30);
+    token1.mint(&user1, &3000);
+    token2.mint(&user1, &3000);
+    router.deposit(
+        &user1,
+        &tokens,
+        &pool_hash,
+        &Vec::from_array(&e, [2000, 2000]),
+        &0,
+    );
+    router.commit_pool_fee(&setup.admin, &tokens, &pool_hash, &100);
+    jump(&e, ADMIN_ACTIONS_DELAY + 1);
+
+    // liquidity is filled under the old index, then the pool is moved
+    router.config_global_rewards(
+        &setup.admin,
+        &10_5000000,
+        &e.ledger().timestamp().saturating_add(60),
+        &Vec::from_array(&e, [(tokens.clone(), 1_0000000)]),
+    );
+    router.fill_liquidity(&tokens);
+    let new_pool_hash = router.apply_pool_fee(&setup.admin, &tokens, &pool_hash);
+    assert_ne!(new_pool_hash, pool_hash);
+
+    // the only pool gets the whole reward
+    assert_eq!(
+        router.config_pool_rewards(&tokens, &new_pool_hash),
+        10_5000000
+    );
+    assert!(router
+        .try_config_pool_rewards(&tokens, &new_pool_hash)
+        .is_err());
+}
+
+#[test]
+fn test_withdraw_one_coin() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let router = setup.router;
+    let [token1, token2, _, _] = setup.tokens;
+    let tokens = Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]);
+    let user1 = Address::generate(&e);
+    setup.reward_token.mint(&user1, &10_0000000);
+    token1.mint(&user1, &200_0000000);
+    token2.mint(&user1, &200_0000000);
+
+    let (standard_hash, standard_address) = router.init_standard_pool(&user1, &tokens, &30);
+    let (stable_hash, _stable_address) = router.init_stableswap_pool(&user1, &tokens, &30);
+    for pool_hash in [&standard_hash, &stable_hash] {
+        router.deposit(
+            &user1,
+            &tokens,
+            pool_hash,
+            &Vec::from_array(&e, [100_0000000, 100_0000000]),
+            &0,
+        );
+    }
+
+    assert_eq!(
+        router.calc_withdraw_one_coin(&tokens, &standard_hash, &10_0000000, &token1.address),
+        189730000
+    );
+    assert_eq!(
+        router.withdraw_one_coin(
+            &user1,
+            &tokens,
+            &standard_hash,
+            &10_0000000,
+            &token1.address,
+            &189730000,
+        ),
+        Vec::from_array(&e, [189730000, 0])
+    );
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                router.address.clone(),
+                (Symbol::new(&e, "withdraw"), tokens.clone(), user1.clone()).into_val(&e),
+                (
+                    standard_address,
+                    10_0000000_u128,
+                    Vec::from_array(&e, [189730000_u128, 0])
+                )
+                    .into_val(&e),
+            ),
+        ]
+    );
+    assert_eq!(
+        router.get_reserves(&tokens, &standard_hash),
+        Vec::from_array(&e, [81_0270000, 100_0000000])
+    );
+
+    let amount = router.calc_withdraw_one_coin(&tokens, &stable_hash, &10_0000000, &token2.address);
+    let balance = token2.balance(&user1);
+    assert_eq!(
+        router.withdraw_one_coin(
+            &user1,
+            &tokens,
+            &stable_hash,
+            &10_0000000,
+            &token2.address,
+            &amount,
+        ),
+        Vec::from_array(&e, [0, amount])
+    );
+    assert_eq!(token2.balance(&user1), balance + amount as i128);
+}
@@ -1,11 +1,19 @@
 #![cfg(test)]
+extern crate std;
 
 use crate::testutils::{
     install_liq_pool_hash, install_stableswap_liq_pool_hash, install_token_wasm, Setup,
 };
-use access_control::constants::ADMIN_ACTIONS_DELAY;
-use soroban_sdk::testutils::Address as _;
-use soroban_sdk::{symbol_short, Address, Symbol, Vec};
+use access_control::audit::{AuditAction, AuditRecord};
+use access_control::constants::{
+    ADMIN_ACTIONS_DELAY, AUDIT_LOG_SIZE, EMERGENCY_MODE_MAX_DURATION, EMERGENCY_MODE_VOTE_LIFETIME,
+    EMERGENCY_VOTER_ROLE, MAX_ADMIN_ACTIONS_DELAY, MIN_ADMIN_ACTIONS_DELAY,
+};
+use access_control::delays::DelayTarget;
+use soroban_sdk::testutils::{
+    Address as _, AuthorizedFunction, AuthorizedInvocation, Events, Ledger,
+};
+use soroban_sdk::{symbol_short, vec, Address, IntoVal, Symbol, Vec};
 use utils::test_utils::{install_dummy_wasm, jump};
 
 // test admin transfer ownership
@@ -85,6 +93,78 @@ fn test_admin_transfer_ownership() {
     router.commit_transfer_ownership(&admin_new, &symbol_short!("Admin"), &admin_new);
 }
 
+#[test]
+#[should_panic(expected = "Error(Contract, #2908)")]
+fn test_admin_accept_ownership_too_early() {
+    let setup = Setup::default();
+    let router = setup.router;
+    let admin_original = setup.admin;
+    let admin_new = Address::generate(&setup.env);
+
+    router.commit_transfer_ownership(&admin_original, &symbol_short!("Admin"), &admin_new);
+    jump(&setup.env, ADMIN_ACTIONS_DELAY - 1);
+    router.accept_ownership(&admin_new, &symbol_short!("Admin"));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2909)")]
+fn test_admin_accept_ownership_wrong_address() {
+    let setup = Setup::default();
+    let router = setup.router;
+    let admin_original = setup.admin;
+    let admin_new = Address::generate(&setup.env);
+
+    router.commit_transfer_ownership(&admin_original, &symbol_short!("Admin"), &admin_new);
+    jump(&setup.env, ADMIN_ACTIONS_DELAY + 1);
+    router.accept_ownership(&admin_original, &symbol_short!("Admin"));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2907)")]
+fn test_admin_accept_ownership_reverted() {
+    let setup = Setup::default();
+    let router = setup.router;
+    let admin_original = setup.admin;
+    let admin_new = Address::generate(&setup.env);
+
+    router.commit_transfer_ownership(&admin_original, &symbol_short!("Admin"), &admin_new);
+    jump(&setup.env, ADMIN_ACTIONS_DELAY + 1);
+    router.revert_transfer_ownership(&admin_original, &symbol_short!("Admin"));
+    router.accept_ownership(&admin_new, &symbol_short!("Admin"));
+}
+
+#[test]
+fn test_admin_accept_ownership() {
+    let setup = Setup::default();
+    let router = setup.router;
+    let admin_original = setup.admin;
+    let admin_new = Address::generate(&setup.env);
+
+    router.commit_transfer_ownership(&admin_original, &symbol_short!("Admin"), &admin_new);
+    jump(&setup.env, ADMIN_ACTIONS_DELAY + 1);
+    router.accept_ownership(&admin_new, &symbol_short!("Admin"));
+    assert_eq!(
+        setup.env.auths()[0],
+        (
+            admin_new.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    router.address.clone(),
+                    Symbol::new(&setup.env, "accept_ownership"),
+                    (admin_new.clone(), symbol_short!("Admin")).into_val(&setup.env),
+                )),
+                sub_invocations: std::vec![],
+            }
+        )
+    );
+
+    // new admin is capable to call protected methods, original one is not
+    assert!(router
+        .try_commit_transfer_ownership(&admin_original, &symbol_short!("Admin"), &admin_original)
+        .is_err());
+    router.commit_transfer_ownership(&admin_new, &symbol_short!("Admin"), &admin_original);
+}
+
 // test emergency admin transfer ownership
 #[test]
 #[should_panic(expected = "Error(Contract, #2908)")]
@@ -268,6 +348,280 @@ fn test_transfer_ownership_separate_deadlines() {
         .is_err());
 }
 
+// test delayed transfer of privileged roles
+#[test]
+#[should_panic(expected = "Error(Contract, #104)")]
+fn test_rewards_admin_transfer_not_delayed() {
+    let setup = Setup::default();
+    let router = setup.router;
+    let rewards_admin_new = Address::generate(&setup.env);
+
+    router.commit_transfer_ownership(
+        &setup.admin,
+        &Symbol::new(&setup.env, "RewardsAdmin"),
+        &rewards_admin_new,
+    );
+}
+
+#[test]
+fn test_enable_transfer_delay() {
+    let setup = Setup::default();
+    let router = setup.router;
+    let user = Address::generate(&setup.env);
+    let role_name = Symbol::new(&setup.env, "OperationsAdmin");
+
+    assert!(!router.get_transfer_delay_enabled(&role_name));
+    assert!(router.get_transfer_delay_enabled(&symbol_short!("Admin")));
+    for (addr, is_ok) in [
+        (user, false),
+        (setup.emergency_admin, false),
+        (setup.rewards_admin, false),
+        (setup.operations_admin, false),
+        (setup.pause_admin, false),
+        (setup.emergency_pause_admin, false),
+        (setup.admin.clone(), true),
+    ] {
+        assert_eq!(
+            router.try_enable_transfer_delay(&addr, &role_name).is_ok(),
+            is_ok
+        );
+    }
+    assert!(router.get_transfer_delay_enabled(&role_name));
+
+    // owner roles are always delayed
+    assert!(router
+        .try_enable_transfer_delay(&setup.admin, &symbol_short!("Admin"))
+        .is_err());
+}
+
+#[test]
+fn test_rewards_admin_delayed_transfer() {
+    let setup = Setup::default();
+    let router = setup.router;
+    let rewards_admin_new = Address::generate(&setup.env);
+    let role_name = Symbol::new(&setup.env, "RewardsAdmin");
+    let emergency_pause_admins = Vec::from_array(&setup.env, [setup.emergency_pause_admin.clone()]);
+
+    router.enable_transfer_delay(&setup.admin, &role_name);
+
+    // instant replacement is not allowed anymore, re-setting same value is fine
+    assert!(router
+        .try_set_privileged_addrs(
+            &setup.admin,
+            &rewards_admin_new,
+            &setup.operations_admin,
+            &setup.pause_admin,
+            &emergency_pause_admins,
+        )
+        .is_err());
+    router.set_privileged_addrs(
+        &setup.admin,
+        &setup.rewards_admin,
+        &setup.operations_admin,
+        &setup.pause_admin,
+        &emergency_pause_admins,
+    );
+
+    router.commit_transfer_ownership(&setup.admin, &role_name, &rewards_admin_new);
+    assert_eq!(router.get_future_address(&role_name), rewards_admin_new);
+    jump(&setup.env, ADMIN_ACTIONS_DELAY - 1);
+    assert!(router
+        .try_apply_transfer_ownership(&setup.admin, &role_name)
+        .is_err());
+    jump(&setup.env, 2);
+    router.apply_transfer_ownership(&setup.admin, &role_name);
+
+    assert_eq!(
+        router.get_privileged_addrs().get(role_name).unwrap(),
+        Vec::from_array(&setup.env, [rewards_admin_new])
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2907)")]
+fn test_emergency_pause_admins_delayed_transfer_reverted() {
+    let setup = Setup::default();
+    let router = setup.router;
+    let role_name = Symbol::new(&setup.env, "EmergencyPauseAdmin");
+    let new_addresses = Vec::from_array(&setup.env, [Address::generate(&setup.env)]);
+
+    router.enable_transfer_delay(&setup.admin, &role_name);
+    router.commit_transfer_addresses(&setup.admin, &role_name, &new_addresses);
+    jump(&setup.env, ADMIN_ACTIONS_DELAY + 1);
+    router.revert_transfer_ownership(&setup.admin, &role_name);
+    router.apply_transfer_addresses(&setup.admin, &role_name);
+}
+
+#[test]
+fn test_emergency_pause_admins_delayed_transfer() {
+    let setup = Setup::default();
+    let router = setup.router;
+    let role_name = Symbol::new(&setup.env, "EmergencyPauseAdmin");
+    let current_addresses = Vec::from_array(&setup.env, [setup.emergency_pause_admin.clone()]);
+    let new_addresses = Vec::from_array(
+        &setup.env,
+        [Address::generate(&setup.env), Address::generate(&setup.env)],
+    );
+
+    // change is instant without opt-in delay
+    assert!(router
+        .try_commit_transfer_addresses(&setup.admin, &role_name, &new_addresses)
+        .is_err());
+
+    router.enable_transfer_delay(&setup.admin, &role_name);
+    assert!(router
+        .try_set_privileged_addrs(
+            &setup.admin,
+            &setup.rewards_admin,
+            &setup.operations_admin,
+            &setup.pause_admin,
+            &new_addresses,
+        )
+        .is_err());
+
+    assert_eq!(router.get_future_addresses(&role_name), current_addresses);
+    router.commit_transfer_addresses(&setup.admin, &role_name, &new_addresses);
+    assert_eq!(router.get_future_addresses(&role_name), new_addresses);
+    assert!(router
+        .try_commit_transfer_addresses(&setup.admin, &role_name, &current_addresses)
+        .is_err());
+
+    jump(&setup.env, ADMIN_ACTIONS_DELAY - 1);
+    assert!(router
+        .try_apply_transfer_addresses(&setup.admin, &role_name)
+        .is_err());
+    jump(&setup.env, 2);
+    router.apply_transfer_addresses(&setup.admin, &role_name);
+
+    assert_eq!(
+        router.get_privileged_addrs().get(role_name).unwrap(),
+        new_addresses
+    );
+}
+
+// test roles declared at runtime
+#[test]
+fn test_declare_role() {
+    let setup = Setup::default();
+    let router = setup.router;
+    let user = Address::generate(&setup.env);
+    let role_name = Symbol::new(&setup.env, "KeeperRole");
+
+    for (addr, is_ok) in [
+        (user, false),
+        (setup.emergency_admin, false),
+        (setup.rewards_admin, false),
+        (setup.operations_admin, false),
+        (setup.pause_admin, false),
+        (setup.emergency_pause_admin, false),
+        (setup.admin.clone(), true),
+    ] {
+        assert_eq!(
+            router
+                .try_declare_role(&addr, &role_name, &true, &false)
+                .is_ok(),
+            is_ok
+        );
+    }
+    assert_eq!(
+        router.get_declared_roles(),
+        Vec::from_array(&setup.env, [role_name.clone()])
+    );
+
+    let keepers = Vec::from_array(
+        &setup.env,
+        [Address::generate(&setup.env), Address::generate(&setup.env)],
+    );
+    assert_eq!(router.get_role_addrs(&role_name), Vec::new(&setup.env));
+    router.set_role_addrs(&setup.admin, &role_name, &keepers);
+    assert_eq!(router.get_role_addrs(&role_name), keepers);
+    assert_eq!(
+        router.get_privileged_addrs().get(role_name).unwrap(),
+        keepers
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #110)")]
+fn test_declare_role_twice() {
+    let setup = Setup::default();
+    let router = setup.router;
+    let role_name = Symbol::new(&setup.env, "FeeAdmin");
+
+    router.declare_role(&setup.admin, &role_name, &false, &false);
+    router.declare_role(&setup.admin, &role_name, &true, &false);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #104)")]
+fn test_declare_builtin_role() {
+    let setup = Setup::default();
+    let router = setup.router;
+
+    router.declare_role(
+        &setup.admin,
+        &Symbol::new(&setup.env, "RewardsAdmin"),
+        &false,
+        &true,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #104)")]
+fn test_set_role_addrs_undeclared() {
+    let setup = Setup::default();
+    let router = setup.router;
+
+    router.set_role_addrs(
+        &setup.admin,
+        &Symbol::new(&setup.env, "FeeAdmin"),
+        &Vec::from_array(&setup.env, [Address::generate(&setup.env)]),
+    );
+}
+
+#[test]
+fn test_declared_role_delayed_transfer() {
+    let setup = Setup::default();
+    let router = setup.router;
+    let role_name = Symbol::new(&setup.env, "FeeAdmin");
+    let fee_admin = Address::generate(&setup.env);
+    let fee_admin_new = Address::generate(&setup.env);
+
+    router.declare_role(&setup.admin, &role_name, &false, &true);
+
+    // single address role expects exactly one address
+    assert!(router
+        .try_set_role_addrs(
+            &setup.admin,
+            &role_name,
+            &Vec::from_array(&setup.env, [fee_admin.clone(), fee_admin_new.clone()]),
+        )
+        .is_err());
+
+    // first assignment is instant, replacement requires delay
+    router.set_role_addrs(
+        &setup.admin,
+        &role_name,
+        &Vec::from_array(&setup.env, [fee_admin.clone()]),
+    );
+    assert!(router
+        .try_set_role_addrs(
+            &setup.admin,
+            &role_name,
+            &Vec::from_array(&setup.env, [fee_admin_new.clone()]),
+        )
+        .is_err());
+
+    router.commit_transfer_ownership(&setup.admin, &role_name, &fee_admin_new);
+    assert_eq!(router.get_future_address(&role_name), fee_admin_new);
+    jump(&setup.env, ADMIN_ACTIONS_DELAY + 1);
+    router.apply_transfer_ownership(&setup.admin, &role_name);
+    assert_eq!(
+        router.get_role_addrs(&role_name),
+        Vec::from_array(&setup.env, [fee_admin_new])
+    );
+}
+
 // test all the authorized methods
 #[test]
 fn test_set_pools_router() {
@@ -644,3 +998,459 @@ fn test_remove_pool() {
         );
     }
 }
+
+#[test]
+fn test_pool_protocol_fee() {
+    let setup = Setup::default();
+    let router = setup.router;
+    let user = Address::generate(&setup.env);
+    let [token1, token2, _, _] = setup.tokens;
+    let tokens = Vec::from_array(&setup.env, [token1.address.clone(), token2.address.clone()]);
+    setup.reward_token.mint(&user, &10_0000000);
+    let (pool_hash, _pool_address) = router.init_standard_pool(&user, &tokens, &10);
+
+    for (addr, is_ok) in [
+        (user, false),
+        (setup.admin, true),
+        (setup.emergency_admin, false),
+        (setup.rewards_admin, false),
+        (setup.operations_admin, false),
+        (setup.pause_admin, false),
+        (setup.emergency_pause_admin, false),
+    ] {
+        assert_eq!(
+            router
+                .try_commit_pool_protocol_fee(&addr, &tokens, &pool_hash, &100)
+                .is_ok(),
+            is_ok
+        );
+        assert_eq!(
+            router
+                .try_revert_pool_protocol_fee(&addr, &tokens, &pool_hash)
+                .is_ok(),
+            is_ok
+        );
+        assert_eq!(
+            router
+                .try_set_pool_fees_collector(
+                    &addr,
+                    &tokens,
+                    &pool_hash,
+                    &Address::generate(&setup.env)
+                )
+                .is_ok(),
+            is_ok
+        );
+    }
+}
+
+#[test]
+fn test_update_pool_fee() {
+    let setup = Setup::default();
+    let router = setup.router;
+    let user = Address::generate(&setup.env);
+    let [token1, token2, _, _] = setup.tokens;
+    let tokens = Vec::from_array(&setup.env, [token1.address.clone(), token2.address.clone()]);
+    setup.reward_token.mint(&user, &10_0000000);
+    let (pool_hash, _pool_address) = router.init_standard_pool(&user, &tokens, &10);
+
+    for (addr, is_ok) in [
+        (user, false),
+        (setup.admin, true),
+        (setup.emergency_admin, false),
+        (setup.rewards_admin, false),
+        (setup.operations_admin, true),
+        (setup.pause_admin, false),
+        (setup.emergency_pause_admin, false),
+    ] {
+        assert_eq!(
+            router
+                .try_commit_pool_fee(&addr, &tokens, &pool_hash, &30)
+                .is_ok(),
+            is_ok
+        );
+        assert_eq!(
+            router
+                .try_revert_pool_fee(&addr, &tokens, &pool_hash)
+                .is_ok(),
+            is_ok
+        );
+    }
+}
+
+#[test]
+fn test_emergency_mode_expires() {
+    let setup = Setup::default();
+    let router = setup.router;
+
+    assert_eq!(router.get_emergency_mode_expiration(), 0);
+    router.set_emergency_mode(&setup.emergency_admin, &true);
+    assert_eq!(
+        router.get_emergency_mode_expiration(),
+        setup.env.ledger().timestamp() + EMERGENCY_MODE_MAX_DURATION
+    );
+
+    jump(&setup.env, EMERGENCY_MODE_MAX_DURATION - 1);
+    assert_eq!(router.get_emergency_mode(), true);
+    jump(&setup.env, 1);
+    assert_eq!(router.get_emergency_mode(), false);
+    assert_eq!(router.get_emergency_mode_expiration(), 0);
+
+    // expired emergency mode doesn't allow instant upgrades
+    let new_wasm = install_dummy_wasm(&setup.env);
+    router.commit_upgrade(&setup.admin, &new_wasm);
+    assert!(router.try_apply_upgrade(&setup.admin).is_err());
+}
+
+#[test]
+fn test_emergency_mode_legacy_flag_expired() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let router = setup.router;
+
+    // flag written before the activation timestamp was tracked
+    e.as_contract(&router.address, || {
+        e.storage()
+            .instance()
+            .set(&vec![&e, Symbol::new(&e, "EmergencyMode")], &true);
+    });
+    assert_eq!(router.get_emergency_mode(), false);
+    assert_eq!(router.get_emergency_mode_expiration(), 0);
+
+    // upgrade delay is not skipped
+    let new_wasm = install_dummy_wasm(&e);
+    router.commit_upgrade(&setup.admin, &new_wasm);
+    assert!(router.try_apply_upgrade(&setup.admin).is_err());
+}
+
+fn set_emergency_voters(setup: &Setup, voters: &Vec<Address>) {
+    let role_name = Symbol::new(&setup.env, EMERGENCY_VOTER_ROLE);
+    setup
+        .router
+        .declare_role(&setup.admin, &role_name, &true, &false);
+    setup
+        .router
+        .set_role_addrs(&setup.admin, &role_name, voters);
+}
+
+#[test]
+fn test_emergency_mode_quorum() {
+    let setup = Setup::default();
+    let voter_1 = Address::generate(&setup.env);
+    let voter_2 = Address::generate(&setup.env);
+    set_emergency_voters(
+        &setup,
+        &Vec::from_array(&setup.env, [voter_1.clone(), voter_2.clone()]),
+    );
+    let router = setup.router;
+
+    // voters are the emergency admin and the declared emergency voters
+    router.set_emergency_quorum(&setup.admin, &2);
+    assert_eq!(
+        router.get_emergency_quorum(),
+        (
+            Vec::from_array(
+                &setup.env,
+                [
+                    setup.emergency_admin.clone(),
+                    voter_1.clone(),
+                    voter_2.clone(),
+                ]
+            ),
+            2
+        )
+    );
+
+    // emergency admin is not able to enable emergency mode alone anymore
+    assert!(router
+        .try_set_emergency_mode(&setup.emergency_admin, &true)
+        .is_err());
+    // emergency pause admin is not able to vote as emergency mode allows instant upgrades
+    assert!(router
+        .try_vote_emergency_mode(&setup.emergency_pause_admin)
+        .is_err());
+
+    router.vote_emergency_mode(&voter_1);
+    assert_eq!(router.get_emergency_mode(), false);
+    // repeated vote is not counted twice
+    router.vote_emergency_mode(&voter_1);
+    assert_eq!(router.get_emergency_mode(), false);
+
+    router.vote_emergency_mode(&setup.emergency_admin);
+    assert_eq!(router.get_emergency_mode(), true);
+    assert_eq!(
+        vec![&setup.env, setup.env.events().all().last().unwrap()],
+        vec![
+            &setup.env,
+            (
+                router.address.clone(),
+                (Symbol::new(&setup.env, "enable_emergency_mode"),).into_val(&setup.env),
+                ().into_val(&setup.env),
+            ),
+        ]
+    );
+
+    // emergency admin is still able to disable emergency mode
+    router.set_emergency_mode(&setup.emergency_admin, &false);
+    assert_eq!(router.get_emergency_mode(), false);
+}
+
+#[test]
+fn test_emergency_mode_quorum_votes_expire() {
+    let setup = Setup::default();
+    let voter = Address::generate(&setup.env);
+    set_emergency_voters(&setup, &Vec::from_array(&setup.env, [voter.clone()]));
+    let router = setup.router;
+    router.set_emergency_quorum(&setup.admin, &2);
+
+    router.vote_emergency_mode(&setup.emergency_admin);
+    jump(&setup.env, EMERGENCY_MODE_VOTE_LIFETIME);
+    router.vote_emergency_mode(&voter);
+    assert_eq!(router.get_emergency_mode(), false);
+
+    router.vote_emergency_mode(&setup.emergency_admin);
+    assert_eq!(router.get_emergency_mode(), true);
+}
+
+#[test]
+fn test_emergency_mode_quorum_voter_removed() {
+    let setup = Setup::default();
+    let voter_1 = Address::generate(&setup.env);
+    let voter_2 = Address::generate(&setup.env);
+    set_emergency_voters(&setup, &Vec::from_array(&setup.env, [voter_1.clone()]));
+    let router = setup.router;
+    router.set_emergency_quorum(&setup.admin, &2);
+
+    router.vote_emergency_mode(&voter_1);
+    router.set_role_addrs(
+        &setup.admin,
+        &Symbol::new(&setup.env, EMERGENCY_VOTER_ROLE),
+        &Vec::from_array(&setup.env, [voter_2.clone()]),
+    );
+    assert!(router.try_vote_emergency_mode(&voter_1).is_err());
+
+    // vote of the removed voter is not counted
+    router.vote_emergency_mode(&setup.emergency_admin);
+    assert_eq!(router.get_emergency_mode(), false);
+    router.vote_emergency_mode(&voter_2);
+    assert_eq!(router.get_emergency_mode(), true);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #102)")]
+fn test_emergency_mode_vote_not_voter() {
+    let setup = Setup::default();
+    let router = setup.router;
+    router.set_emergency_quorum(&setup.admin, &1);
+
+    router.vote_emergency_mode(&setup.admin);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #111)")]
+fn test_emergency_quorum_bad_threshold() {
+    let setup = Setup::default();
+    let router = setup.router;
+    // emergency pause admins are not counted, so emergency admin is the only voter
+    router.set_emergency_quorum(&setup.admin, &2);
+}
+
+#[test]
+fn test_audit_log() {
+    let setup = Setup::default();
+    let router = setup.router;
+    let emergency_admin_symbol = Symbol::new(&setup.env, "EmergencyAdmin");
+    let emergency_pause_admin_symbol = Symbol::new(&setup.env, "EmergencyPauseAdmin");
+
+    // initialization is recorded as well
+    let start = router.get_audit_log_len();
+    assert_eq!(
+        router.get_audit_log(&0, &1).get(0).unwrap(),
+        AuditRecord {
+            action: AuditAction::SetRole,
+            role: Some(Symbol::new(&setup.env, "Admin")),
+            old_address: None,
+            new_address: Some(setup.admin.clone()),
+            timestamp: 0,
+        }
+    );
+
+    jump(&setup.env, 100);
+    router.set_emergency_mode(&setup.emergency_admin, &true);
+    router.set_role_expiry(
+        &setup.admin,
+        &emergency_pause_admin_symbol,
+        &setup.emergency_pause_admin,
+        &(setup.env.ledger().timestamp() + ADMIN_ACTIONS_DELAY + 1000),
+    );
+    let new_emergency_admin = Address::generate(&setup.env);
+    router.commit_transfer_ownership(&setup.admin, &emergency_admin_symbol, &new_emergency_admin);
+    jump(&setup.env, ADMIN_ACTIONS_DELAY + 1);
+    router.apply_transfer_ownership(&setup.admin, &emergency_admin_symbol);
+
+    assert_eq!(router.get_audit_log_len(), start + 4);
+    let log = router.get_audit_log(&start, &10);
+    assert_eq!(
+        log,
+        Vec::from_array(
+            &setup.env,
+            [
+                AuditRecord {
+                    action: AuditAction::EnableEmergencyMode,
+                    role: None,
+                    old_address: None,
+                    new_address: None,
+                    timestamp: 100,
+                },
+                AuditRecord {
+                    action: AuditAction::SetRoleExpiry,
+                    role: Some(emergency_pause_admin_symbol),
+                    old_address: None,
+                    new_address: Some(setup.emergency_pause_admin.clone()),
+                    timestamp: 100,
+                },
+                AuditRecord {
+                    action: AuditAction::CommitTransfer,
+                    role: Some(emergency_admin_symbol.clone()),
+                    old_address: Some(setup.emergency_admin.clone()),
+                    new_address: Some(new_emergency_admin.clone()),
+                    timestamp: 100,
+                },
+                AuditRecord {
+                    action: AuditAction::SetRole,
+                    role: Some(emergency_admin_symbol),
+                    old_address: Some(setup.emergency_admin.clone()),
+                    new_address: Some(new_emergency_admin),
+                    timestamp: setup.env.ledger().timestamp(),
+                },
+            ]
+        )
+    );
+    assert_eq!(router.get_audit_log(&(start + 1), &1).len(), 1);
+    assert_eq!(router.get_audit_log(&(start + 4), &10).len(), 0);
+}
+
+#[test]
+fn test_audit_log_ring_buffer() {
+    let setup = Setup::default();
+    let router = setup.router;
+
+    let start = router.get_audit_log_len();
+    for _ in 0..AUDIT_LOG_SIZE {
+        router.set_emergency_mode(&setup.emergency_admin, &false);
+    }
+    assert_eq!(router.get_audit_log_len(), start + AUDIT_LOG_SIZE);
+
+    // oldest records are overwritten
+    let log = router.get_audit_log(&0, &AUDIT_LOG_SIZE);
+    assert_eq!(log.len(), AUDIT_LOG_SIZE);
+    for record in log.iter() {
+        assert_eq!(record.action, AuditAction::DisableEmergencyMode);
+    }
+}
+
+#[test]
+fn test_configurable_delays() {
+    let setup = Setup::default();
+    let router = setup.router;
+    let emergency_admin_symbol = Symbol::new(&setup.env, "EmergencyAdmin");
+    let role_target = DelayTarget::RoleTransfer(emergency_admin_symbol.clone());
+
+    assert_eq!(
+        router.get_delay(&DelayTarget::AdminActions),
+        ADMIN_ACTIONS_DELAY
+    );
+    assert_eq!(router.get_delay(&DelayTarget::Upgrade), ADMIN_ACTIONS_DELAY);
+    assert_eq!(router.get_delay(&role_target), ADMIN_ACTIONS_DELAY);
+
+    // delay change is delayed by the current delay
+    router.commit_delay(&setup.admin, &DelayTarget::AdminActions, &60);
+    assert!(router
+        .try_commit_delay(&setup.admin, &DelayTarget::AdminActions, &120)
+        .is_err());
+    jump(&setup.env, ADMIN_ACTIONS_DELAY - 1);
+    assert!(router
+        .try_apply_delay(&setup.admin, &DelayTarget::AdminActions)
+        .is_err());
+    jump(&setup.env, 1);
+    router.apply_delay(&setup.admin, &DelayTarget::AdminActions);
+
+    // more specific delays fall back to the contract-wide one
+    assert_eq!(router.get_delay(&DelayTarget::AdminActions), 60);
+    assert_eq!(router.get_delay(&DelayTarget::Upgrade), 60);
+    assert_eq!(router.get_delay(&role_target), 60);
+
+    let new_wasm = install_dummy_wasm(&setup.env);
+    router.commit_upgrade(&setup.admin, &new_wasm);
+    jump(&setup.env, 60);
+    router.apply_upgrade(&setup.admin);
+    assert_eq!(router.version(), 130);
+}
+
+#[test]
+fn test_role_transfer_delay() {
+    let setup = Setup::default();
+    let router = setup.router;
+    let emergency_admin_symbol = Symbol::new(&setup.env, "EmergencyAdmin");
+    let role_target = DelayTarget::RoleTransfer(emergency_admin_symbol.clone());
+
+    router.commit_delay(&setup.admin, &role_target, &3600);
+    jump(&setup.env, ADMIN_ACTIONS_DELAY);
+    router.apply_delay(&setup.admin, &role_target);
+    assert_eq!(router.get_delay(&role_target), 3600);
+    assert_eq!(
+        router.get_delay(&DelayTarget::AdminActions),
+        ADMIN_ACTIONS_DELAY
+    );
+
+    let new_emergency_admin = Address::generate(&setup.env);
+    router.commit_transfer_ownership(&setup.admin, &emergency_admin_symbol, &new_emergency_admin);
+    jump(&setup.env, 3599);
+    assert!(router
+        .try_apply_transfer_ownership(&setup.admin, &emergency_admin_symbol)
+        .is_err());
+    jump(&setup.env, 1);
+    router.apply_transfer_ownership(&setup.admin, &emergency_admin_symbol);
+    assert_eq!(
+        router.get_future_address(&emergency_admin_symbol),
+        new_emergency_admin
+    );
+}
+
+#[test]
+fn test_revert_delay() {
+    let setup = Setup::default();
+    let router = setup.router;
+
+    router.commit_delay(&setup.admin, &DelayTarget::Upgrade, &60);
+    router.revert_delay(&setup.admin, &DelayTarget::Upgrade);
+    jump(&setup.env, ADMIN_ACTIONS_DELAY + 1);
+    assert!(router
+        .try_apply_delay(&setup.admin, &DelayTarget::Upgrade)
+        .is_err());
+    assert_eq!(router.get_delay(&DelayTarget::Upgrade), ADMIN_ACTIONS_DELAY);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #113)")]
+fn test_delay_below_minimum() {
+    let setup = Setup::default();
+    let router = setup.router;
+    router.commit_delay(
+        &setup.admin,
+        &DelayTarget::AdminActions,
+        &(MIN_ADMIN_ACTIONS_DELAY - 1),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #113)")]
+fn test_delay_above_maximum() {
+    let setup = Setup::default();
+    let router = setup.router;
+    router.commit_delay(
+        &setup.admin,
+        &DelayTarget::AdminActions,
+        &(MAX_ADMIN_ACTIONS_DELAY + 1),
+    );
+}
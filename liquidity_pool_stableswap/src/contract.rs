@@ -1,21 +1,27 @@
-use crate::pool_constants::{FEE_DENOMINATOR, MAX_A, MAX_A_CHANGE, MIN_RAMP_TIME};
+use crate::pool_constants::{
+    FEE_DENOMINATOR, MAX_A, MAX_A_CHANGE, MINIMUM_LIQUIDITY, MIN_RAMP_TIME, PRICE_PRECISION,
+};
 use crate::pool_interface::{
-    AdminInterfaceTrait, LiquidityPoolInterfaceTrait, LiquidityPoolTrait, ManagedLiquidityPool,
-    RewardsTrait, UpgradeableContract, UpgradeableLPTokenTrait,
+    AdminInterfaceTrait, FlashLoanTrait, LiquidityPoolInterfaceTrait, LiquidityPoolTrait,
+    ManagedLiquidityPool, ReservesSyncTrait, RewardsTrait, UpgradeableContract,
+    UpgradeableLPTokenTrait,
 };
 use crate::storage::{
-    get_admin_actions_deadline, get_decimals, get_fee, get_future_a, get_future_a_time,
-    get_future_fee, get_initial_a, get_initial_a_time, get_is_killed_claim, get_is_killed_deposit,
-    get_is_killed_swap, get_plane, get_precision, get_precision_mul, get_reserves, get_router,
-    get_token_future_wasm, get_tokens, has_plane, put_admin_actions_deadline, put_decimals,
-    put_fee, put_future_a, put_future_a_time, put_future_fee, put_initial_a, put_initial_a_time,
-    put_reserves, put_tokens, set_is_killed_claim, set_is_killed_deposit, set_is_killed_swap,
-    set_plane, set_router, set_token_future_wasm,
+    get_admin_actions_deadline, get_admin_fee, get_admin_fee_reserves, get_decimals, get_fee,
+    get_fees_collector, get_future_a, get_future_a_time, get_future_admin_fee, get_future_fee,
+    get_initial_a, get_initial_a_time, get_is_killed_claim, get_is_killed_deposit,
+    get_is_killed_swap, get_plane, get_precision, get_precision_mul, get_price_cumulative,
+    get_price_timestamp_last, get_reserves, get_router, get_token_future_wasm, get_tokens,
+    has_plane, has_price_timestamp_last, put_admin_actions_deadline, put_admin_fee_reserves,
+    put_decimals, put_fee, put_future_a, put_future_a_time, put_future_fee, put_initial_a,
+    put_initial_a_time, put_price_cumulative, put_reserves, put_tokens, set_admin_fee,
+    set_fees_collector, set_future_admin_fee, set_is_killed_claim, set_is_killed_deposit,
+    set_is_killed_swap, set_plane, set_price_timestamp_last, set_router, set_token_future_wasm,
 };
 use crate::token::create_contract;
 use token_share::{
-    burn_shares, get_token_share, get_total_shares, get_user_balance_shares, mint_shares,
-    put_token_share, Client as LPToken,
+    burn_shares, get_token_share, get_total_shares, get_user_balance_shares, lock_shares,
+    mint_shares, put_token_share, Client as LPToken,
 };
 
 use crate::errors::LiquidityPoolError;
@@ -23,14 +29,23 @@ use crate::events::Events;
 use crate::normalize::{read_decimals, xp};
 use crate::plane::update_plane;
 use crate::plane_interface::Plane;
-use crate::rewards::get_rewards_manager;
+use crate::rewards::{get_rewards_manager, get_rewards_total_shares};
 use access_control::access::{AccessControl, AccessControlTrait};
-use access_control::constants::ADMIN_ACTIONS_DELAY;
-use access_control::emergency::{get_emergency_mode, set_emergency_mode};
+use access_control::audit::{get_audit_log, get_audit_log_len, AuditRecord};
+use access_control::delays::{apply_delay, commit_delay, get_delay, revert_delay, DelayTarget};
+use access_control::emergency::{
+    get_emergency_mode, get_emergency_mode_expiration, get_emergency_quorum, set_emergency_mode,
+    set_emergency_quorum, vote_emergency_mode,
+};
 use access_control::errors::AccessControlError;
 use access_control::events::Events as AccessControlEvents;
-use access_control::interface::TransferableContract;
+use access_control::grant::RoleGrantTrait;
+use access_control::interface::{
+    AuditLogContract, ConfigurableDelaysContract, DelayedRolesContract, EmergencyQuorumContract,
+    RoleGrantsContract, RolesRegistryContract, TransferableContract,
+};
 use access_control::management::{MultipleAddressesManagementTrait, SingleAddressManagementTrait};
+use access_control::registry::RoleRegistryTrait;
 use access_control::role::Role;
 use access_control::role::SymbolRepresentation;
 use access_control::transfer::TransferOwnershipTrait;
@@ -47,8 +62,8 @@ use rewards::storage::{
 use soroban_fixed_point_math::SorobanFixedPoint;
 use soroban_sdk::token::Client as SorobanTokenClient;
 use soroban_sdk::{
-    contract, contractimpl, contractmeta, panic_with_error, symbol_short, Address, BytesN, Env,
-    IntoVal, Map, Symbol, Val, Vec, U256,
+    contract, contractimpl, contractmeta, panic_with_error, symbol_short, Address, Bytes, BytesN,
+    Env, IntoVal, Map, Symbol, Val, Vec, U256,
 };
 use upgrade::events::Events as UpgradeEvents;
 use upgrade::{apply_upgrade, commit_upgrade, revert_upgrade};
@@ -248,7 +263,7 @@ impl LiquidityPoolTrait for LiquidityPool {
 
         // Before actual changes were made to the pool, update total rewards data and refresh user reward
         let rewards = get_rewards_manager(&e);
-        let total_shares = get_total_shares(&e);
+        let total_shares = get_rewards_total_shares(&e);
         let user_shares = get_user_balance_shares(&e, &user);
         rewards
             .manager()
@@ -271,6 +286,7 @@ impl LiquidityPoolTrait for LiquidityPool {
 
         let d1 = Self::_get_d(&e, &Self::_xp(&e, &new_balances), amp);
 
+        let admin_fee_share = get_admin_fee(&e) as u128;
         for i in 0..n_coins {
             let new_balance = new_balances.get(i).unwrap();
             let ideal_balance = d1
@@ -291,10 +307,13 @@ impl LiquidityPoolTrait for LiquidityPool {
                 &(get_fee(&e) as u128 * n_coins as u128),
                 &(4 * (n_coins as u128 - 1) * FEE_DENOMINATOR as u128),
             );
+            let admin_fee = fee.fixed_mul_floor(&e, &admin_fee_share, &(FEE_DENOMINATOR as u128));
+            Self::_accrue_admin_fee(&e, i, admin_fee);
 
-            reserves.set(i, new_balance);
+            reserves.set(i, new_balance - admin_fee);
             new_balances.set(i, new_balance - fee);
         }
+        Self::_update_price_cumulative(&e);
         put_reserves(&e, &reserves);
 
         let d2 = Self::_get_d(&e, &Self::_xp(&e, &new_balances), amp);
@@ -383,19 +402,23 @@ impl LiquidityPoolTrait for LiquidityPool {
 
         // Before actual changes were made to the pool, update total rewards data and refresh user reward
         let rewards = get_rewards_manager(&e);
-        let total_shares = get_total_shares(&e);
+        let total_shares = get_rewards_total_shares(&e);
         let user_shares = get_user_balance_shares(&e, &user);
         rewards
             .manager()
             .checkpoint_user(&user, total_shares, user_shares);
 
-        let (dy, _) = Self::_calc_withdraw_one_coin(&e, share_amount, i);
+        let (dy, dy_fee) = Self::_calc_withdraw_one_coin(&e, share_amount, i);
         if dy < min_amount {
             panic_with_error!(&e, LiquidityPoolValidationError::InMinNotSatisfied);
         }
+        let dy_admin_fee =
+            dy_fee.fixed_mul_floor(&e, &(get_admin_fee(&e) as u128), &(FEE_DENOMINATOR as u128));
 
         let mut reserves = get_reserves(&e);
-        reserves.set(i, reserves.get(i).unwrap() - dy);
+        reserves.set(i, reserves.get(i).unwrap() - dy - dy_admin_fee);
+        Self::_accrue_admin_fee(&e, i, dy_admin_fee);
+        Self::_update_price_cumulative(&e);
         put_reserves(&e, &reserves);
 
         // Redeem shares
@@ -426,6 +449,50 @@ impl LiquidityPoolTrait for LiquidityPool {
         PoolEvents::new(&e).withdraw_liquidity(coins, amounts.clone(), share_amount);
         amounts
     }
+
+    // Returns the share of the trading fee reserved for the protocol, with 1e4 precision.
+    fn get_admin_fee(e: Env) -> u32 {
+        get_admin_fee(&e)
+    }
+
+    // Returns the admin fees accrued for each token and not yet withdrawn.
+    fn get_admin_fee_reserves(e: Env) -> Vec<u128> {
+        get_admin_fee_reserves(&e)
+    }
+
+    // Transfers the accrued admin fees to the fees collector.
+    // Can be called by anyone.
+    //
+    // # Returns
+    //
+    // * The amounts of tokens transferred.
+    fn withdraw_admin_fees(e: Env) -> Vec<u128> {
+        let fees_collector = get_fees_collector(&e);
+        let coins = get_tokens(&e);
+        let amounts = get_admin_fee_reserves(&e);
+
+        let mut empty_reserves: Vec<u128> = Vec::new(&e);
+        for _i in 0..coins.len() {
+            empty_reserves.push_back(0);
+        }
+        put_admin_fee_reserves(&e, &empty_reserves);
+
+        for i in 0..coins.len() {
+            let amount = amounts.get(i).unwrap();
+            if amount == 0 {
+                continue;
+            }
+
+            SorobanTokenClient::new(&e, &coins.get(i).unwrap()).transfer(
+                &e.current_contract_address(),
+                &fees_collector,
+                &(amount as i128),
+            );
+        }
+
+        Events::new(&e).withdraw_admin_fees(fees_collector, amounts.clone());
+        amounts
+    }
 }
 
 impl LiquidityPool {
@@ -433,6 +500,45 @@ impl LiquidityPool {
         xp(e, reserves)
     }
 
+    // Reward amount configured for the users and not claimed yet.
+    fn _get_reward_liability(e: &Env) -> u128 {
+        let rewards = get_rewards_manager(e);
+        let mut rewards_manager = rewards.manager();
+        let total_shares = get_rewards_total_shares(e);
+        rewards_manager.get_total_configured_reward(total_shares)
+            - rewards_manager.get_total_claimed_reward(total_shares)
+    }
+
+    // Calculates coin balances of the pool not accounted in the reserves, admin fees or rewards.
+    //
+    // # Returns
+    //
+    // * A vector of the excess amounts of each coin.
+    fn _get_excess_balances(e: &Env) -> Vec<u128> {
+        let tokens = get_tokens(e);
+        let reserves = get_reserves(e);
+        let admin_fees = get_admin_fee_reserves(e);
+        let rewards_storage = get_rewards_manager(e).storage();
+        let reward_token = if rewards_storage.has_reward_token() {
+            Some(rewards_storage.get_reward_token())
+        } else {
+            None
+        };
+
+        let mut amounts = Vec::new(e);
+        for i in 0..tokens.len() {
+            let token = tokens.get(i).unwrap();
+            let balance = SorobanTokenClient::new(e, &token).balance(&e.current_contract_address());
+            let mut accounted = reserves.get(i).unwrap() + admin_fees.get(i).unwrap();
+            // reward token balance also backs the rewards configured and not claimed yet
+            if reward_token == Some(token) {
+                accounted += Self::_get_reward_liability(e);
+            }
+            amounts.push_back((balance as u128).saturating_sub(accounted));
+        }
+        amounts
+    }
+
     // Calculates the invariant `D` for the given token balances.
     //
     // # Arguments
@@ -633,6 +739,85 @@ impl LiquidityPool {
         panic_with_error!(e, LiquidityPoolError::MaxIterationsReached);
     }
 
+    // Calculates marginal prices of every token in token 0 for the given balances.
+    //
+    // # Arguments
+    //
+    // * `xp` - The balances of each token in the pool.
+    // * `amp` - The amplification coefficient in the form of A*N**(N-1).
+    // * `d` - The invariant `D` for the given balances.
+    //
+    // # Returns
+    //
+    // * The prices of each token in token 0, scaled by PRICE_PRECISION.
+    fn _get_p(e: &Env, xp: &Vec<u128>, amp: u128, d: &U256) -> Vec<U256> {
+        let n_coins = xp.len();
+        let precision = U256::from_u128(e, PRICE_PRECISION);
+
+        // D**(N+1) / (N**N * prod(xp))
+        let mut dr = d.div(&U256::from_u128(e, (n_coins as u128).pow(n_coins)));
+        for x in xp.iter() {
+            dr = dr.mul(d).div(&U256::from_u128(e, x));
+        }
+
+        let xp0 = U256::from_u128(e, xp.get(0).unwrap());
+        let xp0_a = U256::from_u128(e, amp * n_coins as u128).mul(&xp0);
+        let denominator = xp0_a.add(&dr);
+        let mut prices = Vec::new(e);
+        for x in xp.iter() {
+            let numerator = xp0_a.add(&dr.mul(&xp0).div(&U256::from_u128(e, x)));
+            prices.push_back(precision.mul(&numerator).div(&denominator));
+        }
+        prices
+    }
+
+    // Accumulates marginal prices weighted by the time elapsed since the last update.
+    // Should be called before the reserves are changed, so the previous reserves are accounted.
+    fn _update_price_cumulative(e: &Env) {
+        let now = e.ledger().timestamp();
+        // nothing to accumulate on the very first update
+        if has_price_timestamp_last(e) {
+            let last_timestamp = get_price_timestamp_last(e);
+            if now == last_timestamp {
+                return;
+            }
+
+            let xp = Self::_xp(e, &get_reserves(e));
+            if xp.iter().all(|x| x > 0) {
+                let amp = Self::a(e.clone());
+                let d = Self::_get_d(e, &xp, amp);
+                let prices = Self::_get_p(e, &xp, amp, &d);
+                let elapsed = U256::from_u128(e, (now - last_timestamp) as u128);
+                let mut price_cumulative = get_price_cumulative(e);
+                for i in 0..prices.len() {
+                    price_cumulative.set(
+                        i,
+                        price_cumulative
+                            .get(i)
+                            .unwrap()
+                            .add(&prices.get(i).unwrap().mul(&elapsed)),
+                    );
+                }
+                put_price_cumulative(e, &price_cumulative);
+            }
+        }
+        set_price_timestamp_last(e, &now);
+    }
+
+    // Tracks the protocol share of the trading fee separately from the pool reserves.
+    fn _accrue_admin_fee(e: &Env, token_idx: u32, amount: u128) {
+        if amount == 0 {
+            return;
+        }
+
+        let mut admin_fee_reserves = get_admin_fee_reserves(e);
+        admin_fee_reserves.set(
+            token_idx,
+            admin_fee_reserves.get(token_idx).unwrap() + amount,
+        );
+        put_admin_fee_reserves(e, &admin_fee_reserves);
+    }
+
     // Calculate the amount received when withdrawing a single coin.
     //
     // # Arguments
@@ -764,6 +949,11 @@ impl AdminInterfaceTrait for LiquidityPool {
             access_control.get_role_addresses(&Role::EmergencyPauseAdmin),
         );
 
+        for role_name in access_control.get_declared_roles() {
+            let role = Role::from_symbol(&e, role_name.clone());
+            result.set(role_name, access_control.get_role_addrs(&role));
+        }
+
         result
     }
 
@@ -845,14 +1035,41 @@ impl AdminInterfaceTrait for LiquidityPool {
             panic_with_error!(e, LiquidityPoolValidationError::FeeOutOfBounds);
         }
 
-        let deadline = e.ledger().timestamp() + ADMIN_ACTIONS_DELAY;
+        let deadline = e.ledger().timestamp() + get_delay(&e, &DelayTarget::AdminActions);
         put_admin_actions_deadline(&e, &deadline);
         put_future_fee(&e, &new_fee);
+        set_future_admin_fee(&e, &get_admin_fee(&e));
 
         Events::new(&e).commit_new_fee(new_fee);
     }
 
-    // Applies the committed fee.
+    // Sets a new share of the fee reserved for the protocol to be applied in the future.
+    // Applied with `apply_new_fee` along with the current fee.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `new_admin_fee` - The share of the fee reserved for the protocol, with 1e4 precision.
+    fn commit_new_admin_fee(e: Env, admin: Address, new_admin_fee: u32) {
+        admin.require_auth();
+        require_operations_admin_or_owner(&e, &admin);
+
+        if get_admin_actions_deadline(&e) != 0 {
+            panic_with_error!(&e, LiquidityPoolError::AnotherActionActive);
+        }
+        if new_admin_fee > FEE_DENOMINATOR {
+            panic_with_error!(e, LiquidityPoolValidationError::AdminFeeOutOfBounds);
+        }
+
+        let deadline = e.ledger().timestamp() + get_delay(&e, &DelayTarget::AdminActions);
+        put_admin_actions_deadline(&e, &deadline);
+        put_future_fee(&e, &get_fee(&e));
+        set_future_admin_fee(&e, &new_admin_fee);
+
+        Events::new(&e).commit_new_admin_fee(new_admin_fee);
+    }
+
+    // Applies the committed fee and admin fee share.
     //
     // # Arguments
     //
@@ -871,11 +1088,37 @@ impl AdminInterfaceTrait for LiquidityPool {
         put_admin_actions_deadline(&e, &0);
         let fee = get_future_fee(&e);
         put_fee(&e, &fee);
+        let admin_fee = get_future_admin_fee(&e);
+        let admin_fee_changed = admin_fee != get_admin_fee(&e);
+        set_admin_fee(&e, &admin_fee);
 
         // update plane data for every pool update
         update_plane(&e);
 
         Events::new(&e).apply_new_fee(fee);
+        if admin_fee_changed {
+            Events::new(&e).apply_new_admin_fee(admin_fee);
+        }
+    }
+
+    // Sets the address receiving the withdrawn admin fees.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `fees_collector` - The address of the fees collector.
+    fn set_fees_collector(e: Env, admin: Address, fees_collector: Address) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+
+        set_fees_collector(&e, &fees_collector);
+
+        Events::new(&e).set_fees_collector(fees_collector);
+    }
+
+    // Returns the address receiving the withdrawn admin fees.
+    fn get_fees_collector(e: Env) -> Address {
+        get_fees_collector(&e)
     }
 
     // Reverts the committed parameters to their current values.
@@ -1119,6 +1362,7 @@ impl LiquidityPoolInterfaceTrait for LiquidityPool {
             initial_reserves.push_back(0_u128);
         }
         put_reserves(&e, &initial_reserves);
+        Self::_update_price_cumulative(&e);
 
         // pool config
         put_initial_a(&e, &amp);
@@ -1167,6 +1411,55 @@ impl LiquidityPoolInterfaceTrait for LiquidityPool {
         get_reserves(&e)
     }
 
+    // Returns the pool's cumulative marginal prices, updated on every reserves change.
+    // The average price over a period is the difference of the cumulative prices
+    // divided by the time elapsed.
+    //
+    // # Returns
+    //
+    // A vector of the cumulative prices of every token in token 0, scaled by 1e18.
+    fn get_price_cumulative(e: Env) -> Vec<U256> {
+        get_price_cumulative(&e)
+    }
+
+    // Returns the timestamp of the last cumulative prices update.
+    //
+    // # Returns
+    //
+    // The timestamp of the last cumulative prices update.
+    fn get_price_timestamp_last(e: Env) -> u64 {
+        get_price_timestamp_last(&e)
+    }
+
+    // Calculates the marginal prices for the given reserves, same as accumulated in the cumulative prices.
+    // Prices are in the normalized units, so they should be adjusted by the tokens decimals.
+    //
+    // # Arguments
+    //
+    // * `reserves` - The pool reserves to calculate the prices for.
+    //
+    // # Returns
+    //
+    // A vector of the prices of every token in token 0, scaled by 1e18. Zeros if any reserve is empty.
+    fn calc_prices(e: Env, reserves: Vec<u128>) -> Vec<U256> {
+        if reserves.len() != get_tokens(&e).len() {
+            panic_with_error!(e, LiquidityPoolValidationError::WrongInputVecSize);
+        }
+
+        let xp = Self::_xp(&e, &reserves);
+        if xp.iter().any(|x| x == 0) {
+            let mut prices = Vec::new(&e);
+            for _ in 0..xp.len() {
+                prices.push_back(U256::from_u32(&e, 0));
+            }
+            return prices;
+        }
+
+        let amp = Self::a(e.clone());
+        let d = Self::_get_d(&e, &xp, amp);
+        Self::_get_p(&e, &xp, amp, &d)
+    }
+
     // Returns the pool's tokens.
     //
     // # Returns
@@ -1212,7 +1505,7 @@ impl LiquidityPoolInterfaceTrait for LiquidityPool {
 
         // Before actual changes were made to the pool, update total rewards data and refresh/initialize user reward
         let rewards = get_rewards_manager(&e);
-        let total_shares = get_total_shares(&e);
+        let total_shares = get_rewards_total_shares(&e);
         let user_shares = get_user_balance_shares(&e, &user);
         rewards
             .manager()
@@ -1256,6 +1549,7 @@ impl LiquidityPoolInterfaceTrait for LiquidityPool {
         // to calculate fair user's share
         let mut d2 = d1.clone();
         let balances = if token_supply > 0 {
+            let admin_fee_share = get_admin_fee(&e) as u128;
             let mut result = new_balances.clone();
             // Only account for fees if we are not the first to deposit
             for i in 0..n_coins {
@@ -1280,8 +1574,11 @@ impl LiquidityPoolInterfaceTrait for LiquidityPool {
                     &(get_fee(&e) as u128 * n_coins as u128),
                     &(FEE_DENOMINATOR as u128 * 4 * (n_coins as u128 - 1)),
                 );
+                let admin_fee =
+                    fee.fixed_mul_floor(&e, &admin_fee_share, &(FEE_DENOMINATOR as u128));
+                Self::_accrue_admin_fee(&e, i, admin_fee);
 
-                result.set(i, new_balance);
+                result.set(i, new_balance - admin_fee);
                 new_balances.set(i, new_balances.get(i).unwrap() - fee);
             }
             d2 = Self::_get_d(&e, &Self::_xp(&e, &new_balances), amp);
@@ -1289,10 +1586,11 @@ impl LiquidityPoolInterfaceTrait for LiquidityPool {
         } else {
             new_balances
         };
+        Self::_update_price_cumulative(&e);
         put_reserves(&e, &balances);
 
         // Calculate, how much pool tokens to mint
-        let mint_amount = if token_supply == 0 {
+        let mut mint_amount = if token_supply == 0 {
             d1.to_u128().unwrap() // Take the dust if there was any
         } else {
             U256::from_u128(&e, token_supply)
@@ -1301,6 +1599,17 @@ impl LiquidityPoolInterfaceTrait for LiquidityPool {
                 .to_u128()
                 .unwrap()
         };
+        if token_supply == 0 {
+            // first deposit: lock minimum liquidity forever so the share price can't be inflated
+            if mint_amount <= MINIMUM_LIQUIDITY {
+                panic_with_error!(
+                    &e,
+                    LiquidityPoolValidationError::InsufficientInitialLiquidity
+                );
+            }
+            lock_shares(&e, MINIMUM_LIQUIDITY);
+            mint_amount -= MINIMUM_LIQUIDITY;
+        }
 
         if mint_amount < min_shares {
             panic_with_error!(&e, LiquidityPoolValidationError::OutMinNotSatisfied);
@@ -1374,9 +1683,12 @@ impl LiquidityPoolInterfaceTrait for LiquidityPool {
 
         let dy = xp.get(out_idx).unwrap() - y - 1; // -1 just in case there were some rounding errors
         let dy_fee = dy.fixed_mul_ceil(&e, &(get_fee(&e) as u128), &(FEE_DENOMINATOR as u128));
+        let dy_admin_fee =
+            dy_fee.fixed_mul_floor(&e, &(get_admin_fee(&e) as u128), &(FEE_DENOMINATOR as u128));
 
         // Convert all to real units
         let dy = (dy - dy_fee) / precision_mul.get(out_idx).unwrap();
+        let dy_admin_fee = dy_admin_fee / precision_mul.get(out_idx).unwrap();
         if dy < out_min {
             panic_with_error!(e, LiquidityPoolValidationError::OutMinNotSatisfied);
         }
@@ -1384,8 +1696,13 @@ impl LiquidityPoolInterfaceTrait for LiquidityPool {
         // Change balances exactly in same way as we change actual ERC20 coin amounts
         let mut reserves = get_reserves(&e);
         reserves.set(in_idx, old_balances.get(in_idx).unwrap() + in_amount);
-        reserves.set(out_idx, old_balances.get(out_idx).unwrap() - dy);
+        reserves.set(
+            out_idx,
+            old_balances.get(out_idx).unwrap() - dy - dy_admin_fee,
+        );
+        Self::_update_price_cumulative(&e);
         put_reserves(&e, &reserves);
+        Self::_accrue_admin_fee(&e, out_idx, dy_admin_fee);
 
         let token_out = coins.get(out_idx).unwrap();
         let token_client = SorobanTokenClient::new(&e, &token_out);
@@ -1503,10 +1820,20 @@ impl LiquidityPoolInterfaceTrait for LiquidityPool {
         }
 
         // Update reserves
+        let dy_admin_fee = (dy_w_fee - out_amount).fixed_mul_floor(
+            &e,
+            &(get_admin_fee(&e) as u128),
+            &(FEE_DENOMINATOR as u128),
+        );
         let mut reserves = get_reserves(&e);
         reserves.set(in_idx, old_balances.get(in_idx).unwrap() + dx);
-        reserves.set(out_idx, old_balances.get(out_idx).unwrap() - out_amount);
+        reserves.set(
+            out_idx,
+            old_balances.get(out_idx).unwrap() - out_amount - dy_admin_fee,
+        );
+        Self::_update_price_cumulative(&e);
         put_reserves(&e, &reserves);
+        Self::_accrue_admin_fee(&e, out_idx, dy_admin_fee);
 
         let token_out = coins.get(out_idx).unwrap();
         let token_client = SorobanTokenClient::new(&e, &token_out);
@@ -1565,7 +1892,7 @@ impl LiquidityPoolInterfaceTrait for LiquidityPool {
 
         // Before actual changes were made to the pool, update total rewards data and refresh user reward
         let rewards = get_rewards_manager(&e);
-        let total_shares = get_total_shares(&e);
+        let total_shares = get_rewards_total_shares(&e);
         let user_shares = get_user_balance_shares(&e, &user);
         rewards
             .manager()
@@ -1590,6 +1917,7 @@ impl LiquidityPoolInterfaceTrait for LiquidityPool {
             let token_client = SorobanTokenClient::new(&e, &coins.get(i).unwrap());
             token_client.transfer(&e.current_contract_address(), &user, &(value as i128));
         }
+        Self::_update_price_cumulative(&e);
         put_reserves(&e, &reserves);
 
         // Redeem shares
@@ -1630,6 +1958,166 @@ impl LiquidityPoolInterfaceTrait for LiquidityPool {
     }
 }
 
+// The `FlashLoanTrait` trait provides the interface for lending pool coins within a single transaction.
+#[contractimpl]
+impl FlashLoanTrait for LiquidityPool {
+    // Lends pool coins to the receiver contract and invokes `on_flash_loan` callback on it.
+    // By the end of the callback, the borrowed amounts plus the flash fee should be returned to the pool.
+    // The flash fee is charged at the pool fee rate, admin share of it is accrued as with swaps.
+    //
+    // # Arguments
+    //
+    // * `receiver` - The address of the contract receiving the coins.
+    // * `amounts` - The amounts of coins to borrow, in the pool coins order.
+    // * `data` - Arbitrary data passed to the receiver callback.
+    //
+    // # Returns
+    //
+    // The flash fees charged for every coin.
+    fn flash_loan(e: Env, receiver: Address, amounts: Vec<u128>, data: Bytes) -> Vec<u128> {
+        receiver.require_auth();
+
+        if get_is_killed_swap(&e) {
+            panic_with_error!(e, LiquidityPoolError::PoolSwapKilled);
+        }
+
+        let tokens = get_tokens(&e);
+        let n_coins = tokens.len();
+        if amounts.len() != n_coins {
+            panic_with_error!(e, LiquidityPoolValidationError::WrongInputVecSize);
+        }
+
+        if amounts.iter().all(|amount| amount == 0) {
+            panic_with_error!(e, LiquidityPoolValidationError::ZeroAmount);
+        }
+
+        let mut reserves = get_reserves(&e);
+        let fee = get_fee(&e) as u128;
+
+        let mut fees = Vec::new(&e);
+        let mut balances_before = Vec::new(&e);
+        for i in 0..n_coins {
+            let amount = amounts.get(i).unwrap();
+            if amount > reserves.get(i).unwrap() {
+                panic_with_error!(e, LiquidityPoolValidationError::InsufficientBalance);
+            }
+            fees.push_back(amount.fixed_mul_ceil(&e, &fee, &(FEE_DENOMINATOR as u128)));
+
+            let token_client = SorobanTokenClient::new(&e, &tokens.get(i).unwrap());
+            balances_before.push_back(token_client.balance(&e.current_contract_address()));
+            if amount > 0 {
+                token_client.transfer(&e.current_contract_address(), &receiver, &(amount as i128));
+            }
+        }
+
+        // re-entrance is not allowed, so the pool state can't be changed within the callback
+        e.invoke_contract::<()>(
+            &receiver,
+            &Symbol::new(&e, "on_flash_loan"),
+            Vec::from_array(
+                &e,
+                [
+                    tokens.into_val(&e),
+                    amounts.into_val(&e),
+                    fees.into_val(&e),
+                    data.into_val(&e),
+                ],
+            ),
+        );
+
+        for i in 0..n_coins {
+            let balance = SorobanTokenClient::new(&e, &tokens.get(i).unwrap())
+                .balance(&e.current_contract_address());
+            if balance < balances_before.get(i).unwrap() + fees.get(i).unwrap() as i128 {
+                panic_with_error!(e, LiquidityPoolValidationError::FlashLoanNotRepaid);
+            }
+        }
+
+        // flash fee goes to the reserves, admin share is kept aside
+        Self::_update_price_cumulative(&e);
+        for i in 0..n_coins {
+            let coin_fee = fees.get(i).unwrap();
+            let admin_fee = coin_fee.fixed_mul_floor(
+                &e,
+                &(get_admin_fee(&e) as u128),
+                &(FEE_DENOMINATOR as u128),
+            );
+            Self::_accrue_admin_fee(&e, i, admin_fee);
+            reserves.set(i, reserves.get(i).unwrap() + coin_fee - admin_fee);
+        }
+        put_reserves(&e, &reserves);
+
+        // update plane data for every pool update
+        update_plane(&e);
+
+        PoolEvents::new(&e).flash_loan(receiver, tokens, amounts, fees.clone());
+
+        fees
+    }
+}
+
+// The `ReservesSyncTrait` trait provides the interface for reconciling the reserves with actual coin balances.
+#[contractimpl]
+impl ReservesSyncTrait for LiquidityPool {
+    // Sends coin balances exceeding the reserves to the given address.
+    // Coins sent to the pool directly are recovered this way.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `to` - The address receiving the coins.
+    //
+    // # Returns
+    //
+    // A vector of the amounts of each coin sent.
+    fn skim(e: Env, admin: Address, to: Address) -> Vec<u128> {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+
+        let tokens = get_tokens(&e);
+        let amounts = Self::_get_excess_balances(&e);
+        for i in 0..tokens.len() {
+            let amount = amounts.get(i).unwrap();
+            if amount > 0 {
+                SorobanTokenClient::new(&e, &tokens.get(i).unwrap()).transfer(
+                    &e.current_contract_address(),
+                    &to,
+                    &(amount as i128),
+                );
+            }
+        }
+
+        Events::new(&e).skim(to, amounts.clone());
+        amounts
+    }
+
+    // Adds coin balances exceeding the reserves to the reserves, distributing them to liquidity providers.
+    //
+    // # Returns
+    //
+    // A vector of the pool's reserves after the update.
+    fn sync(e: Env) -> Vec<u128> {
+        // donation to the empty pool would break the shares calculation for the first depositor
+        if get_total_shares(&e) == 0 {
+            panic_with_error!(&e, LiquidityPoolValidationError::EmptyPool);
+        }
+
+        let excess = Self::_get_excess_balances(&e);
+        let mut reserves = get_reserves(&e);
+        for i in 0..reserves.len() {
+            reserves.set(i, reserves.get(i).unwrap() + excess.get(i).unwrap());
+        }
+        Self::_update_price_cumulative(&e);
+        put_reserves(&e, &reserves);
+
+        // update plane data for every pool update
+        update_plane(&e);
+
+        Events::new(&e).sync(reserves.clone());
+        reserves
+    }
+}
+
 // The `UpgradeableContract` trait provides the interface for upgrading the contract.
 #[contractimpl]
 impl UpgradeableContract for LiquidityPool {
@@ -1728,6 +2216,43 @@ impl UpgradeableContract for LiquidityPool {
     }
 }
 
+// The `EmergencyQuorumContract` trait provides the interface for emergency mode quorum.
+#[contractimpl]
+impl EmergencyQuorumContract for LiquidityPool {
+    // Configures the number of emergency admins votes required to enable the emergency mode.
+    // Once quorum is configured, the emergency admin is no longer able to enable the emergency mode alone.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `threshold` - The number of votes required. Zero disables the quorum.
+    fn set_emergency_quorum(e: Env, admin: Address, threshold: u32) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        set_emergency_quorum(&e, threshold);
+    }
+
+    // Returns the emergency mode voters (emergency admin and `EmergencyVoter` role holders) and votes threshold.
+    fn get_emergency_quorum(e: Env) -> (Vec<Address>, u32) {
+        get_emergency_quorum(&e)
+    }
+
+    // Votes for the emergency mode. The emergency mode is enabled once the threshold is reached.
+    //
+    // # Arguments
+    //
+    // * `voter` - The address of the voter.
+    fn vote_emergency_mode(e: Env, voter: Address) {
+        voter.require_auth();
+        vote_emergency_mode(&e, &voter);
+    }
+
+    // Returns the timestamp at which the emergency mode expires. Zero if it's not active.
+    fn get_emergency_mode_expiration(e: Env) -> u64 {
+        get_emergency_mode_expiration(&e)
+    }
+}
+
 #[contractimpl]
 impl UpgradeableLPTokenTrait for LiquidityPool {
     // legacy upgrade. not compatible with token contract version 140+ due to different arguments
@@ -1805,7 +2330,7 @@ impl RewardsTrait for LiquidityPool {
         }
 
         let rewards = get_rewards_manager(&e);
-        let total_shares = get_total_shares(&e);
+        let total_shares = get_rewards_total_shares(&e);
         rewards
             .manager()
             .set_reward_config(total_shares, expired_at, tps);
@@ -1815,10 +2340,7 @@ impl RewardsTrait for LiquidityPool {
     // Get difference between the actual balance and the total unclaimed reward minus the reserves
     fn get_unused_reward(e: Env) -> u128 {
         let rewards = get_rewards_manager(&e);
-        let mut rewards_manager = rewards.manager();
-        let total_shares = get_total_shares(&e);
-        let mut reward_balance_to_keep = rewards_manager.get_total_configured_reward(total_shares)
-            - rewards_manager.get_total_claimed_reward(total_shares);
+        let mut reward_balance_to_keep = Self::_get_reward_liability(&e);
 
         let reward_token = rewards.storage().get_reward_token();
         let reward_balance = SorobanTokenClient::new(&e, &reward_token)
@@ -1827,7 +2349,9 @@ impl RewardsTrait for LiquidityPool {
         match get_tokens(&e).first_index_of(reward_token) {
             Some(idx) => {
                 // since reward token is in the reserves, we need to keep also the reserves value
+                // and the admin fees not yet withdrawn
                 reward_balance_to_keep += get_reserves(&e).get(idx).unwrap();
+                reward_balance_to_keep += get_admin_fee_reserves(&e).get(idx).unwrap();
             }
             None => {}
         };
@@ -1871,7 +2395,7 @@ impl RewardsTrait for LiquidityPool {
         let mut manager = rewards.manager();
         let storage = rewards.storage();
         let config = storage.get_pool_reward_config();
-        let total_shares = get_total_shares(&e);
+        let total_shares = get_rewards_total_shares(&e);
         let user_shares = get_user_balance_shares(&e, &user);
 
         // pre-fill result dict with stored values
@@ -1941,7 +2465,7 @@ impl RewardsTrait for LiquidityPool {
     // The amount of reward tokens available for the user to claim as a u128.
     fn get_user_reward(e: Env, user: Address) -> u128 {
         let rewards = get_rewards_manager(&e);
-        let total_shares = get_total_shares(&e);
+        let total_shares = get_rewards_total_shares(&e);
         let user_shares = get_user_balance_shares(&e, &user);
         rewards
             .manager()
@@ -1958,7 +2482,7 @@ impl RewardsTrait for LiquidityPool {
             panic_with_error!(&e, AccessControlError::Unauthorized);
         }
         let rewards = get_rewards_manager(&e);
-        let total_shares = get_total_shares(&e);
+        let total_shares = get_rewards_total_shares(&e);
         rewards
             .manager()
             .checkpoint_user(&user, total_shares, user_shares);
@@ -1979,7 +2503,7 @@ impl RewardsTrait for LiquidityPool {
             panic_with_error!(&e, AccessControlError::Unauthorized);
         }
         let rewards = get_rewards_manager(&e);
-        let total_shares = get_total_shares(&e);
+        let total_shares = get_rewards_total_shares(&e);
         rewards
             .manager()
             .update_working_balance(&user, total_shares, user_shares);
@@ -1996,7 +2520,7 @@ impl RewardsTrait for LiquidityPool {
     // The total amount of accumulated reward for the pool as a u128.
     fn get_total_accumulated_reward(e: Env) -> u128 {
         let rewards = get_rewards_manager(&e);
-        let total_shares = get_total_shares(&e);
+        let total_shares = get_rewards_total_shares(&e);
         rewards.manager().get_total_accumulated_reward(total_shares)
     }
 
@@ -2011,7 +2535,7 @@ impl RewardsTrait for LiquidityPool {
     // The total amount of configured reward for the pool as a u128.
     fn get_total_configured_reward(e: Env) -> u128 {
         let rewards = get_rewards_manager(&e);
-        let total_shares = get_total_shares(&e);
+        let total_shares = get_rewards_total_shares(&e);
         rewards.manager().get_total_configured_reward(total_shares)
     }
 
@@ -2026,7 +2550,7 @@ impl RewardsTrait for LiquidityPool {
     // The total amount of claimed reward for the pool as a u128.
     fn get_total_claimed_reward(e: Env) -> u128 {
         let rewards = get_rewards_manager(&e);
-        let total_shares = get_total_shares(&e);
+        let total_shares = get_rewards_total_shares(&e);
         rewards.manager().get_total_claimed_reward(total_shares)
     }
 
@@ -2046,7 +2570,7 @@ impl RewardsTrait for LiquidityPool {
         }
 
         let rewards = get_rewards_manager(&e);
-        let total_shares = get_total_shares(&e);
+        let total_shares = get_rewards_total_shares(&e);
         let user_shares = get_user_balance_shares(&e, &user);
         let mut rewards_manager = rewards.manager();
         let rewards_storage = rewards.storage();
@@ -2133,6 +2657,9 @@ impl TransferableContract for LiquidityPool {
     // * `role_name` - The name of the role to transfer ownership of. The role must be one of the following:
     //     * `Admin`
     //     * `EmergencyAdmin`
+    //     * `RewardsAdmin` (if transfer delay is enabled)
+    //     * `OperationsAdmin` (if transfer delay is enabled)
+    //     * `PauseAdmin` (if transfer delay is enabled)
     // * `new_address` - New address for the role
     fn commit_transfer_ownership(e: Env, admin: Address, role_name: Symbol, new_address: Address) {
         admin.require_auth();
@@ -2152,6 +2679,9 @@ impl TransferableContract for LiquidityPool {
     // * `role_name` - The name of the role to transfer ownership of. The role must be one of the following:
     //     * `Admin`
     //     * `EmergencyAdmin`
+    //     * `RewardsAdmin` (if transfer delay is enabled)
+    //     * `OperationsAdmin` (if transfer delay is enabled)
+    //     * `PauseAdmin` (if transfer delay is enabled)
     fn apply_transfer_ownership(e: Env, admin: Address, role_name: Symbol) {
         admin.require_auth();
         let access_control = AccessControl::new(&e);
@@ -2170,6 +2700,9 @@ impl TransferableContract for LiquidityPool {
     // * `role_name` - The name of the role to transfer ownership of. The role must be one of the following:
     //     * `Admin`
     //     * `EmergencyAdmin`
+    //     * `RewardsAdmin` (if transfer delay is enabled)
+    //     * `OperationsAdmin` (if transfer delay is enabled)
+    //     * `PauseAdmin` (if transfer delay is enabled)
     fn revert_transfer_ownership(e: Env, admin: Address, role_name: Symbol) {
         admin.require_auth();
         let access_control = AccessControl::new(&e);
@@ -2180,6 +2713,23 @@ impl TransferableContract for LiquidityPool {
         AccessControlEvents::new(&e).revert_transfer_ownership(role);
     }
 
+    // Accepts the committed ownership transfer.
+    // Alternative to `apply_transfer_ownership` which has to be signed by the future address,
+    // proving it controls its key before taking over the role.
+    //
+    // # Arguments
+    //
+    // * `new_address` - The future address of the role.
+    // * `role_name` - The name of the role to transfer ownership of.
+    fn accept_ownership(e: Env, new_address: Address, role_name: Symbol) {
+        new_address.require_auth();
+        let access_control = AccessControl::new(&e);
+
+        let role = Role::from_symbol(&e, role_name);
+        access_control.accept_transfer_ownership(&role, &new_address);
+        AccessControlEvents::new(&e).accept_transfer_ownership(role, new_address);
+    }
+
     // Returns the future address for the role.
     // The future address is the address that the ownership of the role will be transferred to.
     // The future address is set using the `commit_transfer_ownership` function.
@@ -2190,6 +2740,9 @@ impl TransferableContract for LiquidityPool {
     // * `role_name` - The name of the role to get the future address for. The role must be one of the following:
     //    * `Admin`
     //    * `EmergencyAdmin`
+    //    * `RewardsAdmin` (if transfer delay is enabled)
+    //    * `OperationsAdmin` (if transfer delay is enabled)
+    //    * `PauseAdmin` (if transfer delay is enabled)
     fn get_future_address(e: Env, role_name: Symbol) -> Address {
         let access_control = AccessControl::new(&e);
         let role = Role::from_symbol(&e, role_name);
@@ -2202,3 +2755,283 @@ impl TransferableContract for LiquidityPool {
         }
     }
 }
+
+// The `DelayedRolesContract` trait provides the interface for delayed changes of privileged roles.
+#[contractimpl]
+impl DelayedRolesContract for LiquidityPool {
+    // Enables transfer delay for the privileged role.
+    // Once enabled, the role can only be changed through the commit/apply flow
+    // and the delay cannot be disabled.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `role_name` - The name of the role. The role must be one of the following:
+    //     * `RewardsAdmin`
+    //     * `OperationsAdmin`
+    //     * `PauseAdmin`
+    //     * `EmergencyPauseAdmin`
+    fn enable_transfer_delay(e: Env, admin: Address, role_name: Symbol) {
+        admin.require_auth();
+        let access_control = AccessControl::new(&e);
+        access_control.assert_address_has_role(&admin, &Role::Admin);
+
+        let role = Role::from_symbol(&e, role_name);
+        access_control.enable_transfer_delay(&role);
+        AccessControlEvents::new(&e).enable_transfer_delay(role);
+    }
+
+    // Returns whether the role can be changed only through the commit/apply flow.
+    //
+    // # Arguments
+    //
+    // * `role_name` - The name of the role.
+    fn get_transfer_delay_enabled(e: Env, role_name: Symbol) -> bool {
+        let role = Role::from_symbol(&e, role_name);
+        AccessControl::new(&e).is_transfer_delayed(&role)
+    }
+
+    // Commits a change of the multiple addresses role.
+    // Pending change can be reverted with `revert_transfer_ownership`.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `role_name` - The name of the role. The role must be one of the following:
+    //     * `EmergencyPauseAdmin` (if transfer delay is enabled)
+    // * `new_addresses` - New addresses for the role
+    fn commit_transfer_addresses(
+        e: Env,
+        admin: Address,
+        role_name: Symbol,
+        new_addresses: Vec<Address>,
+    ) {
+        admin.require_auth();
+        let access_control = AccessControl::new(&e);
+        access_control.assert_address_has_role(&admin, &Role::Admin);
+
+        let role = Role::from_symbol(&e, role_name);
+        access_control.commit_transfer_addresses(&role, &new_addresses);
+        AccessControlEvents::new(&e).commit_transfer_addresses(role, new_addresses);
+    }
+
+    // Applies the committed change of the multiple addresses role.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `role_name` - The name of the role. The role must be one of the following:
+    //     * `EmergencyPauseAdmin`
+    fn apply_transfer_addresses(e: Env, admin: Address, role_name: Symbol) {
+        admin.require_auth();
+        let access_control = AccessControl::new(&e);
+        access_control.assert_address_has_role(&admin, &Role::Admin);
+
+        let role = Role::from_symbol(&e, role_name);
+        let new_addresses = access_control.apply_transfer_addresses(&role);
+        AccessControlEvents::new(&e).apply_transfer_addresses(role, new_addresses);
+    }
+
+    // Returns the future addresses for the multiple addresses role.
+    // The addresses will be defaulted to the current ones if the change is not committed.
+    //
+    // # Arguments
+    //
+    // * `role_name` - The name of the role. The role must be one of the following:
+    //     * `EmergencyPauseAdmin`
+    fn get_future_addresses(e: Env, role_name: Symbol) -> Vec<Address> {
+        let access_control = AccessControl::new(&e);
+        let role = Role::from_symbol(&e, role_name);
+        match access_control.get_transfer_ownership_deadline(&role) {
+            0 => access_control.get_role_addresses(&role),
+            _ => access_control.get_future_addresses(&role),
+        }
+    }
+}
+
+// The `RolesRegistryContract` trait provides the interface for managing roles declared at runtime.
+#[contractimpl]
+impl RolesRegistryContract for LiquidityPool {
+    // Declares a new role. Role flags cannot be changed afterwards.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `role_name` - The name of the role. Must not match any of the built-in roles.
+    // * `has_many_users` - Whether the role is assigned to multiple addresses.
+    // * `is_transfer_delayed` - Whether the role address can be replaced only through
+    //   the commit/apply flow. Not available for multiple addresses roles.
+    fn declare_role(
+        e: Env,
+        admin: Address,
+        role_name: Symbol,
+        has_many_users: bool,
+        is_transfer_delayed: bool,
+    ) {
+        admin.require_auth();
+        let access_control = AccessControl::new(&e);
+        access_control.assert_address_has_role(&admin, &Role::Admin);
+
+        let role = access_control.declare_role(&role_name, has_many_users, is_transfer_delayed);
+        AccessControlEvents::new(&e).declare_role(role, has_many_users, is_transfer_delayed);
+    }
+
+    // Returns the names of the roles declared at runtime.
+    fn get_declared_roles(e: Env) -> Vec<Symbol> {
+        AccessControl::new(&e).get_declared_roles()
+    }
+
+    // Sets the addresses of the role.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `role_name` - The name of the role.
+    // * `addresses` - The addresses of the role. Single address roles expect exactly one address.
+    fn set_role_addrs(e: Env, admin: Address, role_name: Symbol, addresses: Vec<Address>) {
+        admin.require_auth();
+        let access_control = AccessControl::new(&e);
+        access_control.assert_address_has_role(&admin, &Role::Admin);
+
+        let role = Role::from_symbol(&e, role_name);
+        access_control.set_role_addrs(&role, &addresses);
+        AccessControlEvents::new(&e).set_role_addrs(role, addresses);
+    }
+
+    // Returns the addresses of the role.
+    //
+    // # Arguments
+    //
+    // * `role_name` - The name of the role.
+    fn get_role_addrs(e: Env, role_name: Symbol) -> Vec<Address> {
+        let role = Role::from_symbol(&e, role_name);
+        AccessControl::new(&e).get_role_addrs(&role)
+    }
+}
+
+// The `RoleGrantsContract` trait provides the interface for managing role grants lifetime.
+#[contractimpl]
+impl RoleGrantsContract for LiquidityPool {
+    // Gives up the role. The address won't hold the role anymore.
+    // For multiple addresses roles only the given address is removed.
+    //
+    // # Arguments
+    //
+    // * `address` - The address of the role holder.
+    // * `role_name` - The name of the role. Admin role cannot be renounced.
+    fn renounce_role(e: Env, address: Address, role_name: Symbol) {
+        address.require_auth();
+        let role = Role::from_symbol(&e, role_name);
+        AccessControl::new(&e).renounce_role(&role, &address);
+        AccessControlEvents::new(&e).renounce_role(role, address);
+    }
+
+    // Sets the grant expiration timestamp. Once expired, the address is no longer authorized for the role.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `role_name` - The name of the role. Admin, EmergencyAdmin and delayed roles grants cannot expire.
+    // * `address` - The address of the role holder.
+    // * `expires_at` - The expiration timestamp, not earlier than the role transfer delay from now.
+    //   Zero means grant never expires.
+    fn set_role_expiry(
+        e: Env,
+        admin: Address,
+        role_name: Symbol,
+        address: Address,
+        expires_at: u64,
+    ) {
+        admin.require_auth();
+        let access_control = AccessControl::new(&e);
+        access_control.assert_address_has_role(&admin, &Role::Admin);
+
+        let role = Role::from_symbol(&e, role_name);
+        access_control.set_role_expiry(&role, &address, expires_at);
+        AccessControlEvents::new(&e).set_role_expiry(role, address, expires_at);
+    }
+
+    // Returns the grant expiration timestamp. Zero means grant never expires.
+    //
+    // # Arguments
+    //
+    // * `role_name` - The name of the role.
+    // * `address` - The address of the role holder.
+    fn get_role_expiry(e: Env, role_name: Symbol, address: Address) -> u64 {
+        let role = Role::from_symbol(&e, role_name);
+        AccessControl::new(&e).get_role_expiry(&role, &address)
+    }
+}
+
+// The `AuditLogContract` trait provides the interface for querying history of privileged actions.
+#[contractimpl]
+impl AuditLogContract for LiquidityPool {
+    // Returns the total number of privileged actions recorded.
+    // Only the last `AUDIT_LOG_SIZE` records are kept.
+    fn get_audit_log_len(e: Env) -> u32 {
+        get_audit_log_len(&e)
+    }
+
+    // Returns the recorded privileged actions, oldest first.
+    //
+    // # Arguments
+    //
+    // * `start` - The sequence number of the first record.
+    // * `limit` - The maximum number of records to return.
+    fn get_audit_log(e: Env, start: u32, limit: u32) -> Vec<AuditRecord> {
+        get_audit_log(&e, start, limit)
+    }
+}
+
+// The `ConfigurableDelaysContract` trait provides the interface for configuring admin actions delays.
+#[contractimpl]
+impl ConfigurableDelaysContract for LiquidityPool {
+    // Returns the current delay for the target.
+    //
+    // # Arguments
+    //
+    // * `target` - The delay target: admin actions, upgrade or specific role transfer.
+    fn get_delay(e: Env, target: DelayTarget) -> u64 {
+        get_delay(&e, &target)
+    }
+
+    // Commits the delay change. The change may be applied once the current delay passes.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `target` - The delay target: admin actions, upgrade or specific role transfer.
+    // * `value` - The new delay in seconds.
+    fn commit_delay(e: Env, admin: Address, target: DelayTarget, value: u64) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        commit_delay(&e, &target, value);
+        AccessControlEvents::new(&e).commit_delay(target, value);
+    }
+
+    // Applies the committed delay change.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `target` - The delay target.
+    fn apply_delay(e: Env, admin: Address, target: DelayTarget) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        let value = apply_delay(&e, &target);
+        AccessControlEvents::new(&e).apply_delay(target, value);
+    }
+
+    // Reverts the committed delay change.
+    //
+    // # Arguments
+    //
+    // * `admin` - The address of the admin.
+    // * `target` - The delay target.
+    fn revert_delay(e: Env, admin: Address, target: DelayTarget) {
+        admin.require_auth();
+        AccessControl::new(&e).assert_address_has_role(&admin, &Role::Admin);
+        revert_delay(&e, &target);
+        AccessControlEvents::new(&e).revert_delay(target);
+    }
+}
@@ -1,4 +1,4 @@
-use soroban_sdk::{Env, Symbol};
+use soroban_sdk::{Address, Env, Symbol, Vec};
 
 #[derive(Clone)]
 pub struct Events(Env);
@@ -26,6 +26,37 @@ impl Events {
             .publish((Symbol::new(self.env(), "apply_new_fee"),), (new_fee,))
     }
 
+    pub fn commit_new_admin_fee(&self, new_admin_fee: u32) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "commit_new_admin_fee"),),
+            (new_admin_fee,),
+        )
+    }
+
+    pub fn apply_new_admin_fee(&self, new_admin_fee: u32) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "apply_new_admin_fee"),),
+            (new_admin_fee,),
+        )
+    }
+
+    pub fn set_fees_collector(&self, fees_collector: Address) {
+        self.env().events().publish(
+            (Symbol::new(self.env(), "set_fees_collector"),),
+            (fees_collector,),
+        )
+    }
+
+    pub fn withdraw_admin_fees(&self, fees_collector: Address, amounts: Vec<u128>) {
+        self.env().events().publish(
+            (
+                Symbol::new(self.env(), "withdraw_admin_fees"),
+                fees_collector,
+            ),
+            (amounts,),
+        )
+    }
+
     pub fn revert_new_parameters(&self) {
         self.env()
             .events()
@@ -44,4 +75,16 @@ impl Events {
             .events()
             .publish((Symbol::new(self.env(), "stop_ramp_a"),), (current_a,))
     }
+
+    pub fn skim(&self, to: Address, amounts: Vec<u128>) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "skim"), to), (amounts,))
+    }
+
+    pub fn sync(&self, reserves: Vec<u128>) {
+        self.env()
+            .events()
+            .publish((Symbol::new(self.env(), "sync"),), (reserves,))
+    }
 }
@@ -10,7 +10,7 @@ pub use crate::plane::pool_plane::Client as PoolPlaneClient;
 use crate::normalize::xp;
 use crate::storage::{
     get_fee, get_future_a, get_future_a_time, get_initial_a, get_initial_a_time, get_plane,
-    get_reserves,
+    get_price_cumulative, get_price_timestamp_last, get_reserves,
 };
 use soroban_sdk::{symbol_short, Env, Vec};
 
@@ -32,10 +32,16 @@ fn get_pool_data(e: &Env) -> (Vec<u128>, Vec<u128>) {
 
 pub fn update_plane(e: &Env) {
     let (init_args, reserves) = get_pool_data(e);
-    PoolPlaneClient::new(e, &get_plane(e)).update(
+    let plane_client = PoolPlaneClient::new(e, &get_plane(e));
+    plane_client.update(
         &e.current_contract_address(),
         &symbol_short!("stable"),
         &init_args,
         &reserves,
     );
+    plane_client.update_price_cumulative(
+        &e.current_contract_address(),
+        &get_price_cumulative(e),
+        &get_price_timestamp_last(e),
+    );
 }
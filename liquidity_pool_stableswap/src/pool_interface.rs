@@ -1,4 +1,4 @@
-use soroban_sdk::{Address, BytesN, Env, Map, Symbol, Val, Vec};
+use soroban_sdk::{Address, Bytes, BytesN, Env, Map, Symbol, Val, Vec, U256};
 
 pub trait ManagedLiquidityPool {
     // Initialize pool completely to reduce calculations cost
@@ -44,6 +44,15 @@ pub trait LiquidityPoolInterfaceTrait {
     // Getter for the pool balances array.
     fn get_reserves(e: Env) -> Vec<u128>;
 
+    // Getter for the cumulative marginal prices of every token in token 0, scaled by 1e18.
+    fn get_price_cumulative(e: Env) -> Vec<U256>;
+
+    // Getter for the timestamp of the last cumulative prices update.
+    fn get_price_timestamp_last(e: Env) -> u64;
+
+    // Calculate marginal prices of every token in token 0 for the given reserves, scaled by 1e18.
+    fn calc_prices(e: Env, reserves: Vec<u128>) -> Vec<U256>;
+
     // Getter for the array of swappable coins within the pool.
     fn get_tokens(e: Env) -> Vec<Address>;
 
@@ -212,9 +221,16 @@ pub trait AdminInterfaceTrait {
     // Set new fee to be applied in future
     fn commit_new_fee(e: Env, admin: Address, new_fee: u32);
 
-    // Apply committed fee
+    // Set new admin fee share to be applied in future
+    fn commit_new_admin_fee(e: Env, admin: Address, new_admin_fee: u32);
+
+    // Apply committed fee and admin fee share
     fn apply_new_fee(e: Env, admin: Address);
 
+    // Set address receiving the withdrawn admin fees
+    fn set_fees_collector(e: Env, admin: Address, fees_collector: Address);
+    fn get_fees_collector(e: Env) -> Address;
+
     // Revert committed parameters to current values
     fn revert_new_parameters(e: Env, admin: Address);
 
@@ -287,4 +303,31 @@ pub trait LiquidityPoolTrait:
         i: u32,
         min_amount: u128,
     ) -> Vec<u128>;
+
+    // Share of the trading fee reserved for the protocol, with 1e4 precision.
+    fn get_admin_fee(e: Env) -> u32;
+
+    // Amounts of admin fees accrued per coin and not yet withdrawn.
+    fn get_admin_fee_reserves(e: Env) -> Vec<u128>;
+
+    // Transfer accrued admin fees to the fees collector. Can be called by anyone.
+    // Returns the amounts of each coin transferred.
+    fn withdraw_admin_fees(e: Env) -> Vec<u128>;
+}
+
+pub trait FlashLoanTrait {
+    // Lend pool coins to the receiver contract, invoking `on_flash_loan(tokens, amounts, fees, data)` on it.
+    // Borrowed amounts plus flash fees should be returned to the pool by the end of the callback.
+    // Returns flash fees charged per coin.
+    fn flash_loan(e: Env, receiver: Address, amounts: Vec<u128>, data: Bytes) -> Vec<u128>;
+}
+
+pub trait ReservesSyncTrait {
+    // Send coin balances not accounted in the reserves, admin fees or rewards to the given address.
+    // Returns the amounts of each coin sent.
+    fn skim(e: Env, admin: Address, to: Address) -> Vec<u128>;
+
+    // Add coin balances not accounted in the reserves, admin fees or rewards to the reserves.
+    // Can be called by anyone. Returns the updated reserves.
+    fn sync(e: Env) -> Vec<u128>;
 }
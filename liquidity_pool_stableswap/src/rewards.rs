@@ -1,5 +1,6 @@
 use rewards::Rewards;
 use soroban_sdk::Env;
+use token_share::{get_locked_shares, get_total_shares};
 
 // page size of 100 is optimal since 8 bytes key + 16 bytes value * 100 = 2400 bytes per page
 // it gives us up to 26 aggregation layers
@@ -12,3 +13,8 @@ pub(crate) const PAGE_SIZE: u64 = 5;
 pub(crate) fn get_rewards_manager(e: &Env) -> Rewards {
     Rewards::new(e, PAGE_SIZE)
 }
+
+// Shares locked on the first deposit have no owner to claim rewards, so they're excluded from the rewards supply
+pub(crate) fn get_rewards_total_shares(e: &Env) -> u128 {
+    get_total_shares(e) - get_locked_shares(e)
+}
@@ -1,10 +1,11 @@
 use paste::paste;
-use soroban_sdk::{contracttype, panic_with_error, Address, BytesN, Env, Vec};
+use soroban_sdk::{contracttype, panic_with_error, Address, BytesN, Env, Vec, U256};
 
 use crate::normalize;
 use rewards::utils::bump::bump_instance;
 use utils::storage_errors::StorageError;
 use utils::{
+    generate_instance_storage_getter, generate_instance_storage_getter_and_setter,
     generate_instance_storage_getter_and_setter_with_default,
     generate_instance_storage_getter_with_default, generate_instance_storage_setter,
 };
@@ -23,6 +24,8 @@ enum DataKey {
     FutureFee,
     AdminFee,
     FutureAdminFee,
+    AdminFeeReserves,
+    FeesCollector,
     AdminActionsDeadline,
     IsKilledSwap,
     IsKilledDeposit,
@@ -30,6 +33,8 @@ enum DataKey {
     Plane,
     Router,
     TokenFutureWASM,
+    PriceCumulative, // marginal prices of tokens in token 0, scaled by PRICE_PRECISION
+    PriceTimestampLast,
 
     // Tokens precision
     Precision, // target precision for internal calculations. It's the maximum precision of all tokens.
@@ -182,6 +187,73 @@ pub fn put_future_fee(e: &Env, value: &u32) {
     e.storage().instance().set(&DataKey::FutureFee, value);
 }
 
+// admin_fee - fraction of the trading fee reserved for the protocol
+generate_instance_storage_getter_and_setter_with_default!(admin_fee, DataKey::AdminFee, u32, 0);
+generate_instance_storage_getter_and_setter_with_default!(
+    future_admin_fee,
+    DataKey::FutureAdminFee,
+    u32,
+    0
+);
+
+// admin fee reserves - protocol fees accrued per token, excluded from the pool reserves
+pub fn get_admin_fee_reserves(e: &Env) -> Vec<u128> {
+    bump_instance(e);
+    match e.storage().instance().get(&DataKey::AdminFeeReserves) {
+        Some(v) => v,
+        None => {
+            let mut amounts = Vec::new(e);
+            for _ in 0..get_tokens(e).len() {
+                amounts.push_back(0_u128);
+            }
+            amounts
+        }
+    }
+}
+
+pub fn put_admin_fee_reserves(e: &Env, amounts: &Vec<u128>) {
+    bump_instance(e);
+    e.storage()
+        .instance()
+        .set(&DataKey::AdminFeeReserves, amounts);
+}
+
+// fees collector - destination of the withdrawn admin fees
+generate_instance_storage_getter_and_setter!(fees_collector, DataKey::FeesCollector, Address);
+
+// cumulative prices - time-weighted marginal prices of every token in token 0
+pub fn get_price_cumulative(e: &Env) -> Vec<U256> {
+    bump_instance(e);
+    match e.storage().instance().get(&DataKey::PriceCumulative) {
+        Some(v) => v,
+        None => {
+            let mut values = Vec::new(e);
+            for _ in 0..get_tokens(e).len() {
+                values.push_back(U256::from_u32(e, 0));
+            }
+            values
+        }
+    }
+}
+
+pub fn put_price_cumulative(e: &Env, values: &Vec<U256>) {
+    bump_instance(e);
+    e.storage()
+        .instance()
+        .set(&DataKey::PriceCumulative, values);
+}
+
+generate_instance_storage_getter_and_setter_with_default!(
+    price_timestamp_last,
+    DataKey::PriceTimestampLast,
+    u64,
+    0
+);
+
+pub fn has_price_timestamp_last(e: &Env) -> bool {
+    e.storage().instance().has(&DataKey::PriceTimestampLast)
+}
+
 // admin_actions_deadline
 pub fn get_admin_actions_deadline(e: &Env) -> u64 {
     bump_instance(e);
@@ -1,17 +1,17 @@
 #![cfg(test)]
 extern crate std;
 
-use crate::pool_constants::MIN_RAMP_TIME;
+use crate::pool_constants::{MINIMUM_LIQUIDITY, MIN_RAMP_TIME};
 use core::cmp::min;
 use rewards::utils::test_utils::assert_approx_eq_abs;
 use soroban_sdk::testutils::{Address as _, Events};
-use soroban_sdk::{symbol_short, vec, Address, Env, Error, IntoVal, Symbol, Val, Vec};
+use soroban_sdk::{symbol_short, vec, Address, Bytes, Env, Error, IntoVal, Symbol, Val, Vec, U256};
 use token_share::Client as ShareTokenClient;
 
 use crate::testutils::{
-    create_liqpool_contract, create_plane_contract, create_reward_boost_feed_contract,
-    create_token_contract, get_token_admin_client, install_token_wasm,
-    install_token_wasm_with_decimal, Setup, TestConfig,
+    create_flash_loan_receiver, create_liqpool_contract, create_plane_contract,
+    create_reward_boost_feed_contract, create_token_contract, get_token_admin_client,
+    install_token_wasm, install_token_wasm_with_decimal, Setup, TestConfig,
 };
 use access_control::constants::ADMIN_ACTIONS_DELAY;
 use soroban_sdk::token::{
@@ -128,11 +128,15 @@ fn test_happy_flow() {
     let total_share_token_amount = 400_0000000_u128; // share amount after two deposits
 
     assert_eq!(calculated_amount as u128, total_share_token_amount / 2 / 10);
+    // minimum liquidity is locked by the pool on the first deposit
     assert_eq!(
         token_share.balance(&user1) as u128,
-        total_share_token_amount
+        total_share_token_amount - MINIMUM_LIQUIDITY
+    );
+    assert_eq!(
+        token_share.balance(&liqpool.address) as u128,
+        MINIMUM_LIQUIDITY
     );
-    assert_eq!(token_share.balance(&liqpool.address) as u128, 0);
     assert_eq!(token1.balance(&user1) as u128, 800_0000000);
     assert_eq!(token1.balance(&liqpool.address) as u128, 200_0000000);
     assert_eq!(token2.balance(&user1) as u128, 800_0000000);
@@ -156,24 +160,32 @@ fn test_happy_flow() {
     assert_eq!(token2.balance(&user1) as u128, 903_9818633);
     assert_eq!(
         token_share.balance(&user1) as u128,
-        total_share_token_amount / 2
+        total_share_token_amount / 2 - MINIMUM_LIQUIDITY
     );
     assert_eq!(token1.balance(&liqpool.address) as u128, 105_0000000);
     assert_eq!(token2.balance(&liqpool.address) as u128, 96_0181367);
-    assert_eq!(token_share.balance(&liqpool.address) as u128, 0);
+    assert_eq!(
+        token_share.balance(&liqpool.address) as u128,
+        MINIMUM_LIQUIDITY
+    );
 
     liqpool.withdraw(
         &user1,
-        &(total_share_token_amount / 2),
+        &(total_share_token_amount / 2 - MINIMUM_LIQUIDITY),
         &Vec::from_array(&e, [0, 0]),
     );
 
-    assert_eq!(token1.balance(&user1) as u128, 1000_0000000);
-    assert_eq!(token2.balance(&user1) as u128, 1000_0000000);
+    // share of the locked minimum liquidity stays in the pool
+
+    assert_eq!(token1.balance(&user1) as u128, 1000_0000000 - 525);
+    assert_eq!(token2.balance(&user1) as u128, 1000_0000000 - 481);
     assert_eq!(token_share.balance(&user1) as u128, 0);
-    assert_eq!(token1.balance(&liqpool.address) as u128, 0);
-    assert_eq!(token2.balance(&liqpool.address) as u128, 0);
-    assert_eq!(token_share.balance(&liqpool.address) as u128, 0);
+    assert_eq!(token1.balance(&liqpool.address) as u128, 525);
+    assert_eq!(token2.balance(&liqpool.address) as u128, 481);
+    assert_eq!(
+        token_share.balance(&liqpool.address) as u128,
+        MINIMUM_LIQUIDITY
+    );
 }
 
 #[test]
@@ -362,11 +374,15 @@ fn test_happy_flow_different_decimals() {
     let total_share_token_amount = 400000000000000000000_u128; // share amount after two deposits
 
     assert_eq!(calculated_amount, total_share_token_amount / 2 / 10);
+    // minimum liquidity is locked by the pool on the first deposit
     assert_eq!(
         token_share.balance(&user1) as u128,
-        total_share_token_amount
+        total_share_token_amount - MINIMUM_LIQUIDITY
+    );
+    assert_eq!(
+        token_share.balance(&liqpool.address) as u128,
+        MINIMUM_LIQUIDITY
     );
-    assert_eq!(token_share.balance(&liqpool.address) as u128, 0);
     assert_eq!(token_7.balance(&user1) as u128, 800_0000000);
     assert_eq!(token_7.balance(&liqpool.address) as u128, 200_0000000);
     assert_eq!(token18.balance(&user1) as u128, 800_000000000000000000);
@@ -399,27 +415,38 @@ fn test_happy_flow_different_decimals() {
     assert_eq!(token18.balance(&user1) as u128, 903_981863326370485948);
     assert_eq!(
         token_share.balance(&user1) as u128,
-        total_share_token_amount / 2
+        total_share_token_amount / 2 - MINIMUM_LIQUIDITY
     );
     assert_eq!(token_7.balance(&liqpool.address) as u128, 105_0000000);
     assert_eq!(
         token18.balance(&liqpool.address) as u128,
         96_018136673629514052
     );
-    assert_eq!(token_share.balance(&liqpool.address) as u128, 0);
+    assert_eq!(
+        token_share.balance(&liqpool.address) as u128,
+        MINIMUM_LIQUIDITY
+    );
 
     liqpool.withdraw(
         &user1,
-        &(total_share_token_amount / 2),
+        &(total_share_token_amount / 2 - MINIMUM_LIQUIDITY),
         &Vec::from_array(&e, [0, 0]),
     );
 
-    assert_eq!(token_7.balance(&user1) as u128, 1000_0000000);
-    assert_eq!(token18.balance(&user1) as u128, 1000_000000000000000000);
+    // share of the locked minimum liquidity stays in the pool
+
+    assert_eq!(token_7.balance(&user1) as u128, 1000_0000000 - 1);
+    assert_eq!(
+        token18.balance(&user1) as u128,
+        1000_000000000000000000 - 481
+    );
     assert_eq!(token_share.balance(&user1) as u128, 0);
-    assert_eq!(token_7.balance(&liqpool.address) as u128, 0);
-    assert_eq!(token18.balance(&liqpool.address) as u128, 0);
-    assert_eq!(token_share.balance(&liqpool.address) as u128, 0);
+    assert_eq!(token_7.balance(&liqpool.address) as u128, 1);
+    assert_eq!(token18.balance(&liqpool.address) as u128, 481);
+    assert_eq!(
+        token_share.balance(&liqpool.address) as u128,
+        MINIMUM_LIQUIDITY
+    );
 }
 
 #[test]
@@ -511,7 +538,8 @@ fn test_events_2_tokens() {
         liqpool.deposit(&user1, &Vec::from_array(&e, [100_0000000, 100_0000000]), &0);
     assert_eq!(amounts.get(0).unwrap(), 1000000000);
     assert_eq!(amounts.get(1).unwrap(), 1000000000);
-    assert_eq!(share_amt, 2000000000);
+    // minimum liquidity is locked by the pool on the first deposit
+    assert_eq!(share_amt, 1999999000);
     assert_eq!(
         vec![&e, e.events().all().last().unwrap()],
         vec![
@@ -524,7 +552,7 @@ fn test_events_2_tokens() {
                     token2.address.clone(),
                 )
                     .into_val(&e),
-                (200_0000000_i128, 100_0000000_i128, 100_0000000_i128,).into_val(&e),
+                (share_amt as i128, 100_0000000_i128, 100_0000000_i128,).into_val(&e),
             ),
         ]
     );
@@ -548,9 +576,9 @@ fn test_events_2_tokens() {
         ]
     );
 
-    let amounts_out = liqpool.withdraw(&user1, &200_0000000, &Vec::from_array(&e, [0, 0]));
-    assert_eq!(amounts_out.get(0).unwrap(), 1000000100);
-    assert_eq!(amounts_out.get(1).unwrap(), 999999902);
+    let amounts_out = liqpool.withdraw(&user1, &share_amt, &Vec::from_array(&e, [0, 0]));
+    assert_eq!(amounts_out.get(0).unwrap(), 999999599);
+    assert_eq!(amounts_out.get(1).unwrap(), 999999402);
     assert_eq!(
         vec![&e, e.events().all().last().unwrap()],
         vec![
@@ -564,7 +592,7 @@ fn test_events_2_tokens() {
                 )
                     .into_val(&e),
                 (
-                    200_0000000_i128,
+                    share_amt as i128,
                     amounts_out.get(0).unwrap() as i128,
                     amounts_out.get(1).unwrap() as i128
                 )
@@ -633,7 +661,8 @@ fn test_events_3_tokens() {
     assert_eq!(amounts.get(0).unwrap(), 1000000000);
     assert_eq!(amounts.get(1).unwrap(), 1000000000);
     assert_eq!(amounts.get(2).unwrap(), 1000000000);
-    assert_eq!(share_amt, 3000000000);
+    // minimum liquidity is locked by the pool on the first deposit
+    assert_eq!(share_amt, 2999999000);
     assert_eq!(
         vec![&e, e.events().all().last().unwrap()],
         vec![
@@ -648,7 +677,7 @@ fn test_events_3_tokens() {
                 )
                     .into_val(&e),
                 (
-                    300_0000000_i128,
+                    share_amt as i128,
                     100_0000000_i128,
                     100_0000000_i128,
                     100_0000000_i128,
@@ -677,10 +706,10 @@ fn test_events_3_tokens() {
         ]
     );
 
-    let amounts_out = liqpool.withdraw(&user1, &300_0000000, &Vec::from_array(&e, [0, 0, 0]));
-    assert_eq!(amounts_out.get(0).unwrap(), 1000000100);
-    assert_eq!(amounts_out.get(1).unwrap(), 999999902);
-    assert_eq!(amounts_out.get(2).unwrap(), 1000000000);
+    let amounts_out = liqpool.withdraw(&user1, &share_amt, &Vec::from_array(&e, [0, 0, 0]));
+    assert_eq!(amounts_out.get(0).unwrap(), 999999766);
+    assert_eq!(amounts_out.get(1).unwrap(), 999999568);
+    assert_eq!(amounts_out.get(2).unwrap(), 999999666);
     assert_eq!(
         vec![&e, e.events().all().last().unwrap()],
         vec![
@@ -695,7 +724,7 @@ fn test_events_3_tokens() {
                 )
                     .into_val(&e),
                 (
-                    300_0000000_i128,
+                    share_amt as i128,
                     amounts_out.get(0).unwrap() as i128,
                     amounts_out.get(1).unwrap() as i128,
                     amounts_out.get(2).unwrap() as i128,
@@ -775,7 +804,8 @@ fn test_events_4_tokens() {
     assert_eq!(amounts.get(1).unwrap(), 1000000000);
     assert_eq!(amounts.get(2).unwrap(), 1000000000);
     assert_eq!(amounts.get(3).unwrap(), 1000000000);
-    assert_eq!(share_amt, 4000000000);
+    // minimum liquidity is locked by the pool on the first deposit
+    assert_eq!(share_amt, 3999999000);
     assert_eq!(
         vec![&e, e.events().all().last().unwrap()],
         vec![
@@ -791,7 +821,7 @@ fn test_events_4_tokens() {
                 )
                     .into_val(&e),
                 (
-                    400_0000000_i128,
+                    share_amt as i128,
                     100_0000000_i128,
                     100_0000000_i128,
                     100_0000000_i128,
@@ -821,11 +851,11 @@ fn test_events_4_tokens() {
         ]
     );
 
-    let amounts_out = liqpool.withdraw(&user1, &400_0000000, &Vec::from_array(&e, [0, 0, 0, 0]));
-    assert_eq!(amounts_out.get(0).unwrap(), 1000000100);
-    assert_eq!(amounts_out.get(1).unwrap(), 999999902);
-    assert_eq!(amounts_out.get(2).unwrap(), 1000000000);
-    assert_eq!(amounts_out.get(3).unwrap(), 1000000000);
+    let amounts_out = liqpool.withdraw(&user1, &share_amt, &Vec::from_array(&e, [0, 0, 0, 0]));
+    assert_eq!(amounts_out.get(0).unwrap(), 999999849);
+    assert_eq!(amounts_out.get(1).unwrap(), 999999652);
+    assert_eq!(amounts_out.get(2).unwrap(), 999999750);
+    assert_eq!(amounts_out.get(3).unwrap(), 999999750);
     assert_eq!(
         vec![&e, e.events().all().last().unwrap()],
         vec![
@@ -841,7 +871,7 @@ fn test_events_4_tokens() {
                 )
                     .into_val(&e),
                 (
-                    400_0000000_i128,
+                    share_amt as i128,
                     amounts_out.get(0).unwrap() as i128,
                     amounts_out.get(1).unwrap() as i128,
                     amounts_out.get(2).unwrap() as i128,
@@ -1272,11 +1302,15 @@ fn test_happy_flow_3_tokens() {
     let total_share_token_amount = 600_0000000_u128; // share amount after two deposits
 
     assert_eq!(calculated_amount, total_share_token_amount / 2 / 10);
+    // minimum liquidity is locked by the pool on the first deposit
     assert_eq!(
         token_share.balance(&user1) as u128,
-        total_share_token_amount
+        total_share_token_amount - MINIMUM_LIQUIDITY
+    );
+    assert_eq!(
+        token_share.balance(&liqpool.address) as u128,
+        MINIMUM_LIQUIDITY
     );
-    assert_eq!(token_share.balance(&liqpool.address) as u128, 0);
     assert_eq!(token1.balance(&user1) as u128, 800_0000000);
     assert_eq!(token1.balance(&liqpool.address) as u128, 200_0000000);
     assert_eq!(token2.balance(&user1) as u128, 800_0000000);
@@ -1313,27 +1347,34 @@ fn test_happy_flow_3_tokens() {
     assert_eq!(token3.balance(&user1) as u128, 890_0000000);
     assert_eq!(
         token_share.balance(&user1) as u128,
-        total_share_token_amount / 2
+        total_share_token_amount / 2 - MINIMUM_LIQUIDITY
     );
     assert_eq!(token1.balance(&liqpool.address) as u128, 97_0347794);
     assert_eq!(token2.balance(&liqpool.address) as u128, 96_0181367);
     assert_eq!(token3.balance(&liqpool.address) as u128, 110_0000000);
-    assert_eq!(token_share.balance(&liqpool.address) as u128, 0);
+    assert_eq!(
+        token_share.balance(&liqpool.address) as u128,
+        MINIMUM_LIQUIDITY
+    );
 
     liqpool.withdraw(
         &user1,
-        &((total_share_token_amount as u128) / 2),
+        &((total_share_token_amount as u128) / 2 - MINIMUM_LIQUIDITY),
         &Vec::from_array(&e, [0, 0, 0]),
     );
 
-    assert_eq!(token1.balance(&user1) as u128, 1000_0000000);
-    assert_eq!(token2.balance(&user1) as u128, 1000_0000000);
-    assert_eq!(token3.balance(&user1) as u128, 1000_0000000);
+    // share of the locked minimum liquidity stays in the pool
+    assert_eq!(token1.balance(&user1) as u128, 1000_0000000 - 324);
+    assert_eq!(token2.balance(&user1) as u128, 1000_0000000 - 321);
+    assert_eq!(token3.balance(&user1) as u128, 1000_0000000 - 367);
     assert_eq!(token_share.balance(&user1) as u128, 0);
-    assert_eq!(token1.balance(&liqpool.address) as u128, 0);
-    assert_eq!(token2.balance(&liqpool.address) as u128, 0);
-    assert_eq!(token3.balance(&liqpool.address) as u128, 0);
-    assert_eq!(token_share.balance(&liqpool.address) as u128, 0);
+    assert_eq!(token1.balance(&liqpool.address) as u128, 324);
+    assert_eq!(token2.balance(&liqpool.address) as u128, 321);
+    assert_eq!(token3.balance(&liqpool.address) as u128, 367);
+    assert_eq!(
+        token_share.balance(&liqpool.address) as u128,
+        MINIMUM_LIQUIDITY
+    );
 }
 
 #[test]
@@ -1419,11 +1460,15 @@ fn test_happy_flow_4_tokens() {
     let total_share_token_amount = 800_0000000_u128; // share amount after two deposits
 
     assert_eq!(calculated_amount, total_share_token_amount / 2 / 10);
+    // minimum liquidity is locked by the pool on the first deposit
     assert_eq!(
         token_share.balance(&user1) as u128,
-        total_share_token_amount
+        total_share_token_amount - MINIMUM_LIQUIDITY
+    );
+    assert_eq!(
+        token_share.balance(&liqpool.address) as u128,
+        MINIMUM_LIQUIDITY
     );
-    assert_eq!(token_share.balance(&liqpool.address) as u128, 0);
     assert_eq!(token1.balance(&user1) as u128, 800_0000000);
     assert_eq!(token1.balance(&liqpool.address) as u128, 200_0000000);
     assert_eq!(token2.balance(&user1) as u128, 800_0000000);
@@ -1457,20 +1502,24 @@ fn test_happy_flow_4_tokens() {
 
     liqpool.withdraw(
         &user1,
-        &(total_share_token_amount),
+        &(total_share_token_amount - MINIMUM_LIQUIDITY),
         &Vec::from_array(&e, [0, 0, 0, 0]),
     );
 
-    assert_eq!(token1.balance(&user1) as u128, 1000_0000000);
-    assert_eq!(token2.balance(&user1) as u128, 1000_0000000);
-    assert_eq!(token3.balance(&user1) as u128, 1000_0000000);
-    assert_eq!(token4.balance(&user1) as u128, 1000_0000000);
+    // share of the locked minimum liquidity stays in the pool
+    assert_eq!(token1.balance(&user1) as u128, 1000_0000000 - 243);
+    assert_eq!(token2.balance(&user1) as u128, 1000_0000000 - 241);
+    assert_eq!(token3.balance(&user1) as u128, 1000_0000000 - 250);
+    assert_eq!(token4.balance(&user1) as u128, 1000_0000000 - 275);
     assert_eq!(token_share.balance(&user1) as u128, 0);
-    assert_eq!(token1.balance(&liqpool.address) as u128, 0);
-    assert_eq!(token2.balance(&liqpool.address) as u128, 0);
-    assert_eq!(token3.balance(&liqpool.address) as u128, 0);
-    assert_eq!(token4.balance(&liqpool.address) as u128, 0);
-    assert_eq!(token_share.balance(&liqpool.address) as u128, 0);
+    assert_eq!(token1.balance(&liqpool.address) as u128, 243);
+    assert_eq!(token2.balance(&liqpool.address) as u128, 241);
+    assert_eq!(token3.balance(&liqpool.address) as u128, 250);
+    assert_eq!(token4.balance(&liqpool.address) as u128, 275);
+    assert_eq!(
+        token_share.balance(&liqpool.address) as u128,
+        MINIMUM_LIQUIDITY
+    );
 }
 
 #[test]
@@ -1525,8 +1574,14 @@ fn test_withdraw_partial() {
     liqpool.deposit(&user1, &Vec::from_array(&e, [100_0000000, 100_0000000]), &0);
 
     let share_token_amount = 200_0000000;
-    assert_eq!(token_share.balance(&user1) as u128, share_token_amount);
-    assert_eq!(token_share.balance(&liqpool.address) as u128, 0);
+    assert_eq!(
+        token_share.balance(&user1) as u128,
+        share_token_amount - MINIMUM_LIQUIDITY
+    );
+    assert_eq!(
+        token_share.balance(&liqpool.address) as u128,
+        MINIMUM_LIQUIDITY
+    );
     assert_eq!(token1.balance(&user1) as u128, 900_0000000);
     assert_eq!(token1.balance(&liqpool.address) as u128, 100_0000000);
     assert_eq!(token2.balance(&user1) as u128, 900_0000000);
@@ -1549,11 +1604,14 @@ fn test_withdraw_partial() {
     assert_eq!(token2.balance(&user1) as u128, 936_9364213);
     assert_eq!(
         token_share.balance(&user1) as u128,
-        share_token_amount - (share_token_amount * 30 / 100)
+        share_token_amount - MINIMUM_LIQUIDITY - (share_token_amount * 30 / 100)
     );
     assert_eq!(token1.balance(&liqpool.address) as u128, 77_0000000);
     assert_eq!(token2.balance(&liqpool.address) as u128, 63_0635787);
-    assert_eq!(token_share.balance(&liqpool.address) as u128, 0);
+    assert_eq!(
+        token_share.balance(&liqpool.address) as u128,
+        MINIMUM_LIQUIDITY
+    );
 }
 
 #[test]
@@ -1607,8 +1665,14 @@ fn test_withdraw_one_token() {
     liqpool.deposit(&user1, &Vec::from_array(&e, [100_0000000, 100_0000000]), &0);
 
     let share_token_amount = 200_0000000_u128;
-    assert_eq!(token_share.balance(&user1) as u128, share_token_amount);
-    assert_eq!(token_share.balance(&liqpool.address) as u128, 0);
+    assert_eq!(
+        token_share.balance(&user1) as u128,
+        share_token_amount - MINIMUM_LIQUIDITY
+    );
+    assert_eq!(
+        token_share.balance(&liqpool.address) as u128,
+        MINIMUM_LIQUIDITY
+    );
     assert_eq!(token1.balance(&user1) as u128, 900_0000000);
     assert_eq!(token1.balance(&liqpool.address) as u128, 100_0000000);
     assert_eq!(token2.balance(&user1) as u128, 900_0000000);
@@ -1639,8 +1703,14 @@ fn test_withdraw_one_token() {
     assert_eq!(token1.balance(&liqpool.address) as u128, 8_9564393);
     assert_eq!(token2.balance(&user1) as u128, 900_0000000);
     assert_eq!(token2.balance(&liqpool.address) as u128, 100_0000000);
-    assert_eq!(token_share.balance(&user1) as u128, 100_0000000);
-    assert_eq!(token_share.balance(&liqpool.address) as u128, 0);
+    assert_eq!(
+        token_share.balance(&user1) as u128,
+        100_0000000 - MINIMUM_LIQUIDITY
+    );
+    assert_eq!(
+        token_share.balance(&liqpool.address) as u128,
+        MINIMUM_LIQUIDITY
+    );
 }
 
 #[test]
@@ -1694,8 +1764,14 @@ fn test_withdraw_one_token_different_decimals() {
     );
 
     let share_token_amount = 200_000000000000000000_u128;
-    assert_eq!(token_share.balance(&user1) as u128, share_token_amount);
-    assert_eq!(token_share.balance(&liqpool.address) as u128, 0);
+    assert_eq!(
+        token_share.balance(&user1) as u128,
+        share_token_amount - MINIMUM_LIQUIDITY
+    );
+    assert_eq!(
+        token_share.balance(&liqpool.address) as u128,
+        MINIMUM_LIQUIDITY
+    );
     assert_eq!(token18.balance(&user1) as u128, 900_000000000000000000);
     assert_eq!(
         token18.balance(&liqpool.address) as u128,
@@ -1716,8 +1792,14 @@ fn test_withdraw_one_token_different_decimals() {
     );
     assert_eq!(token7.balance(&user1) as u128, 900_0000000);
     assert_eq!(token7.balance(&liqpool.address) as u128, 100_0000000);
-    assert_eq!(token_share.balance(&user1) as u128, 100_000000000000000000);
-    assert_eq!(token_share.balance(&liqpool.address) as u128, 0);
+    assert_eq!(
+        token_share.balance(&user1) as u128,
+        100_000000000000000000 - MINIMUM_LIQUIDITY
+    );
+    assert_eq!(
+        token_share.balance(&liqpool.address) as u128,
+        MINIMUM_LIQUIDITY
+    );
 }
 
 #[test]
@@ -1752,46 +1834,45 @@ fn test_custom_fee() {
         (1000, 8991824), // fee = 10%
         (3000, 6993641), // fee = 30%
     ] {
-        let plane = create_plane_contract(&e);
-        let liqpool = create_liqpool_contract(
-            &e,
-            &user1,
-            &Address::generate(&e),
-            &install_token_wasm(&e),
-            &Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]),
-            10,
-            fee_config.0,
-            &token_reward.address,
-            &create_token_contract(&e, &Address::generate(&e)).address,
-            &create_reward_boost_feed_contract(
+        // pool reserves can't be reset with full withdraw because of the locked liquidity,
+        // so every swap kind is checked against a fresh pool
+        for strict_receive in [false, true] {
+            let plane = create_plane_contract(&e);
+            let liqpool = create_liqpool_contract(
                 &e,
+                &user1,
                 &Address::generate(&e),
-                &Address::generate(&e),
-                &Address::generate(&e),
-            )
-            .address,
-            &plane.address,
-        );
-        liqpool.deposit(&user1, &Vec::from_array(&e, [100_0000000, 100_0000000]), &0);
-        assert_eq!(liqpool.estimate_swap(&0, &1, &1_0000000), fee_config.1);
-        assert_eq!(liqpool.swap(&user1, &0, &1, &1_0000000, &0), fee_config.1);
-
-        // full withdraw & deposit to reset pool reserves
-        liqpool.withdraw(
-            &user1,
-            &(SorobanTokenClient::new(&e, &liqpool.share_id()).balance(&user1) as u128),
-            &Vec::from_array(&e, [0, 0]),
-        );
-        liqpool.deposit(&user1, &Vec::from_array(&e, [100_0000000, 100_0000000]), &0);
-        assert_eq!(liqpool.estimate_swap(&0, &1, &1_0000000), fee_config.1); // re-check swap result didn't change
-        assert_eq!(
-            liqpool.estimate_swap_strict_receive(&0, &1, &fee_config.1),
-            1_0000000
-        );
-        assert_eq!(
-            liqpool.swap_strict_receive(&user1, &0, &1, &fee_config.1, &1_0000000),
-            1_0000000
-        );
+                &install_token_wasm(&e),
+                &Vec::from_array(&e, [token1.address.clone(), token2.address.clone()]),
+                10,
+                fee_config.0,
+                &token_reward.address,
+                &create_token_contract(&e, &Address::generate(&e)).address,
+                &create_reward_boost_feed_contract(
+                    &e,
+                    &Address::generate(&e),
+                    &Address::generate(&e),
+                    &Address::generate(&e),
+                )
+                .address,
+                &plane.address,
+            );
+            liqpool.deposit(&user1, &Vec::from_array(&e, [100_0000000, 100_0000000]), &0);
+            assert_eq!(liqpool.estimate_swap(&0, &1, &1_0000000), fee_config.1);
+
+            if strict_receive {
+                assert_eq!(
+                    liqpool.estimate_swap_strict_receive(&0, &1, &fee_config.1),
+                    1_0000000
+                );
+                assert_eq!(
+                    liqpool.swap_strict_receive(&user1, &0, &1, &fee_config.1, &1_0000000),
+                    1_0000000
+                );
+            } else {
+                assert_eq!(liqpool.swap(&user1, &0, &1, &1_0000000, &0), fee_config.1);
+            }
+        }
     }
 }
 
@@ -1837,7 +1918,7 @@ fn test_deposit_inequal() {
     token2_admin_client.mint(&user1, &1000_0000000);
 
     liqpool.deposit(&user1, &Vec::from_array(&e, [10_0000000, 100_0000000]), &0);
-    assert_eq!(token_share.balance(&user1) as u128, 101_8767615);
+    assert_eq!(token_share.balance(&user1) as u128, 101_8766615);
     assert_eq!(token1.balance(&user1) as u128, 990_0000000);
     assert_eq!(token2.balance(&user1) as u128, 900_0000000);
     liqpool.deposit(&user1, &Vec::from_array(&e, [100_0000000, 10_0000000]), &0);
@@ -1891,7 +1972,7 @@ fn test_deposit_inequal_different_decimals() {
         &Vec::from_array(&e, [10_0000000, 100_000000000000000000]),
         &0,
     );
-    assert_eq!(token_share.balance(&user1) as u128, 101_876761504564086655);
+    assert_eq!(token_share.balance(&user1) as u128, 101_876761504564085655);
     assert_eq!(token7.balance(&user1) as u128, 990_0000000);
     assert_eq!(token18.balance(&user1) as u128, 900_000000000000000000);
     liqpool.deposit(
@@ -1948,7 +2029,7 @@ fn test_remove_liquidity_imbalance() {
     assert_eq!(token1.balance(&user1) as u128, 990_0000000);
     assert_eq!(token2.balance(&user1) as u128, 900_0000000);
     let token_share_amount = token_share.balance(&user1) as u128;
-    assert_eq!(token_share_amount, 101_8767615);
+    assert_eq!(token_share_amount, 101_8766615);
     liqpool.remove_liquidity_imbalance(
         &user1,
         &Vec::from_array(&e, [0_5000000, 99_0000000]),
@@ -1967,7 +2048,7 @@ fn test_remove_liquidity_imbalance() {
                 )
                     .into_val(&e),
                 (
-                    (token_share_amount - 9_7635378) as i128,
+                    (token_share_amount - 9_7634378) as i128,
                     0_5000000_i128,
                     99_0000000_i128
                 )
@@ -1980,7 +2061,7 @@ fn test_remove_liquidity_imbalance() {
     assert_eq!(token1.balance(&liqpool.address) as u128, 9_5000000);
     assert_eq!(token2.balance(&liqpool.address) as u128, 1_0000000);
     assert!((token_share.balance(&user1) as u128) < token_share_amount / 10); // more than 90% of the share were burned
-    assert_eq!(token_share.balance(&user1) as u128, 9_7635378); // control exact value
+    assert_eq!(token_share.balance(&user1) as u128, 9_7634378); // control exact value
 }
 
 #[test]
@@ -2032,7 +2113,7 @@ fn test_remove_liquidity_imbalance_different_decimals() {
     assert_eq!(token1.balance(&user1) as u128, 990_000000000000000000);
     assert_eq!(token2.balance(&user1) as u128, 900_0000000);
     let token_share_amount = token_share.balance(&user1) as u128;
-    assert_eq!(token_share_amount, 101_876761504564086655);
+    assert_eq!(token_share_amount, 101_876761504564085655);
     liqpool.remove_liquidity_imbalance(
         &user1,
         &Vec::from_array(&e, [0_500000000000000000, 99_0000000]),
@@ -2046,7 +2127,7 @@ fn test_remove_liquidity_imbalance_different_decimals() {
     );
     assert_eq!(token2.balance(&liqpool.address) as u128, 1_0000000);
     assert!((token_share.balance(&user1) as u128) < token_share_amount / 10); // more than 90% of the share were burned
-    assert_eq!(token_share.balance(&user1) as u128, 9_763537957616772036); // control exact value
+    assert_eq!(token_share.balance(&user1) as u128, 9_763537957616771036); // control exact value
 }
 
 #[test]
@@ -2104,7 +2185,14 @@ fn test_simple_ongoing_reward() {
 
     // 10 seconds passed since config, user depositing
     jump(&e, 10);
-    liqpool.deposit(&user1, &Vec::from_array(&e, [100, 100]), &0);
+    liqpool.deposit(
+        &user1,
+        &Vec::from_array(
+            &e,
+            [100 + MINIMUM_LIQUIDITY / 2, 100 + MINIMUM_LIQUIDITY / 2],
+        ),
+        &0,
+    );
 
     assert_eq!(token_reward.balance(&user1) as u128, 0);
     // 30 seconds passed, half of the reward is available for the user
@@ -2223,7 +2311,14 @@ fn test_simple_reward() {
 
     // 10 seconds. user depositing
     jump(&e, 10);
-    liqpool.deposit(&user1, &Vec::from_array(&e, [100, 100]), &0);
+    liqpool.deposit(
+        &user1,
+        &Vec::from_array(
+            &e,
+            [100 + MINIMUM_LIQUIDITY / 2, 100 + MINIMUM_LIQUIDITY / 2],
+        ),
+        &0,
+    );
 
     // 20 seconds. rewards set up for 60 seconds
     jump(&e, 10);
@@ -2308,7 +2403,14 @@ fn test_two_users_rewards() {
 
     // two users make deposit for equal value. second after 30 seconds after rewards start,
     //  so it gets only 1/4 of total reward
-    liqpool.deposit(&user1, &Vec::from_array(&e, [100, 100]), &0);
+    liqpool.deposit(
+        &user1,
+        &Vec::from_array(
+            &e,
+            [100 + MINIMUM_LIQUIDITY / 2, 100 + MINIMUM_LIQUIDITY / 2],
+        ),
+        &0,
+    );
     jump(&e, 30);
     assert_eq!(liqpool.claim(&user1), total_reward_1 / 2);
     liqpool.deposit(&user2, &Vec::from_array(&e, [100, 100]), &0);
@@ -2356,7 +2458,14 @@ fn test_boosted_rewards() {
 
     // two users make deposit for equal value. second after 30 seconds after rewards start,
     //  so it gets only 1/4 of total reward
-    liq_pool.deposit(&user1, &Vec::from_array(&env, [100, 100]), &0);
+    liq_pool.deposit(
+        &user1,
+        &Vec::from_array(
+            &env,
+            [100 + MINIMUM_LIQUIDITY / 2, 100 + MINIMUM_LIQUIDITY / 2],
+        ),
+        &0,
+    );
     jump(&env, 30);
     assert_eq!(liq_pool.claim(&user1), total_reward_1 / 2);
 
@@ -2546,7 +2655,14 @@ fn test_lazy_user_rewards() {
         assert_eq!(token2.balance(user) as u128, 1000);
     }
 
-    liqpool.deposit(&user1, &Vec::from_array(&e, [100, 100]), &0);
+    liqpool.deposit(
+        &user1,
+        &Vec::from_array(
+            &e,
+            [100 + MINIMUM_LIQUIDITY / 2, 100 + MINIMUM_LIQUIDITY / 2],
+        ),
+        &0,
+    );
     jump(&e, 59);
     liqpool.deposit(&user2, &Vec::from_array(&e, [1000, 1000]), &0);
     jump(&e, 100);
@@ -3207,6 +3323,69 @@ fn test_deposit_min_mint() {
     liqpool.deposit(&user1, &Vec::from_array(&e, [1, 1]), &10);
 }
 
+#[test]
+fn test_locked_minimum_liquidity() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let liq_pool = setup.liq_pool;
+    let token1_admin_client = get_token_admin_client(&e, &setup.token1.address);
+    let token2_admin_client = get_token_admin_client(&e, &setup.token2.address);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    for user in [&user1, &user2] {
+        token1_admin_client.mint(user, &1000_0000000);
+        token2_admin_client.mint(user, &1000_0000000);
+    }
+
+    // minimum liquidity is locked by the pool itself on the first deposit
+    assert_eq!(
+        liq_pool.deposit(&user1, &Vec::from_array(&e, [50_0000000, 50_0000000]), &0),
+        (
+            Vec::from_array(&e, [50_0000000, 50_0000000]),
+            100_0000000 - MINIMUM_LIQUIDITY
+        )
+    );
+    assert_eq!(liq_pool.get_total_shares(), 100_0000000);
+    assert_eq!(
+        setup.token_share.balance(&liq_pool.address),
+        MINIMUM_LIQUIDITY as i128
+    );
+
+    // pool can't be emptied, so the share price can't be reset by the next depositor
+    liq_pool.withdraw(
+        &user1,
+        &(100_0000000 - MINIMUM_LIQUIDITY),
+        &Vec::from_array(&e, [0, 0]),
+    );
+    assert_eq!(liq_pool.get_total_shares(), MINIMUM_LIQUIDITY);
+    assert_eq!(
+        liq_pool.get_reserves(),
+        Vec::from_array(&e, [MINIMUM_LIQUIDITY / 2, MINIMUM_LIQUIDITY / 2])
+    );
+    assert_eq!(
+        liq_pool.deposit(&user2, &Vec::from_array(&e, [10_0000000, 10_0000000]), &0),
+        (Vec::from_array(&e, [10_0000000, 10_0000000]), 20_0000000)
+    );
+    assert_eq!(
+        setup.token_share.balance(&liq_pool.address),
+        MINIMUM_LIQUIDITY as i128
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2022)")]
+fn test_insufficient_initial_liquidity() {
+    let setup = Setup::default();
+    let user1 = Address::generate(&setup.env);
+    get_token_admin_client(&setup.env, &setup.token1.address).mint(&user1, &1000);
+    get_token_admin_client(&setup.env, &setup.token2.address).mint(&user1, &1000);
+    setup.liq_pool.deposit(
+        &user1,
+        &Vec::from_array(&setup.env, [MINIMUM_LIQUIDITY / 2, MINIMUM_LIQUIDITY / 2]),
+        &0,
+    );
+}
+
 #[test]
 fn test_deposit_inequal_ok() {
     let e = Env::default();
@@ -3249,15 +3428,22 @@ fn test_deposit_inequal_ok() {
     token1_admin_client.mint(&user1, &i128::MAX);
     token2_admin_client.mint(&user1, &i128::MAX);
 
-    liqpool.deposit(&user1, &Vec::from_array(&e, [100, 100]), &0);
+    liqpool.deposit(
+        &user1,
+        &Vec::from_array(
+            &e,
+            [100 + MINIMUM_LIQUIDITY / 2, 100 + MINIMUM_LIQUIDITY / 2],
+        ),
+        &0,
+    );
 
     let token_share = SorobanTokenClient::new(&e, &liqpool.share_id());
-    assert_eq!(token1.balance(&liqpool.address), 100);
-    assert_eq!(token2.balance(&liqpool.address), 100);
+    assert_eq!(token1.balance(&liqpool.address), 600);
+    assert_eq!(token2.balance(&liqpool.address), 600);
     assert_eq!(token_share.balance(&user1), 200);
     liqpool.deposit(&user1, &Vec::from_array(&e, [200, 100]), &0);
-    assert_eq!(token1.balance(&liqpool.address), 300);
-    assert_eq!(token2.balance(&liqpool.address), 200);
+    assert_eq!(token1.balance(&liqpool.address), 800);
+    assert_eq!(token2.balance(&liqpool.address), 700);
     assert_eq!(token_share.balance(&user1), 499);
 }
 
@@ -3311,7 +3497,10 @@ fn test_large_numbers() {
     liqpool.deposit(&user1, &desired_amounts, &0);
 
     // when we deposit equal amounts, we gotta have deposited amount of share tokens
-    assert_eq!(token_share.balance(&liqpool.address), 0);
+    assert_eq!(
+        token_share.balance(&liqpool.address),
+        MINIMUM_LIQUIDITY as i128
+    );
     assert_eq!(
         token1.balance(&user1),
         i128::MAX - amount_to_deposit as i128
@@ -3352,22 +3541,27 @@ fn test_large_numbers() {
 
     let share_amount = token_share.balance(&user1);
 
-    let withdraw_amounts = [
-        amount_to_deposit + swap_in,
-        amount_to_deposit - estimate_swap_result,
-    ];
+    // share of the locked minimum liquidity stays in the pool
+    let reserve_a = amount_to_deposit + swap_in;
+    let reserve_b = amount_to_deposit - estimate_swap_result;
+    let locked_a = (reserve_a * MINIMUM_LIQUIDITY).div_ceil(2 * amount_to_deposit);
+    let locked_b = (reserve_b * MINIMUM_LIQUIDITY).div_ceil(2 * amount_to_deposit);
+    let withdraw_amounts = [reserve_a - locked_a, reserve_b - locked_b];
     liqpool.withdraw(
         &user1,
         &(share_amount as u128),
         &Vec::from_array(&e, withdraw_amounts),
     );
 
-    assert_eq!(token1.balance(&user1), i128::MAX);
-    assert_eq!(token2.balance(&user1), i128::MAX);
+    assert_eq!(token1.balance(&user1), i128::MAX - locked_a as i128);
+    assert_eq!(token2.balance(&user1), i128::MAX - locked_b as i128);
     assert_eq!(token_share.balance(&user1), 0);
-    assert_eq!(token1.balance(&liqpool.address), 0);
-    assert_eq!(token2.balance(&liqpool.address), 0);
-    assert_eq!(token_share.balance(&liqpool.address), 0);
+    assert_eq!(token1.balance(&liqpool.address), locked_a as i128);
+    assert_eq!(token2.balance(&liqpool.address), locked_b as i128);
+    assert_eq!(
+        token_share.balance(&liqpool.address),
+        MINIMUM_LIQUIDITY as i128
+    );
 }
 
 #[test]
@@ -3612,7 +3806,14 @@ fn test_kill_claim() {
 
     // 10 seconds. user depositing
     jump(&e, 10);
-    liqpool.deposit(&user1, &Vec::from_array(&e, [100, 100]), &0);
+    liqpool.deposit(
+        &user1,
+        &Vec::from_array(
+            &e,
+            [100 + MINIMUM_LIQUIDITY / 2, 100 + MINIMUM_LIQUIDITY / 2],
+        ),
+        &0,
+    );
 
     // 20 seconds. rewards set up for 60 seconds
     jump(&e, 10);
@@ -3699,12 +3900,18 @@ fn test_withdraw_rewards() {
     );
     let token_share = ShareTokenClient::new(&e, &liq_pool.share_id());
 
-    token1_admin_client.mint(&user1, &100_0000000);
-    token2_admin_client.mint(&user1, &100_0000000);
-    liq_pool.deposit(&user1, &Vec::from_array(&e, [100_0000000, 100_0000000]), &0);
+    // first deposit covers the locked minimum liquidity
+    let first_deposit = 100_0000000 + MINIMUM_LIQUIDITY / 2;
+    token1_admin_client.mint(&user1, &(first_deposit as i128));
+    token2_admin_client.mint(&user1, &(first_deposit as i128));
+    liq_pool.deposit(
+        &user1,
+        &Vec::from_array(&e, [first_deposit, first_deposit]),
+        &0,
+    );
     assert_eq!(
         liq_pool.get_reserves(),
-        Vec::from_array(&e, [100_0000000, 100_0000000])
+        Vec::from_array(&e, [first_deposit, first_deposit])
     );
 
     liq_pool.set_rewards_config(
@@ -3724,18 +3931,27 @@ fn test_withdraw_rewards() {
     );
     assert_eq!(
         liq_pool.get_reserves(),
-        Vec::from_array(&e, [1_100_0000000, 1_100_0000000])
+        Vec::from_array(
+            &e,
+            [1_000_0000000 + first_deposit, 1_000_0000000 + first_deposit]
+        )
     );
 
     assert_eq!(
         liq_pool.get_reserves(),
-        Vec::from_array(&e, [1_100_0000000, 1_100_0000000])
+        Vec::from_array(
+            &e,
+            [1_000_0000000 + first_deposit, 1_000_0000000 + first_deposit]
+        )
     );
     assert_eq!(
         token1.balance(&liq_pool.address),
-        1_100_0000000 + 1_000_0000000 * 100
+        (1_000_0000000 + first_deposit + 1_000_0000000 * 100) as i128
+    );
+    assert_eq!(
+        token2.balance(&liq_pool.address),
+        (1_000_0000000 + first_deposit) as i128
     );
-    assert_eq!(token2.balance(&liq_pool.address), 1_100_0000000);
 
     assert_eq!(
         liq_pool.withdraw(
@@ -3747,13 +3963,13 @@ fn test_withdraw_rewards() {
     );
     assert_eq!(
         liq_pool.get_reserves(),
-        Vec::from_array(&e, [100_0000000, 100_0000000])
+        Vec::from_array(&e, [first_deposit, first_deposit])
     );
     assert_eq!(
         token1.balance(&liq_pool.address),
-        100_0000000 + 1_000_0000000 * 100
+        (first_deposit + 1_000_0000000 * 100) as i128
     );
-    assert_eq!(token2.balance(&liq_pool.address), 100_0000000);
+    assert_eq!(token2.balance(&liq_pool.address), first_deposit as i128);
     assert_eq!(token1.balance(&user2), 1_000_0000000);
     assert_eq!(token2.balance(&user2), 1_000_0000000);
 
@@ -4045,12 +4261,18 @@ fn test_claim_rewards() {
         &plane.address,
     );
 
-    token1_admin_client.mint(&user1, &100_0000000);
-    token2_admin_client.mint(&user1, &100_0000000);
-    liq_pool.deposit(&user1, &Vec::from_array(&e, [100_0000000, 100_0000000]), &0);
+    // first deposit covers the locked minimum liquidity
+    let first_deposit = 100_0000000 + MINIMUM_LIQUIDITY / 2;
+    token1_admin_client.mint(&user1, &(first_deposit as i128));
+    token2_admin_client.mint(&user1, &(first_deposit as i128));
+    liq_pool.deposit(
+        &user1,
+        &Vec::from_array(&e, [first_deposit, first_deposit]),
+        &0,
+    );
     assert_eq!(
         liq_pool.get_reserves(),
-        Vec::from_array(&e, [100_0000000, 100_0000000])
+        Vec::from_array(&e, [first_deposit, first_deposit])
     );
 
     liq_pool.set_rewards_config(&admin, &e.ledger().timestamp().saturating_add(100), &1000);
@@ -4129,7 +4351,13 @@ fn test_drain_reward() {
 
     liq_pool.deposit(
         &users[0],
-        &Vec::from_array(&e, [1000_0000000, 1000_0000000]),
+        &Vec::from_array(
+            &e,
+            [
+                1000_0000000 + MINIMUM_LIQUIDITY / 2,
+                1000_0000000 + MINIMUM_LIQUIDITY / 2,
+            ],
+        ),
         &0,
     );
     let (_, lp_amount) = liq_pool.deposit(
@@ -4214,11 +4442,12 @@ fn test_drain_reserves() {
     assert_eq!(liq_pool.get_reserves(), Vec::from_array(&e, [0, 0]));
 
     // first user deposits
-    token1_admin_client.mint(&user1, &1_000_000_0000000);
-    token2_admin_client.mint(&user1, &1_000_000_0000000);
+    let first_deposit = 1_000_000_0000000 + MINIMUM_LIQUIDITY / 2;
+    token1_admin_client.mint(&user1, &(first_deposit as i128));
+    token2_admin_client.mint(&user1, &(first_deposit as i128));
     liq_pool.deposit(
         &user1,
-        &Vec::from_array(&e, [1_000_000_0000000, 1_000_000_0000000]),
+        &Vec::from_array(&e, [first_deposit, first_deposit]),
         &0,
     );
 
@@ -4253,10 +4482,22 @@ fn test_drain_reserves() {
     // check reserves
     assert_eq!(
         liq_pool.get_reserves(),
-        Vec::from_array(&e, [1_300_000_0000000, 1_300_000_0000000])
+        Vec::from_array(
+            &e,
+            [
+                300_000_0000000 + first_deposit,
+                300_000_0000000 + first_deposit
+            ]
+        )
+    );
+    assert_eq!(
+        token1.balance(&liq_pool.address),
+        (300_000_0000000 + first_deposit + 1) as i128
+    ); // 1 token left on balance because of rounding
+    assert_eq!(
+        token2.balance(&liq_pool.address),
+        (300_000_0000000 + first_deposit) as i128
     );
-    assert_eq!(token1.balance(&liq_pool.address), 1_300_000_0000001); // 1 token left on balance because of rounding
-    assert_eq!(token2.balance(&liq_pool.address), 1_300_000_0000000);
 }
 
 #[test]
@@ -4724,6 +4965,35 @@ fn test_set_fee_events() {
             ),
         ]
     );
+
+    pool.commit_new_admin_fee(&setup.admin, &5000);
+    assert_eq!(
+        vec![&setup.env, setup.env.events().all().last().unwrap()],
+        vec![
+            &setup.env,
+            (
+                pool.address.clone(),
+                (Symbol::new(&setup.env, "commit_new_admin_fee"),).into_val(&setup.env),
+                (5000_u32,).into_val(&setup.env),
+            ),
+        ]
+    );
+    jump(&setup.env, ADMIN_ACTIONS_DELAY + 1);
+    pool.apply_new_fee(&setup.admin);
+    assert_eq!(
+        vec![&setup.env, setup.env.events().all().last().unwrap()],
+        vec![
+            &setup.env,
+            (
+                pool.address.clone(),
+                (Symbol::new(&setup.env, "apply_new_admin_fee"),).into_val(&setup.env),
+                (5000_u32,).into_val(&setup.env),
+            ),
+        ]
+    );
+    // fee is kept while the admin fee share is updated
+    assert_eq!(pool.get_fee_fraction(), 8);
+    assert_eq!(pool.get_admin_fee(), 5000);
 }
 
 #[test]
@@ -4926,3 +5196,380 @@ fn test_claim_event() {
         ]
     );
 }
+
+#[test]
+fn test_admin_fee() {
+    let setup = Setup::default();
+    let pool = setup.liq_pool;
+    let token1_admin_client = get_token_admin_client(&setup.env, &setup.token1.address);
+    let token2_admin_client = get_token_admin_client(&setup.env, &setup.token2.address);
+    let user1 = Address::generate(&setup.env);
+    let fees_collector = Address::generate(&setup.env);
+
+    pool.commit_new_fee(&setup.admin, &30);
+    jump(&setup.env, ADMIN_ACTIONS_DELAY + 1);
+    pool.apply_new_fee(&setup.admin);
+    pool.commit_new_admin_fee(&setup.admin, &5000);
+    jump(&setup.env, ADMIN_ACTIONS_DELAY + 1);
+    pool.apply_new_fee(&setup.admin);
+    assert_eq!(pool.get_admin_fee(), 5000);
+    pool.set_fees_collector(&setup.admin, &fees_collector);
+    assert_eq!(pool.get_fees_collector(), fees_collector);
+
+    token1_admin_client.mint(&user1, &1000_0000000);
+    token2_admin_client.mint(&user1, &1000_0000000);
+    pool.deposit(
+        &user1,
+        &Vec::from_array(&setup.env, [200_0000000, 200_0000000]),
+        &0,
+    );
+
+    // user receives the same amount, part of the fee is excluded from the reserves
+    let estimate = pool.estimate_swap(&0, &1, &10_0000000);
+    assert_eq!(pool.swap(&user1, &0, &1, &10_0000000, &0), estimate);
+    pool.swap_strict_receive(&user1, &1, &0, &5_0000000, &10_0000000);
+
+    let admin_fees = pool.get_admin_fee_reserves();
+    assert!(admin_fees.get(0).unwrap() > 0);
+    assert!(admin_fees.get(1).unwrap() > 0);
+    let reserves = pool.get_reserves();
+    for (i, token) in [&setup.token1, &setup.token2].iter().enumerate() {
+        assert_eq!(
+            token.balance(&pool.address) as u128,
+            reserves.get(i as u32).unwrap() + admin_fees.get(i as u32).unwrap()
+        );
+    }
+
+    assert_eq!(pool.withdraw_admin_fees(), admin_fees);
+    assert_eq!(
+        pool.get_admin_fee_reserves(),
+        Vec::from_array(&setup.env, [0, 0])
+    );
+    assert_eq!(pool.get_reserves(), reserves);
+    assert_eq!(
+        setup.token1.balance(&fees_collector) as u128,
+        admin_fees.get(0).unwrap()
+    );
+    assert_eq!(
+        setup.token2.balance(&fees_collector) as u128,
+        admin_fees.get(1).unwrap()
+    );
+    assert_eq!(
+        vec![&setup.env, setup.env.events().all().last().unwrap()],
+        vec![
+            &setup.env,
+            (
+                pool.address.clone(),
+                (
+                    Symbol::new(&setup.env, "withdraw_admin_fees"),
+                    fees_collector
+                )
+                    .into_val(&setup.env),
+                (admin_fees,).into_val(&setup.env),
+            ),
+        ]
+    );
+
+    // nothing accrued since the last withdrawal
+    assert_eq!(
+        pool.withdraw_admin_fees(),
+        Vec::from_array(&setup.env, [0, 0])
+    );
+
+    // imbalanced liquidity operations charge the fee too
+    pool.deposit(&user1, &Vec::from_array(&setup.env, [50_0000000, 0]), &0);
+    let deposit_fees = pool.get_admin_fee_reserves();
+    assert!(deposit_fees.get(0).unwrap() > 0);
+    assert!(deposit_fees.get(1).unwrap() > 0);
+
+    pool.remove_liquidity_imbalance(
+        &user1,
+        &Vec::from_array(&setup.env, [0, 30_0000000]),
+        &100_0000000,
+    );
+    let imbalance_fees = pool.get_admin_fee_reserves();
+    assert!(imbalance_fees.get(0).unwrap() > deposit_fees.get(0).unwrap());
+    assert!(imbalance_fees.get(1).unwrap() > deposit_fees.get(1).unwrap());
+
+    pool.withdraw_one_coin(&user1, &10_0000000, &0, &0);
+    let one_coin_fees = pool.get_admin_fee_reserves();
+    assert!(one_coin_fees.get(0).unwrap() > imbalance_fees.get(0).unwrap());
+    assert_eq!(
+        one_coin_fees.get(1).unwrap(),
+        imbalance_fees.get(1).unwrap()
+    );
+
+    let reserves = pool.get_reserves();
+    for (i, token) in [&setup.token1, &setup.token2].iter().enumerate() {
+        assert_eq!(
+            token.balance(&pool.address) as u128,
+            reserves.get(i as u32).unwrap() + one_coin_fees.get(i as u32).unwrap()
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2012)")]
+fn test_admin_fee_out_of_bounds() {
+    let setup = Setup::default();
+    setup.liq_pool.commit_new_admin_fee(&setup.admin, &10001);
+}
+
+#[test]
+fn test_price_cumulative() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let pool = setup.liq_pool;
+    let token1_admin_client = get_token_admin_client(&e, &setup.token1.address);
+    let token2_admin_client = get_token_admin_client(&e, &setup.token2.address);
+    let user1 = Address::generate(&e);
+    token1_admin_client.mint(&user1, &1000_0000000);
+    token2_admin_client.mint(&user1, &1000_0000000);
+
+    pool.deposit(&user1, &Vec::from_array(&e, [100_0000000, 100_0000000]), &0);
+    let zero = U256::from_u32(&e, 0);
+    assert_eq!(
+        pool.get_price_cumulative(),
+        Vec::from_array(&e, [zero.clone(), zero.clone()])
+    );
+    let timestamp = pool.get_price_timestamp_last();
+
+    // balanced pool, prices are equal
+    jump(&e, 100);
+    pool.swap(&user1, &0, &1, &10_0000000, &0);
+    let price_cumulative = Vec::from_array(
+        &e,
+        [
+            U256::from_u128(&e, 100_000000000000000000),
+            U256::from_u128(&e, 100_000000000000000000),
+        ],
+    );
+    assert_eq!(pool.get_price_cumulative(), price_cumulative);
+    assert_eq!(pool.get_price_timestamp_last(), timestamp + 100);
+    assert_eq!(
+        setup
+            .plane
+            .get_price_cumulative(&Vec::from_array(&e, [pool.address.clone()])),
+        Vec::from_array(&e, [(price_cumulative, timestamp + 100)])
+    );
+
+    // token 1 is more expensive after the swap
+    jump(&e, 50);
+    pool.swap(&user1, &1, &0, &1_0000000, &0);
+    let price_cumulative = pool.get_price_cumulative();
+    assert_eq!(
+        price_cumulative.get(0).unwrap(),
+        U256::from_u128(&e, 150_000000000000000000)
+    );
+    assert!(price_cumulative.get(1).unwrap() > U256::from_u128(&e, 150_000000000000000000));
+    assert_eq!(pool.get_price_timestamp_last(), timestamp + 150);
+
+    // marginal prices for arbitrary reserves
+    let precision = U256::from_u128(&e, 1_000000000000000000);
+    assert_eq!(
+        pool.calc_prices(&Vec::from_array(&e, [100_0000000, 100_0000000])),
+        Vec::from_array(&e, [precision.clone(), precision.clone()])
+    );
+    let prices = pool.calc_prices(&pool.get_reserves());
+    assert_eq!(prices.get(0).unwrap(), precision);
+    assert!(prices.get(1).unwrap() > precision);
+    assert_eq!(
+        pool.calc_prices(&Vec::from_array(&e, [100_0000000, 0])),
+        Vec::from_array(&e, [zero.clone(), zero.clone()])
+    );
+}
+
+#[test]
+fn test_flash_loan() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let pool = setup.liq_pool;
+    let token1_admin_client = get_token_admin_client(&e, &setup.token1.address);
+    let token2_admin_client = get_token_admin_client(&e, &setup.token2.address);
+    let user1 = Address::generate(&e);
+    token1_admin_client.mint(&user1, &1000_0000000);
+    token2_admin_client.mint(&user1, &1000_0000000);
+    pool.deposit(&user1, &Vec::from_array(&e, [100_0000000, 100_0000000]), &0);
+    pool.commit_new_fee(&setup.admin, &30);
+    jump(&e, ADMIN_ACTIONS_DELAY + 1);
+    pool.apply_new_fee(&setup.admin);
+    pool.commit_new_admin_fee(&setup.admin, &5000);
+    jump(&e, ADMIN_ACTIONS_DELAY + 1);
+    pool.apply_new_fee(&setup.admin);
+
+    let receiver = create_flash_loan_receiver(&e, &pool.address);
+    // receiver pays flash fees from its own funds
+    token1_admin_client.mint(&receiver.address, &1_0000000);
+    token2_admin_client.mint(&receiver.address, &1_0000000);
+
+    let fees = pool.flash_loan(
+        &receiver.address,
+        &Vec::from_array(&e, [10_0000000, 5_0000000]),
+        &Bytes::new(&e),
+    );
+    assert_eq!(fees, Vec::from_array(&e, [300000, 150000]));
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                pool.address.clone(),
+                (
+                    Symbol::new(&e, "flash_loan"),
+                    setup.token1.address.clone(),
+                    setup.token2.address.clone()
+                )
+                    .into_val(&e),
+                (
+                    receiver.address.clone(),
+                    10_0000000_i128,
+                    300000_i128,
+                    5_0000000_i128,
+                    150000_i128
+                )
+                    .into_val(&e),
+            ),
+        ]
+    );
+
+    // half of the fees goes to the reserves, another half is the admin fee
+    assert_eq!(
+        pool.get_reserves(),
+        Vec::from_array(&e, [100_0150000, 100_0075000])
+    );
+    assert_eq!(
+        pool.get_admin_fee_reserves(),
+        Vec::from_array(&e, [150000, 75000])
+    );
+    assert_eq!(setup.token1.balance(&pool.address), 100_0300000);
+    assert_eq!(setup.token2.balance(&pool.address), 100_0150000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2021)")]
+fn test_flash_loan_not_repaid() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let pool = setup.liq_pool;
+    let token1_admin_client = get_token_admin_client(&e, &setup.token1.address);
+    let token2_admin_client = get_token_admin_client(&e, &setup.token2.address);
+    let user1 = Address::generate(&e);
+    token1_admin_client.mint(&user1, &1000_0000000);
+    token2_admin_client.mint(&user1, &1000_0000000);
+    pool.deposit(&user1, &Vec::from_array(&e, [100_0000000, 100_0000000]), &0);
+
+    let receiver = create_flash_loan_receiver(&e, &pool.address);
+    // receiver returns borrowed amount without the fee
+    pool.flash_loan(
+        &receiver.address,
+        &Vec::from_array(&e, [10_0000000, 0]),
+        &Bytes::from_array(&e, &[1]),
+    );
+}
+
+#[test]
+fn test_skim_sync() {
+    let setup = Setup::default();
+    let e = setup.env;
+    let pool = setup.liq_pool;
+    let token1_admin_client = get_token_admin_client(&e, &setup.token1.address);
+    let token2_admin_client = get_token_admin_client(&e, &setup.token2.address);
+    let user1 = Address::generate(&e);
+    token1_admin_client.mint(&user1, &1000_0000000);
+    token2_admin_client.mint(&user1, &1000_0000000);
+    pool.deposit(&user1, &Vec::from_array(&e, [100_0000000, 100_0000000]), &0);
+
+    // nothing to skim yet
+    let to = Address::generate(&e);
+    assert_eq!(pool.skim(&setup.admin, &to), Vec::from_array(&e, [0, 0]));
+
+    // coins sent directly to the pool are not accounted in the reserves
+    setup.token1.transfer(&user1, &pool.address, &1_0000000);
+    setup.token2.transfer(&user1, &pool.address, &2_0000000);
+    assert_eq!(
+        pool.skim(&setup.admin, &to),
+        Vec::from_array(&e, [1_0000000, 2_0000000])
+    );
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                pool.address.clone(),
+                (Symbol::new(&e, "skim"), to.clone()).into_val(&e),
+                (Vec::from_array(&e, [1_0000000_u128, 2_0000000_u128]),).into_val(&e),
+            ),
+        ]
+    );
+    assert_eq!(setup.token1.balance(&to), 1_0000000);
+    assert_eq!(setup.token2.balance(&to), 2_0000000);
+    assert_eq!(
+        pool.get_reserves(),
+        Vec::from_array(&e, [100_0000000, 100_0000000])
+    );
+
+    // sync adds the surplus to the reserves
+    setup.token1.transfer(&user1, &pool.address, &3_0000000);
+    assert_eq!(pool.sync(), Vec::from_array(&e, [103_0000000, 100_0000000]));
+    assert_eq!(
+        vec![&e, e.events().all().last().unwrap()],
+        vec![
+            &e,
+            (
+                pool.address.clone(),
+                (Symbol::new(&e, "sync"),).into_val(&e),
+                (Vec::from_array(&e, [103_0000000_u128, 100_0000000_u128]),).into_val(&e),
+            ),
+        ]
+    );
+    assert_eq!(
+        pool.get_reserves(),
+        Vec::from_array(&e, [103_0000000, 100_0000000])
+    );
+    assert_eq!(pool.skim(&setup.admin, &to), Vec::from_array(&e, [0, 0]));
+}
+
+#[test]
+fn test_skim_reward_token_in_pool() {
+    let setup = Setup::new_with_config(&TestConfig {
+        reward_token_in_pool: true,
+        ..TestConfig::default()
+    });
+    assert_eq!(setup.token1.address, setup.token_reward.address);
+    let e = setup.env;
+    let pool = setup.liq_pool;
+    let token1_admin_client = get_token_admin_client(&e, &setup.token1.address);
+    let token2_admin_client = get_token_admin_client(&e, &setup.token2.address);
+    let user1 = Address::generate(&e);
+    token1_admin_client.mint(&user1, &1000_0000000);
+    token2_admin_client.mint(&user1, &1000_0000000);
+    pool.deposit(&user1, &Vec::from_array(&e, [100_0000000, 100_0000000]), &0);
+    token1_admin_client.mint(&pool.address, &60_0000000);
+    pool.set_rewards_config(
+        &setup.admin,
+        &e.ledger().timestamp().saturating_add(60),
+        &1_0000000,
+    );
+
+    // only reward token balance above the configured rewards is skimmed
+    token1_admin_client.mint(&pool.address, &10_0000000);
+    setup.token2.transfer(&user1, &pool.address, &1_0000000);
+    assert_eq!(pool.get_unused_reward(), 10_0000000);
+    let to = Address::generate(&e);
+    assert_eq!(
+        pool.skim(&setup.admin, &to),
+        Vec::from_array(&e, [10_0000000, 1_0000000])
+    );
+    assert_eq!(setup.token1.balance(&to), 10_0000000);
+    assert_eq!(pool.sync(), Vec::from_array(&e, [100_0000000, 100_0000000]));
+    assert_eq!(pool.get_unused_reward(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2010)")]
+fn test_sync_empty_pool() {
+    let setup = Setup::default();
+    get_token_admin_client(&setup.env, &setup.token1.address)
+        .mint(&setup.liq_pool.address, &1_0000000);
+    setup.liq_pool.sync();
+}
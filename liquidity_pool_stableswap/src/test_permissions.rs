@@ -589,5 +589,51 @@ fn test_update_fee() {
         assert_eq!(pool.try_commit_new_fee(&addr, &1).is_ok(), is_ok);
         jump(&setup.env, ADMIN_ACTIONS_DELAY + 1);
         assert_eq!(pool.try_apply_new_fee(&addr).is_ok(), is_ok);
+        assert_eq!(pool.try_commit_new_admin_fee(&addr, &5000).is_ok(), is_ok);
+        jump(&setup.env, ADMIN_ACTIONS_DELAY + 1);
+        assert_eq!(pool.try_apply_new_fee(&addr).is_ok(), is_ok);
+    }
+}
+
+#[test]
+fn test_set_fees_collector() {
+    let setup = Setup::default();
+    let pool = setup.liq_pool;
+    let user = Address::generate(&setup.env);
+    let fees_collector = Address::generate(&setup.env);
+
+    for (addr, is_ok) in [
+        (user, false),
+        (setup.admin, true),
+        (setup.rewards_admin, false),
+        (setup.operations_admin, false),
+        (setup.pause_admin, false),
+        (setup.emergency_pause_admin, false),
+    ] {
+        assert_eq!(
+            pool.try_set_fees_collector(&addr, &fees_collector).is_ok(),
+            is_ok
+        );
+    }
+}
+
+#[test]
+fn test_skim() {
+    let setup = Setup::default();
+    let pool = setup.liq_pool;
+    let user = Address::generate(&setup.env);
+
+    for (addr, is_ok) in [
+        (user, false),
+        (setup.admin, true),
+        (setup.rewards_admin, false),
+        (setup.operations_admin, false),
+        (setup.pause_admin, false),
+        (setup.emergency_pause_admin, false),
+    ] {
+        assert_eq!(
+            pool.try_skim(&addr, &Address::generate(&setup.env)).is_ok(),
+            is_ok
+        );
     }
 }
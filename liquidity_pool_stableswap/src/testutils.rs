@@ -9,7 +9,9 @@ use soroban_sdk::testutils::Address as _;
 use soroban_sdk::token::{
     StellarAssetClient as SorobanTokenAdminClient, TokenClient as SorobanTokenClient,
 };
-use soroban_sdk::{Address, BytesN, Env, IntoVal, Symbol, Vec};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, Address, Bytes, BytesN, Env, IntoVal, Symbol, Vec,
+};
 use token_share::token_contract::Client as ShareTokenClient;
 use utils::test_utils::jump;
 
@@ -66,6 +68,47 @@ pub fn create_liqpool_contract<'a>(
     liqpool
 }
 
+#[contract]
+pub struct FlashLoanReceiver;
+
+#[contractimpl]
+impl FlashLoanReceiver {
+    pub fn __constructor(e: Env, pool: Address) {
+        e.storage().instance().set(&symbol_short!("pool"), &pool);
+    }
+
+    // returns borrowed amounts with fees. non-empty data makes receiver keep the fees
+    pub fn on_flash_loan(
+        e: Env,
+        tokens: Vec<Address>,
+        amounts: Vec<u128>,
+        fees: Vec<u128>,
+        data: Bytes,
+    ) {
+        let pool: Address = e.storage().instance().get(&symbol_short!("pool")).unwrap();
+        for i in 0..tokens.len() {
+            let mut amount = amounts.get(i).unwrap();
+            if data.is_empty() {
+                amount += fees.get(i).unwrap();
+            }
+            if amount > 0 {
+                SorobanTokenClient::new(&e, &tokens.get(i).unwrap()).transfer(
+                    &e.current_contract_address(),
+                    &pool,
+                    &(amount as i128),
+                );
+            }
+        }
+    }
+}
+
+pub(crate) fn create_flash_loan_receiver<'a>(
+    e: &Env,
+    pool: &Address,
+) -> FlashLoanReceiverClient<'a> {
+    FlashLoanReceiverClient::new(e, &e.register(FlashLoanReceiver {}, (pool,)))
+}
+
 pub fn install_token_wasm(e: &Env) -> BytesN<32> {
     soroban_sdk::contractimport!(
         file = "../target/wasm32-unknown-unknown/release/soroban_token_contract.wasm"
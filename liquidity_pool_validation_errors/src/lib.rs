@@ -24,4 +24,6 @@ pub enum LiquidityPoolValidationError {
     ZeroAmount = 2018,
     InsufficientBalance = 2019,
     InMaxNotSatisfied = 2020,
+    FlashLoanNotRepaid = 2021,
+    InsufficientInitialLiquidity = 2022,
 }
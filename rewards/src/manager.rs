@@ -93,7 +93,7 @@ impl Manager {
     //
     // # Arguments
     //
-    // * `total_shares` - The total shares in the pool.
+    // * `total_shares` - The total shares in the pool, excluding ones locked by the pool itself.
     // * `expired_at` - The expiration time for the reward configuration.
     // * `tps` - The number of tokens per second for the reward configuration.
     //
@@ -141,7 +141,7 @@ impl Manager {
     //
     // # Arguments
     //
-    // * `total_shares` - The total shares in the pool.
+    // * `total_shares` - The total shares in the pool, excluding ones locked by the pool itself.
     //
     // # Returns
     //
@@ -193,7 +193,7 @@ impl Manager {
     //
     // # Arguments
     //
-    // * `total_shares` - The total shares in the pool.
+    // * `total_shares` - The total shares in the pool, excluding ones locked by the pool itself.
     //
     // # Returns
     //
@@ -289,7 +289,7 @@ impl Manager {
     // # Arguments
     //
     // * `user` - The address of the user for whom the reward is being calculated.
-    // * `total_shares` - The total shares in the pool.
+    // * `total_shares` - The total shares in the pool, excluding ones locked by the pool itself.
     // * `user_balance_shares` - The number of shares the user has in the pool.
     //
     // # Returns
@@ -366,6 +366,8 @@ impl Manager {
     // Working balance manipulation
     // ------------------------------------
 
+    // Lazily initializes working supply with total shares. Shares locked by the pool never get
+    // working balance, so `total_shares` must exclude them to keep the supply consistent.
     pub fn get_working_supply(&mut self, total_shares: u128) -> u128 {
         if self.storage.has_working_supply() {
             self.storage.get_working_supply()
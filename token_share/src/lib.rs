@@ -11,6 +11,7 @@ use utils::bump::bump_instance;
 enum DataKey {
     TokenShare,
     TotalShares,
+    LockedShares,
 }
 
 pub mod token {
@@ -66,3 +67,22 @@ pub fn mint_shares(e: &Env, to: &Address, amount: i128) {
     let share_contract_id = get_token_share(e);
     SorobanTokenAdminClient::new(e, &share_contract_id).mint(to, &amount);
 }
+
+pub fn get_locked_shares(e: &Env) -> u128 {
+    bump_instance(e);
+    e.storage()
+        .instance()
+        .get(&DataKey::LockedShares)
+        .unwrap_or(0)
+}
+
+// Mint shares to the contract itself. Locked shares can never be burned.
+pub fn lock_shares(e: &Env, amount: u128) {
+    mint_shares(e, &e.current_contract_address(), amount as i128);
+
+    let locked_shares = get_locked_shares(e);
+    bump_instance(e);
+    e.storage()
+        .instance()
+        .set(&DataKey::LockedShares, &(locked_shares + amount))
+}
@@ -1 +0,0 @@
-pub const UPGRADE_DELAY: u64 = 3 * 86400; // 3 days
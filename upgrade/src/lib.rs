@@ -1,16 +1,16 @@
 #![no_std]
 
-mod constants;
 mod errors;
 pub mod events;
 pub mod interface;
 mod storage;
 
-use crate::constants::UPGRADE_DELAY;
 use crate::errors::Error;
 use crate::storage::{
     get_future_wasm, get_upgrade_deadline, put_future_wasm, put_upgrade_deadline,
 };
+use access_control::audit::{record_audit_action, AuditAction};
+use access_control::delays::{get_delay, DelayTarget};
 use access_control::emergency::get_emergency_mode;
 use soroban_sdk::{panic_with_error, BytesN, Env};
 use utils::storage_errors::StorageError;
@@ -20,9 +20,10 @@ pub fn commit_upgrade(e: &Env, new_wasm_hash: &BytesN<32>) {
         panic_with_error!(e, Error::AnotherActionActive);
     }
 
-    let deadline = e.ledger().timestamp() + UPGRADE_DELAY;
+    let deadline = e.ledger().timestamp() + get_delay(e, &DelayTarget::Upgrade);
     put_upgrade_deadline(e, &deadline);
     put_future_wasm(e, &new_wasm_hash);
+    record_audit_action(e, AuditAction::CommitUpgrade, None, None, None);
 }
 
 pub fn apply_upgrade(e: &Env) -> BytesN<32> {
@@ -40,6 +41,7 @@ pub fn apply_upgrade(e: &Env) -> BytesN<32> {
         Some(v) => v,
         None => panic_with_error!(e, StorageError::ValueNotInitialized),
     };
+    record_audit_action(e, AuditAction::ApplyUpgrade, None, None, None);
     e.deployer()
         .update_current_contract_wasm(new_wasm_hash.clone());
     new_wasm_hash
@@ -47,4 +49,5 @@ pub fn apply_upgrade(e: &Env) -> BytesN<32> {
 
 pub fn revert_upgrade(e: &Env) {
     put_upgrade_deadline(e, &0);
+    record_audit_action(e, AuditAction::RevertUpgrade, None, None, None);
 }